@@ -0,0 +1,211 @@
+//! `cargo build` 없이 `.slint` 파일을 생성하고 검사하는 명령줄 도구입니다.
+//!
+//! ```text
+//! frand-property-gen generate [--src <dir>] [--out <dir>] [--features <a,b>]
+//! frand-property-gen check [--src <dir>] [--out <dir>] [--features <a,b>]
+//! frand-property-gen list [--src <dir>] [--features <a,b>]
+//! frand-property-gen print <Model> [--src <dir>] [--features <a,b>]
+//! frand-property-gen reverse <file.slint> [--out <dir>]
+//! frand-property-gen schema [--src <dir>] [--out <dir>] [--typescript] [--features <a,b>]
+//! ```
+//!
+//! - `generate` 는 `build.rs` 의 `generate_slint_files` 와 같이 파일을 쓰고, 바뀐 파일을 출력합니다.
+//! - `check` 는 파일을 쓰지 않고, 커밋된 파일과 모델이 생성할 내용의 차이를 unified diff 로 출력합니다.
+//!   차이가 있으면 종료 코드 1 로 끝납니다.
+//! - `list` 는 찾은 모델과 필드, 방향, 생성할 파일 경로를 출력합니다.
+//! - `print` 는 모델(Rust 모델 이름 또는 global 이름)이 생성하는 Slint 선언을 출력합니다.
+//! - `reverse` 는 `.slint` 파일의 global 과 데이터 struct 로부터 `slint_model!` 선언을 출력합니다.
+//!   옮기지 못한 필드는 표준 에러로 알립니다.
+//! - `schema` 는 모델마다 JSON Schema(`{모델}.schema.json`)를 `--out`(기본값 `schema`)에 쓰고,
+//!   `--typescript` 가 있으면 TypeScript 선언(`models.d.ts`)도 씁니다.
+//!
+//! `cfg(feature = "...")` 는 `--features` 로 켠 기능으로 평가합니다. 오류가 있으면 종료 코드 2 로 끝납니다.
+
+use frand_property_build::generator::{generate_slint_code_with_types, slint_export_path, slint_model_path};
+use frand_property_build::reverse::reverse_slint_models;
+use frand_property_build::schema::write_schema_files;
+use frand_property_build::types::rust_type_string as rust_type;
+use frand_property_build::{plan_slint_files, scan_items, scan_slint_items, CfgContext, ScanOptions};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: frand-property-gen <generate|check|list|print <Model>|reverse <file.slint>|schema> [--src <dir>] [--out <dir>] [--typescript] [--features <a,b>]";
+
+struct Options {
+    src_dir: PathBuf,
+    output_dir: PathBuf,
+    cfg: CfgContext,
+    /// `print` 의 모델 이름, `reverse` 의 파일 경로
+    name: Option<String>,
+    /// `schema` 에서 TypeScript 선언도 쓸지 여부
+    typescript: bool,
+}
+
+fn parse_options(args: impl Iterator<Item = String>, command: Option<&str>) -> Result<Options, String> {
+    let mut options = Options {
+        src_dir: PathBuf::from("src"),
+        output_dir: PathBuf::from(if command == Some("schema") { "schema" } else { "slint" }),
+        cfg: CfgContext::from_env(),
+        name: None,
+        typescript: false,
+    };
+    let takes_name = matches!(command, Some("print" | "reverse"));
+
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        let target = match arg.as_str() {
+            "--src" => &mut options.src_dir,
+            "--out" => &mut options.output_dir,
+            "--features" => {
+                let features = args.next().ok_or_else(|| format!("`{arg}` requires a feature list"))?;
+                for feature in features.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+                    options.cfg = options.cfg.with_feature(feature);
+                }
+                continue;
+            }
+            "--typescript" if command == Some("schema") => {
+                options.typescript = true;
+                continue;
+            }
+            _ if takes_name && !arg.starts_with('-') && options.name.is_none() => {
+                options.name = Some(arg);
+                continue;
+            }
+            _ => return Err(format!("unknown argument `{arg}`")),
+        };
+        *target = args.next().map(PathBuf::from).ok_or_else(|| format!("`{arg}` requires a directory"))?;
+    }
+
+    Ok(options)
+}
+
+fn generate(options: &Options) -> anyhow::Result<bool> {
+    let output = plan_slint_files(&options.src_dir, &options.output_dir, &options.cfg)?;
+    let diffs = output.diffs();
+    output.write()?;
+
+    for diff in &diffs {
+        let action = match (&diff.original, &diff.generated) {
+            (None, _) => "created",
+            (_, None) => "deleted",
+            _ => "updated",
+        };
+        println!("{action} {}", options.output_dir.join(&diff.path).display());
+    }
+    Ok(true)
+}
+
+fn check(options: &Options) -> anyhow::Result<bool> {
+    let diffs = plan_slint_files(&options.src_dir, &options.output_dir, &options.cfg)?.diffs();
+
+    for diff in &diffs {
+        eprintln!("{}:", options.output_dir.join(&diff.path).display());
+        for change in &diff.changes {
+            eprintln!("  {}", change.to_string().replace('\n', "\n  "));
+        }
+        print!("{}", diff.unified_diff());
+    }
+
+    if !diffs.is_empty() {
+        eprintln!("{} generated Slint file(s) are out of date", diffs.len());
+    }
+    Ok(diffs.is_empty())
+}
+
+fn list(options: &Options) -> anyhow::Result<bool> {
+    let items = scan_slint_items(&options.src_dir, &options.cfg)?;
+
+    for model in &items.models {
+        let len = model.len.as_ref().map(|len| format!("[{}]", rust_type(len))).unwrap_or_default();
+        println!("model {}{}: {} -> {}", model.model_name, len, model.type_name, slint_path(slint_model_path(model)));
+        for field in &model.fields {
+            let ret = field.ret.as_ref().map(|ret| format!(" -> {}", rust_type(ret))).unwrap_or_default();
+            println!("    {} {}: {}{}", field.direction.keyword(), field.name, rust_type(&field.ty), ret);
+        }
+    }
+    for item in &items.enums {
+        let variants: Vec<_> = item.variants.iter().map(|variant| variant.name.to_string()).collect();
+        println!("enum {} -> {}", item.name, slint_path(slint_export_path(item.export_path.as_deref(), &item.name.to_string())));
+        println!("    {}", variants.join(", "));
+    }
+    for item in &items.structs {
+        println!("struct {} -> {}", item.name, slint_path(slint_export_path(item.export_path.as_deref(), &item.name.to_string())));
+        for field in &item.fields {
+            println!("    {}: {}", field.name, rust_type(&field.ty));
+        }
+    }
+    Ok(true)
+}
+
+fn print(options: &Options) -> anyhow::Result<bool> {
+    let name = options.name.as_deref().ok_or_else(|| anyhow::anyhow!("`print` requires a model name\n{USAGE}"))?;
+    let items = scan_slint_items(&options.src_dir, &options.cfg)?;
+
+    let model = items.models.iter()
+        .find(|model| model.model_name == name || model.type_name == name)
+        .ok_or_else(|| anyhow::anyhow!("no slint_model! named `{name}` in {}", options.src_dir.display()))?;
+    println!("// {}", slint_path(slint_model_path(model)));
+    println!("{}", generate_slint_code_with_types(model, &items.user_types()));
+    Ok(true)
+}
+
+fn reverse(options: &Options) -> anyhow::Result<bool> {
+    let path = PathBuf::from(options.name.as_deref().ok_or_else(|| anyhow::anyhow!("`reverse` requires a .slint file\n{USAGE}"))?);
+    let content = std::fs::read_to_string(&path).map_err(|err| anyhow::anyhow!("cannot read {}: {}", path.display(), err))?;
+    // 출력 디렉토리 안의 파일이면 `export to` 로 같은 파일을 가리킵니다.
+    let export_path = path.strip_prefix(&options.output_dir).ok().map(slint_path);
+
+    let models = reverse_slint_models(&content, &path, export_path.as_deref())?;
+    if models.is_empty() {
+        anyhow::bail!("no global with a `data: [..]` property of a struct declared in {}", path.display());
+    }
+
+    for (i, model) in models.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print!("{}", model.source);
+        for issue in &model.issues {
+            eprintln!("{}: {}.{}: {}", path.display(), model.global_name, issue.field, issue.message);
+        }
+    }
+    Ok(true)
+}
+
+fn schema(options: &Options) -> anyhow::Result<bool> {
+    let items = scan_items(&options.src_dir, &options.cfg, ScanOptions { rust_models: true })?;
+    for path in write_schema_files(&items, &options.output_dir, options.typescript)? {
+        println!("updated {}", path.display());
+    }
+    Ok(true)
+}
+
+fn slint_path(path: impl AsRef<std::path::Path>) -> String {
+    path.as_ref().to_string_lossy().replace('\\', "/")
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+
+    let command = args.next();
+    let options = parse_options(args, command.as_deref()).map_err(anyhow::Error::msg);
+    let result = match command.as_deref() {
+        Some("generate") => options.and_then(|options| generate(&options)),
+        Some("check") => options.and_then(|options| check(&options)),
+        Some("list") => options.and_then(|options| list(&options)),
+        Some("print") => options.and_then(|options| print(&options)),
+        Some("reverse") => options.and_then(|options| reverse(&options)),
+        Some("schema") => options.and_then(|options| schema(&options)),
+        Some(command) => Err(anyhow::anyhow!("unknown command `{command}`\n{USAGE}")),
+        None => Err(anyhow::anyhow!("{USAGE}")),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// 빌드 스크립트의 `OUT_DIR` 에 두는 스캔 캐시 파일 이름입니다.
+pub const SCAN_CACHE_FILE: &str = "frand-property-scan-cache";
+
+const HEADER: &str = "# frand-property scan cache v2";
+
+/// 한 소스 파일에서 찾은 매크로 호출과 하위 모듈입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CachedFile {
+    /// 파일 내용과 모듈 디렉토리의 해시
+    pub hash: u64,
+    /// (매크로 이름, 토큰 문자열)
+    pub macros: Vec<(String, String)>,
+    /// (모듈 파일, 그 파일의 하위 모듈 디렉토리)
+    pub modules: Vec<(PathBuf, PathBuf)>,
+}
+
+/// 파싱한 모델 정의를 파일 내용의 해시로 저장하는 캐시입니다.
+/// `cfg` 조건이 바뀌면 모든 항목을 버립니다.
+#[derive(Debug, Default)]
+pub(crate) struct ScanCache {
+    cfg: u64,
+    files: HashMap<PathBuf, CachedFile>,
+    /// 이번 스캔에서 사용한 파일. 저장할 때 나머지는 버립니다.
+    used: HashMap<PathBuf, CachedFile>,
+}
+
+impl ScanCache {
+    /// 캐시 파일을 읽습니다. 없거나 형식이 다르거나 `cfg` 조건이 다르면 빈 캐시입니다.
+    pub fn load(path: &Path, cfg: u64) -> Self {
+        let files = fs::read_to_string(path).ok()
+            .and_then(|content| parse_cache(&content, cfg))
+            .unwrap_or_default();
+        Self { cfg, files, used: HashMap::new() }
+    }
+
+    /// 파일 내용이 바뀌지 않았고, 하위 모듈 파일이 모두 그대로 있으면 캐시된 결과를 돌려줍니다.
+    pub fn get(&mut self, path: &Path, hash: u64) -> Option<CachedFile> {
+        let file = self.files.get(path).filter(|file| file.hash == hash)?;
+        if !file.modules.iter().all(|(module, _)| module.exists()) {
+            return None;
+        }
+        let file = file.clone();
+        self.used.insert(path.to_path_buf(), file.clone());
+        Some(file)
+    }
+
+    pub fn insert(&mut self, path: &Path, file: CachedFile) {
+        self.used.insert(path.to_path_buf(), file);
+    }
+
+    /// 이번 스캔에서 사용한 항목만 저장합니다. 내용이 같으면 쓰지 않습니다.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut paths: Vec<_> = self.used.keys().collect();
+        paths.sort();
+
+        let mut content = format!("{HEADER}\ncfg {:016x}\n", self.cfg);
+        for path in paths {
+            let file = &self.used[path];
+            content.push_str(&format!("file {}\t{:016x}\n", path.display(), file.hash));
+            for (name, tokens) in &file.macros {
+                content.push_str(&format!("macro {} {}\n", name, escape(tokens)));
+            }
+            for (module, dir) in &file.modules {
+                content.push_str(&format!("mod {}\t{}\n", module.display(), dir.display()));
+            }
+        }
+
+        if fs::read_to_string(path).ok().as_deref() != Some(content.as_str()) {
+            fs::write(path, content)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_cache(content: &str, cfg: u64) -> Option<HashMap<PathBuf, CachedFile>> {
+    let mut lines = content.lines();
+    if lines.next() != Some(HEADER) || lines.next() != Some(format!("cfg {cfg:016x}").as_str()) {
+        return None;
+    }
+
+    let mut files = HashMap::new();
+    let mut current: Option<(PathBuf, CachedFile)> = None;
+    for line in lines {
+        let (tag, rest) = line.split_once(' ')?;
+        match tag {
+            "file" => {
+                let (path, hash) = rest.rsplit_once('\t')?;
+                let hash = u64::from_str_radix(hash, 16).ok()?;
+                files.extend(current.take());
+                current = Some((PathBuf::from(path), CachedFile { hash, macros: Vec::new(), modules: Vec::new() }));
+            }
+            "macro" => {
+                let (name, tokens) = rest.split_once(' ')?;
+                current.as_mut()?.1.macros.push((name.to_string(), unescape(tokens)));
+            }
+            "mod" => {
+                let (path, dir) = rest.split_once('\t')?;
+                current.as_mut()?.1.modules.push((PathBuf::from(path), PathBuf::from(dir)));
+            }
+            _ => return None,
+        }
+    }
+    files.extend(current);
+
+    Some(files)
+}
+
+pub(crate) fn hash_of(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
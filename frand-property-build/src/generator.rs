@@ -1,94 +1,177 @@
-use crate::parser::{self, SlintModel};
-use std::fs;
-use std::path::{Path};
-use quote::quote;
+use crate::parser::{self, SlintEnum, SlintModel, SlintStruct};
+use crate::slint_syntax::{Declaration, DeclarationKind, SlintFile};
+use crate::types::{self, SlintType, UserTypes};
+use crate::output::SlintOutput;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use syn::Type;
 use heck::ToSnakeCase;
 
 pub fn generate_slint_doc(input: &SlintModel) -> String {
-    let (struct_name, struct_body, global_name, global_body, component_name, component_body) = generate_code_components(input, None);
+    format!(" 생성된 Slint 코드:\n```slint\n{}\n```", generate_slint_code(input))
+}
+
+/// `slint_model!` 이 생성하는 struct, global, component 선언입니다.
+/// 알려진 `slint_enum!` 타입이 없으므로 `Enum::Variant` 초기값은 `data` 기본값에 기록하지 않습니다.
+pub fn generate_slint_code(input: &SlintModel) -> String {
+    generate_slint_code_with_types(input, &UserTypes::default())
+}
+
+/// `generate_slint_code` 와 같지만, `user_types` 에 선언된 타입 이름으로 필드 타입을 분류하고
+/// `slint_enum!` 타입의 `Enum::Variant` 초기값을 `data` 기본값에 기록합니다.
+pub fn generate_slint_code_with_types(input: &SlintModel, user_types: &UserTypes) -> String {
+    let (struct_name, struct_body, global_name, global_body, component_name, component_body) = generate_code_components(input, None, user_types);
 
     let struct_def = format!("export struct {} {{\n{}\n}}", struct_name, struct_body);
     let global_def = format!("export global {} {{\n{}\n}}", global_name, global_body);
     let component_def = format!("export component {} inherits Rectangle {{\n{}\n}}", component_name, component_body);
 
-    format!(
-        " 생성된 Slint 코드:\n```slint\n{struct_def}\n\n{global_def}\n\n{component_def}\n```"
-    )
+    format!("{struct_def}\n\n{global_def}\n\n{component_def}")
 }
 
-pub fn generate_slint_file(input: &SlintModel, output_dir: &Path) -> anyhow::Result<()> {
-    let global_name_str = input.type_name.to_string();
-    let name_for_file = if let Some(stripped) = global_name_str.strip_suffix("Global") {
-        stripped
-    } else {
-        &global_name_str
-    };
+/// 모델을 생성할 파일의 경로 (출력 디렉토리 기준). `export to` 가 없으면 `global/{global 이름}.slint` 입니다.
+pub fn slint_model_path(input: &SlintModel) -> PathBuf {
+    let global_name = input.type_name.to_string();
+    let name = global_name.strip_suffix("Global").unwrap_or(&global_name);
+    slint_export_path(input.export_path.as_deref(), name)
+}
 
-    let file_stem = name_for_file.to_snake_case();
-    
-    // output_dir 기준 타겟 경로 결정
-    let file_rel_path = if let Some(path) = &input.export_path {
-        Path::new(path).to_path_buf()
-    } else {
-        Path::new("global").join(format!("{}.slint", file_stem))
-    };
-    
-    let target_path = output_dir.join(&file_rel_path);
-    
-    if let Some(parent) = target_path.parent() {
-        if !parent.exists() {
-            fs::create_dir_all(parent)?;
-        }
+/// Slint 타입을 생성할 파일의 경로 (출력 디렉토리 기준). `export to` 가 없으면 `global/{타입 이름}.slint` 입니다.
+pub fn slint_export_path(export_path: Option<&str>, name: &str) -> PathBuf {
+    match export_path {
+        Some(path) => Path::new(path).to_path_buf(),
+        None => Path::new("global").join(format!("{}.slint", name.to_snake_case())),
     }
+}
 
-    let original_content = if target_path.exists() {
-        fs::read_to_string(&target_path)?
-    } else {
-        String::new()
-    };
-    let mut content = original_content.clone();
+pub fn generate_slint_file(input: &SlintModel, output_dir: &Path) -> anyhow::Result<()> {
+    let mut output = SlintOutput::new(output_dir);
+    merge_slint_model(input, &mut output)?;
+    output.write()
+}
 
-    let (struct_name, struct_body, global_name, global_body, component_name, component_body) = generate_code_components(input, Some(&original_content));
+/// `slint_model!` 의 struct, global, component 선언을 출력 파일에 병합합니다. 디스크에는 쓰지 않습니다.
+pub fn merge_slint_model(input: &SlintModel, output: &mut SlintOutput) -> anyhow::Result<()> {
+    let file_rel_path = slint_model_path(input);
+    let target_path = output.output_dir().join(&file_rel_path);
+    let original_content = output.read(&file_rel_path)?;
 
-    let struct_header_pattern = format!("export struct {}", struct_name);
-    content = replace_or_append_block(content, &struct_header_pattern, &struct_body, || {
-        format!("export struct {} {{\n{}\n}}", struct_name, struct_body)
-    });
+    // 기존 global 의 `data` 초기값은 모델에 초기값이 선언되지 않은 경우 그대로 유지합니다.
+    let original_file = parse_slint_file(&original_content, &target_path)?;
+    let existing_data = original_file
+        .find(DeclarationKind::Global, &input.type_name.to_string())
+        .and_then(|global| original_file.property_value(global, "data"));
 
-    let global_header_pattern = format!("export global {}", global_name);
-    content = replace_or_append_block(content, &global_header_pattern, &global_body, || {
-        format!("export global {} {{\n{}\n}}", global_name, global_body)
-    });
+    let (struct_name, struct_body, global_name, global_body, component_name, component_body) = generate_code_components(input, existing_data, output.user_types());
 
-    let component_header_pattern = format!("export component {}", component_name);
-    content = replace_or_append_block(content, &component_header_pattern, &component_body, || {
+    let mut content = original_content.clone();
+    content = replace_or_append_block(content, &target_path, DeclarationKind::Struct, &struct_name, &struct_body, || {
+        format!("export struct {} {{\n{}\n}}", struct_name, struct_body)
+    })?;
+    content = replace_or_append_block(content, &target_path, DeclarationKind::Global, &global_name, &global_body, || {
+        format!("export global {} {{\n{}\n}}", global_name, global_body)
+    })?;
+    content = replace_or_append_block(content, &target_path, DeclarationKind::Component, &component_name, &component_body, || {
         format!("export component {} inherits Rectangle {{\n{}\n}}", component_name, component_body)
-    });
+    })?;
+
+    output.stage(&file_rel_path, content);
+    output.own_declaration(&file_rel_path, DeclarationKind::Struct, &struct_name);
+    output.own_declaration(&file_rel_path, DeclarationKind::Global, &global_name);
+    output.own_declaration(&file_rel_path, DeclarationKind::Component, &component_name);
 
-    if content != original_content {
-        fs::write(&target_path, content)?;
-    }
-    
     // global 디렉토리의 index.slint 업데이트
-    update_index_slint(output_dir, &global_name, &file_rel_path)?;
+    update_index_slint(output, &global_name, &file_rel_path)
+}
 
-    Ok(())
+/// `slint_enum!` 의 Slint 본문(`export enum Name { ... }` 의 중괄호 안)을 생성합니다.
+fn generate_enum_body(input: &SlintEnum) -> String {
+    let mut lines = slint_comment_lines(&parser::doc_lines(&input.attrs));
+    for variant in &input.variants {
+        lines.extend(slint_comment_lines(&parser::doc_lines(&variant.attrs)));
+        lines.push(format!("    {},", variant.name));
+    }
+    lines.join("\n")
 }
 
-fn update_index_slint(output_dir: &Path, global_name: &str, file_rel_path: &Path) -> anyhow::Result<()> {
-    let index_dir = output_dir.join("global");
-    if !index_dir.exists() {
-        fs::create_dir_all(&index_dir)?;
+/// `slint_struct!` 의 Slint 본문(`export struct Name { ... }` 의 중괄호 안)을 생성합니다.
+fn generate_struct_body(input: &SlintStruct, user_types: &UserTypes) -> String {
+    let mut lines = slint_comment_lines(&parser::doc_lines(&input.attrs));
+    for field in &input.fields {
+        lines.extend(slint_comment_lines(&parser::doc_lines(&field.attrs)));
+        lines.push(format!("    {}: {},", field.slint_name(), rust_type_to_slint_type(&field.ty, user_types)));
     }
-    let index_path = index_dir.join("index.slint");
-    
+    lines.join("\n")
+}
+
+pub fn generate_slint_enum_doc(input: &SlintEnum) -> String {
+    format!(" 생성된 Slint 코드:\n```slint\nexport enum {} {{\n{}\n}}\n```", input.name, generate_enum_body(input))
+}
+
+pub fn generate_slint_struct_doc(input: &SlintStruct) -> String {
+    format!(" 생성된 Slint 코드:\n```slint\nexport struct {} {{\n{}\n}}\n```", input.name, generate_struct_body(input, &UserTypes::default()))
+}
+
+pub fn generate_slint_enum_file(input: &SlintEnum, output_dir: &Path) -> anyhow::Result<()> {
+    let mut output = SlintOutput::new(output_dir);
+    merge_slint_enum(input, &mut output)?;
+    output.write()
+}
+
+pub fn generate_slint_struct_file(input: &SlintStruct, output_dir: &Path) -> anyhow::Result<()> {
+    let mut output = SlintOutput::new(output_dir);
+    merge_slint_struct(input, &mut output)?;
+    output.write()
+}
+
+/// `slint_enum!` 의 선언을 출력 파일에 병합합니다. 디스크에는 쓰지 않습니다.
+pub fn merge_slint_enum(input: &SlintEnum, output: &mut SlintOutput) -> anyhow::Result<()> {
+    output.declare_enum(&input.name.to_string());
+    merge_slint_type(output, input.export_path.as_deref(), &input.name.to_string(), DeclarationKind::Enum, &generate_enum_body(input))
+}
+
+/// `slint_struct!` 의 선언을 출력 파일에 병합합니다. 디스크에는 쓰지 않습니다.
+pub fn merge_slint_struct(input: &SlintStruct, output: &mut SlintOutput) -> anyhow::Result<()> {
+    output.declare_struct(&input.name.to_string());
+    let body = generate_struct_body(input, output.user_types());
+    merge_slint_type(output, input.export_path.as_deref(), &input.name.to_string(), DeclarationKind::Struct, &body)
+}
+
+/// Slint 타입 선언(`export enum`/`export struct`)을 파일에 병합하고 `index.slint` 에서 내보냅니다.
+/// 새로 추가되는 선언은 이를 사용하는 다른 선언보다 앞에 오도록 첫 번째 선언(과 그 위의 주석) 앞에 삽입합니다.
+fn merge_slint_type(output: &mut SlintOutput, export_path: Option<&str>, name: &str, kind: DeclarationKind, body: &str) -> anyhow::Result<()> {
+    let file_rel_path = slint_export_path(export_path, name);
+
+    let target_path = output.output_dir().join(&file_rel_path);
+    let original_content = output.read(&file_rel_path)?;
+
+    let keyword = if kind == DeclarationKind::Enum { "enum" } else { "struct" };
+    let block = format!("export {keyword} {name} {{\n{body}\n}}");
+    let original_file = parse_slint_file(&original_content, &target_path)?;
+    let content = match original_file.find(kind, name) {
+        Some(declaration) => replace_body(&original_file, declaration, body)?,
+        None => match original_file.declarations().first() {
+            Some(first) => format!("{}{}\n\n{}", &original_content[..first.start], block, &original_content[first.start..]),
+            None => append_block(original_content.clone(), &block),
+        },
+    };
+
+    output.stage(&file_rel_path, content);
+    output.own_declaration(&file_rel_path, kind, name);
+
+    update_index_slint(output, name, &file_rel_path)
+}
+
+fn update_index_slint(output: &mut SlintOutput, global_name: &str, file_rel_path: &Path) -> anyhow::Result<()> {
+    let index_path = Path::new("global").join("index.slint");
+
+
     // index.slint(global/ 위치) 기준 import 경로 계산
     // slint는 forward slash를 사용한다고 가정
     let path_str = file_rel_path.to_string_lossy().replace("\\", "/");
-    let import_path = if path_str.starts_with("global/") {
+    let import_path = if let Some(sibling) = path_str.strip_prefix("global/") {
         // 파일이 global/에 있으므로 형제 경로
-        format!("./{}", &path_str["global/".len()..])
+        format!("./{}", sibling)
     } else {
         // 파일이 다른 곳에 있으므로 global/에서 한 단계 위로 이동
         format!("../{}", path_str)
@@ -96,51 +179,43 @@ fn update_index_slint(output_dir: &Path, global_name: &str, file_rel_path: &Path
     
     let export_stmt = format!("export {{ {} }} from \"{}\";", global_name, import_path);
     
-    let mut index_content = if index_path.exists() {
-        fs::read_to_string(&index_path)?
-    } else {
-        String::new()
-    };
-    
+    output.own_export(&export_stmt);
+    let mut index_content = output.read(&index_path)?;
+
     if !index_content.contains(&export_stmt) {
         if !index_content.is_empty() && !index_content.ends_with('\n') {
             index_content.push('\n');
         }
         index_content.push_str(&export_stmt);
         index_content.push('\n');
-        
-        fs::write(&index_path, index_content)?;
+
+        output.stage(&index_path, index_content);
     }
     Ok(())
 }
 
-pub fn generate_code_components(input: &SlintModel, original_content: Option<&str>) -> (String, String, String, String, String, String) {
+/// `existing_data` 는 기존 파일에 있던 global 의 `data` 초기값 원문입니다.
+pub fn generate_code_components(input: &SlintModel, existing_data: Option<&str>, user_types: &UserTypes) -> (String, String, String, String, String, String) {
     let global_name = input.type_name.to_string();
     let struct_name = format!("{}Data", input.type_name);
 
     let mut data_init = "[{}]".to_string();
-    if let Some(content) = original_content {
-        let global_header_pattern = format!("export global {}", global_name);
-        if let Some((start, end)) = find_block_range(content, &global_header_pattern) {
-            let block_content = &content[start..end];
-            if let Some(data_idx) = block_content.find("data:") {
-                let after_data = &block_content[data_idx + 5..];
-                if let Some(semi_idx) = after_data.find(';') {
-                    let maybe_array = after_data[..semi_idx].trim();
-                    if maybe_array.starts_with('[') && maybe_array.ends_with(']') {
-                        data_init = maybe_array.to_string();
-                    }
-                }
-            }
-        }
+    let declared_init = generate_data_init(input, user_types);
+    if let Some(init) = declared_init {
+        // 모델에 선언된 초기값이 있으면 기존 파일의 값보다 우선합니다.
+        data_init = init;
+    } else if let Some(existing) = existing_data.filter(|data| data.starts_with('[') && data.ends_with(']')) {
+        data_init = existing.to_string();
     }
 
     let global_data = format!("    in-out property <[{struct_name}]> data: {data_init};");
+    let model_docs = slint_comment_lines(&parser::doc_lines(&input.attrs));
     
     let mut struct_fields = Vec::new();
-    let mut global_fields = vec![global_data];
+    let mut global_fields = model_docs.clone();
+    global_fields.push(global_data);
 
-    let mut component_fields = Vec::new();
+    let mut component_fields = model_docs;
 
     // 기본 길이 프로퍼티 추가
     component_fields.push(format!("    out property <int> global-data-length: {global_name}.data.length;"));
@@ -149,12 +224,60 @@ pub fn generate_code_components(input: &SlintModel, original_content: Option<&st
     component_fields.push("    in-out property <int> global-data-index: 0;".to_string());
 
     for field in &input.fields {
-        let kebab_name = field.name.to_string().replace("_", "-");
-        let slint_type = rust_type_to_slint_type(&field.ty);
+        let kebab_name = field.slint_name();
+        let slint_type = rust_type_to_slint_type(&field.ty, user_types);
+        let field_docs = slint_comment_lines(&parser::doc_lines(&field.attrs));
+
+        if field.direction == parser::Direction::Action {
+             // 비동기 요청: 요청 콜백과 행별 처리 상태(`-pending`, `-error`, `-result`) 필드
+             let arg_types = callback_arg_types(&field.ty, user_types);
+             let params: Vec<String> = (0..arg_types.len()).map(|n| format!("arg{n}")).collect();
+             let global_args = std::iter::once("int".to_string()).chain(arg_types.iter().cloned()).collect::<Vec<_>>().join(", ");
+             let call_args = std::iter::once("global-data-index".to_string()).chain(params.iter().cloned()).collect::<Vec<_>>().join(", ");
+
+             global_fields.extend(field_docs.iter().cloned());
+             global_fields.push(format!("    callback {kebab_name}({global_args});"));
+
+             let mut state_fields = vec![
+                 (format!("{kebab_name}-pending"), "bool".to_string()),
+                 (format!("{kebab_name}-error"), "string".to_string()),
+             ];
+             if let Some(ret) = field.ret.as_ref().filter(|ret| !is_unit_ty(ret)) {
+                 state_fields.push((format!("{kebab_name}-result"), rust_type_to_slint_type(ret, user_types)));
+             }
+
+             component_fields.extend(field_docs.iter().cloned());
+             component_fields.push(format!("    callback global-{kebab_name}({});", arg_types.join(", ")));
+             if params.is_empty() {
+                 component_fields.push(format!("    global-{kebab_name} => {{ {global_name}.{kebab_name}({call_args}); }}"));
+             } else {
+                 component_fields.push(format!("    global-{kebab_name}({}) => {{ {global_name}.{kebab_name}({call_args}); }}", params.join(", ")));
+             }
 
-        if is_unit_ty(&field.ty) || field.direction == parser::Direction::Callback {
+             struct_fields.extend(field_docs.iter().cloned());
+             for (state_name, state_type) in state_fields {
+                 struct_fields.push(format!("    {state_name}: {state_type},"));
+                 component_fields.push(format!("    out property <{state_type}> global-{state_name}: {global_name}.data[global-data-index].{state_name};"));
+             }
+        } else if is_unit_ty(&field.ty) || field.direction == parser::Direction::Callback {
              // 콜백 타입 처리
-             if is_unit_ty(&field.ty) {
+             global_fields.extend(field_docs.iter().cloned());
+             component_fields.extend(field_docs);
+             if let Some(ret) = &field.ret {
+                 // 반환값이 있는 콜백: 바인딩에서 호출할 수 있도록 `pure` 로 선언하고, 튜플 인자는 Slint 의 개별 인자로 펼칩니다.
+                 let slint_ret = rust_type_to_slint_type(ret, user_types);
+                 let arg_types = callback_arg_types(&field.ty, user_types);
+                 let params: Vec<String> = (0..arg_types.len()).map(|n| format!("arg{n}")).collect();
+                 let global_args = std::iter::once("int".to_string()).chain(arg_types.iter().cloned()).collect::<Vec<_>>().join(", ");
+                 let call_args = std::iter::once("global-data-index".to_string()).chain(params.iter().cloned()).collect::<Vec<_>>().join(", ");
+                 global_fields.push(format!("    pure callback {kebab_name}({global_args}) -> {slint_ret};"));
+                 component_fields.push(format!("    pure callback global-{kebab_name}({}) -> {slint_ret};", arg_types.join(", ")));
+                 if params.is_empty() {
+                     component_fields.push(format!("    global-{kebab_name} => {{ {global_name}.{kebab_name}({call_args}) }}"));
+                 } else {
+                     component_fields.push(format!("    global-{kebab_name}({}) => {{ {global_name}.{kebab_name}({call_args}) }}", params.join(", ")));
+                 }
+             } else if is_unit_ty(&field.ty) {
                  global_fields.push(format!("    callback {kebab_name}(int);"));
                  component_fields.push(format!("    callback global-{kebab_name};"));
                  component_fields.push(format!("    global-{kebab_name} => {{ {global_name}.{kebab_name}(global-data-index); }}"));
@@ -164,25 +287,40 @@ pub fn generate_code_components(input: &SlintModel, original_content: Option<&st
                  component_fields.push(format!("    global-{kebab_name}(val) => {{ {global_name}.{kebab_name}(global-data-index, val); }}"));
              }
         } else {
-             struct_fields.push(
-                format!("    {kebab_name}: {slint_type},")
-            );
+             // `Option<T>` 필드는 값 필드와 `has-*` 플래그 필드로 나뉩니다.
+             let mut data_fields = vec![(kebab_name.clone(), slint_type)];
+             if matches!(user_types.classify(&field.ty), SlintType::Option(_)) {
+                 data_fields.push((format!("has-{kebab_name}"), "bool".to_string()));
+             }
+
+             struct_fields.extend(field_docs.iter().cloned());
+             for (name, slint_type) in &data_fields {
+                 struct_fields.push(format!("    {name}: {slint_type},"));
+             }
+             if matches!(field.direction, parser::Direction::In | parser::Direction::Out | parser::Direction::Computed) {
+                 component_fields.extend(field_docs);
+             }
 
             match field.direction {
                 parser::Direction::In => {
                     // Rust -> Slint (Slint 로직에 의해 변경되어 Rust 가 읽음)
                     // "in x: i32" -> "property <int> global-x;"
-                    
-                    component_fields.push(format!("    in-out property <{slint_type}> global-{kebab_name}: {global_name}.data[global-data-index].{kebab_name};"));
-                    component_fields.push(format!("    changed global-{kebab_name} => {{ {global_name}.data[global-data-index].{kebab_name} = self.global-{kebab_name}; }}"));
+                    for (name, slint_type) in &data_fields {
+                        component_fields.push(format!("    in-out property <{slint_type}> global-{name}: {global_name}.data[global-data-index].{name};"));
+                        component_fields.push(format!("    changed global-{name} => {{ {global_name}.data[global-data-index].{name} = self.global-{name}; }}"));
+                    }
                 }
-                parser::Direction::Out => {
+                parser::Direction::Out | parser::Direction::Computed => {
                     // Rust -> Slint (Rust 가 쓰고 Slint 가 읽음)
                     // "out sum: i32" -> "property <int> global-sum: Global.data[index].sum;"
-                    component_fields.push(format!("    out property <{slint_type}> global-{kebab_name}: {global_name}.data[global-data-index].{kebab_name};"));
+                    for (name, slint_type) in &data_fields {
+                        component_fields.push(format!("    out property <{slint_type}> global-{name}: {global_name}.data[global-data-index].{name};"));
+                    }
                 }
                 parser::Direction::Model => {}
-                parser::Direction::Callback => {}
+                parser::Direction::Callback | parser::Direction::Action => {}
+                // slint_model! 은 inout 필드를 파싱하지 않습니다.
+                parser::Direction::InOut => {}
             }
         }
     }
@@ -199,34 +337,186 @@ pub fn generate_code_components(input: &SlintModel, original_content: Option<&st
     )
 }
 
-fn rust_type_to_slint_type(ty: &Type) -> String {
-    if let Type::Array(type_array) = ty {
-        let inner_type = rust_type_to_slint_type(&type_array.elem);
-        return format!("[{}]", inner_type);
+/// 문서 주석 줄을 Slint `//` 주석 줄로 변환합니다.
+fn slint_comment_lines(lines: &[String]) -> Vec<String> {
+    lines.iter().map(|line| {
+        if line.is_empty() {
+            "    //".to_string()
+        } else {
+            format!("    // {line}")
+        }
+    }).collect()
+}
+
+/// 필드에 선언된 초기값으로 `data` 프로퍼티의 기본값(`[{ field: value, .. }]`)을 만듭니다.
+/// 모델 배열(`Model[N]`)은 런타임과 같이 같은 초기값의 행을 `N` 개 만듭니다.
+/// Slint 리터럴로 옮길 수 있는 초기값이 하나도 없거나, 모델 배열의 길이가 정수 리터럴이 아니면 `None`을 반환합니다.
+fn generate_data_init(input: &SlintModel, user_types: &UserTypes) -> Option<String> {
+    let rows = match &input.len {
+        Some(len) => syn::parse2::<syn::LitInt>(len.clone()).ok()?.base10_parse::<usize>().ok()?,
+        None => 1,
+    };
+
+    let fields: Vec<_> = input.fields.iter()
+        .filter(|f| matches!(f.direction, parser::Direction::In | parser::Direction::Out))
+        .filter_map(|f| {
+            let init = f.init.as_ref()?;
+            let literal = LiteralContext { enums: &user_types.enums, overflow: f.options.overflow() };
+            if let Some(inner) = types::option_inner(&f.ty) {
+                // `Some(value)` 는 값과 `has-*: true` 로, `None` 은 기본값 그대로 둡니다.
+                let value = option_some_expr(init)?;
+                let literal = literal.convert(value, &user_types.classify(inner))?;
+                return Some(format!("{}: {}, has-{}: true", f.slint_name(), literal, f.slint_name()));
+            }
+            let literal = literal.convert(init, &user_types.classify(&f.ty))?;
+            Some(format!("{}: {}", f.slint_name(), literal))
+        })
+        .collect();
+
+    if fields.is_empty() || rows == 0 {
+        None
+    } else {
+        let row = format!("{{ {} }}", fields.join(", "));
+        Some(format!("[{}]", vec![row; rows].join(", ")))
+    }
+}
+
+/// `Some(value)` 식이면 `value` 를 반환합니다.
+fn option_some_expr(expr: &syn::Expr) -> Option<&syn::Expr> {
+    match expr {
+        syn::Expr::Call(call) if matches!(call.func.as_ref(), syn::Expr::Path(path) if path.path.is_ident("Some")) && call.args.len() == 1 => {
+            call.args.first()
+        }
+        _ => None,
+    }
+}
+
+/// Rust 초기값 식을 Slint 리터럴로 옮길 때 필요한 정보입니다.
+struct LiteralContext<'a> {
+    /// `Enum::Variant` 경로로 옮길 수 있는 `slint_enum!` 타입 이름
+    enums: &'a BTreeSet<String>,
+    /// `int` 범위를 벗어난 정수 리터럴의 처리 방식 (`#[frand(overflow = "...")]`)
+    overflow: parser::Overflow,
+}
+
+impl LiteralContext<'_> {
+    /// Rust 초기값 식을 `ty` 타입의 Slint 리터럴로 변환합니다.
+    /// 리터럴, 부호, 배열, 알려진 `slint_enum!` 의 `Enum::Variant` 경로, `Duration::from_millis/from_secs(N)` 만 지원하며,
+    /// 그 외의 식이나 `overflow = "reject"` 로 버려지는 정수는 `None`을 반환합니다.
+    fn convert(&self, expr: &syn::Expr, ty: &SlintType) -> Option<String> {
+        if *ty == SlintType::Int {
+            if let Some(value) = int_literal(expr) {
+                return self.int_literal(value);
+            }
+        }
+
+        match expr {
+            syn::Expr::Call(call) => {
+                let syn::Expr::Path(func) = call.func.as_ref() else { return None };
+                let mut segments = func.path.segments.iter().rev();
+                let (function, type_name) = (segments.next()?, segments.next()?);
+                if type_name.ident != "Duration" || call.args.len() != 1 {
+                    return None;
+                }
+                let unit = match function.ident.to_string().as_str() {
+                    "from_millis" => "ms",
+                    "from_secs" => "s",
+                    _ => return None,
+                };
+                match call.args.first()? {
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => Some(format!("{}{}", lit.base10_digits(), unit)),
+                    _ => None,
+                }
+            }
+            syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+                syn::Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+                syn::Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+                syn::Lit::Bool(lit) => Some(lit.value.to_string()),
+                syn::Lit::Str(lit) => Some(slint_string_literal(&lit.value())),
+                syn::Lit::Char(lit) => Some(slint_string_literal(&lit.value().to_string())),
+                _ => None,
+            },
+            syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => {
+                Some(format!("-{}", self.convert(expr, ty)?))
+            }
+            syn::Expr::Array(array) => {
+                let elem_ty = match ty {
+                    SlintType::Array(elem_ty) => elem_ty.as_ref(),
+                    ty => ty,
+                };
+                let elems = array.elems.iter()
+                    .map(|elem| self.convert(elem, elem_ty))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("[{}]", elems.join(", ")))
+            }
+            // `i32::MAX` 같은 연관 상수는 Slint 에 없으므로, 알려진 열거형의 `Enum::Variant` 만 옮깁니다.
+            syn::Expr::Path(expr_path) if expr_path.qself.is_none() && expr_path.path.segments.len() == 2 => {
+                let enum_name = &expr_path.path.segments[0];
+                let variant = &expr_path.path.segments[1];
+                if !enum_name.arguments.is_none() || !self.enums.contains(&enum_name.ident.to_string()) {
+                    return None;
+                }
+                Some(format!("{}.{}", enum_name.ident, variant.ident))
+            }
+            syn::Expr::Paren(paren) => self.convert(&paren.expr, ty),
+            syn::Expr::Group(group) => self.convert(&group.expr, ty),
+            _ => None,
+        }
+    }
+
+    /// 정수 리터럴을 `int`(`i32`) 범위로 옮깁니다. 범위를 벗어나면 필드의 `overflow` 방식을 런타임과 같이 적용합니다.
+    fn int_literal(&self, value: i128) -> Option<String> {
+        let value = match i32::try_from(value) {
+            Ok(value) => value,
+            Err(_) => match self.overflow {
+                parser::Overflow::Saturate => if value < 0 { i32::MIN } else { i32::MAX },
+                parser::Overflow::Wrap => value as i32,
+                parser::Overflow::Reject => return None,
+            },
+        };
+        Some(value.to_string())
+    }
+}
+
+/// 정수 리터럴(`5`, `-5`, `(5)`)의 값입니다. `i128` 로 나타낼 수 없을 만큼 크면 범위 끝의 값으로 봅니다.
+fn int_literal(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => {
+            Some(lit.base10_parse::<i128>().unwrap_or(i128::MAX))
+        }
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => int_literal(expr).map(|value| value.saturating_neg()),
+        syn::Expr::Paren(paren) => int_literal(&paren.expr),
+        syn::Expr::Group(group) => int_literal(&group.expr),
+        _ => None,
+    }
+}
+
+/// Slint 문자열 리터럴을 만듭니다. Slint 에서 `\{` 는 보간(`"\{expr}"`)의 시작이므로 `\` 를 이스케이프하며,
+/// `{` 만으로는 보간이 되지 않으므로 그대로 둡니다.
+fn slint_string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            c => literal.push(c),
+        }
     }
+    literal.push('"');
+    literal
+}
+
+fn rust_type_to_slint_type(ty: &Type, user_types: &UserTypes) -> String {
+    user_types.classify(ty).slint_name()
+}
 
-    let type_str = quote!(#ty).to_string();
-
-    // 매칭을 위해 타입 문자열에서 공백 제거
-    let type_str_clean = type_str.replace(" ", "");
-
-    match type_str_clean.as_str() {
-        // 정수형
-        "i8" | "i16" | "i32" | "i64" | "isize" => "int".to_string(),
-        "u8" | "u16" | "u32" | "u64" | "usize" => "int".to_string(),
-        // 부동 소수점
-        "f32" | "f64" => "float".to_string(),
-        // 불리언
-        "bool" => "bool".to_string(),
-        // 문자
-        "char" => "string".to_string(),
-        // 문자열
-        s if (s.starts_with("ArrayString<") || s.starts_with("ArrayString::<")) && s.ends_with(">") => "string".to_string(),
-        "String" => "string".to_string(),
-        // 유닛 타입
-        "()" => "void".to_string(),
-        // 기본 폴백
-        _ => type_str,
+/// 콜백 인자 타입을 Slint 인자 타입 목록으로 변환합니다. `()` 는 인자 없음, 튜플은 여러 인자입니다.
+fn callback_arg_types(ty: &Type, user_types: &UserTypes) -> Vec<String> {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.iter().map(|ty| rust_type_to_slint_type(ty, user_types)).collect(),
+        ty => vec![rust_type_to_slint_type(ty, user_types)],
     }
 }
 
@@ -238,53 +528,95 @@ fn is_unit_ty(ty: &Type) -> bool {
     }
 }
 
-fn replace_or_append_block<F>(mut content: String, header_pattern: &str, new_body: &str, default_block_gen: F) -> String
+/// 기존 파일을 해석합니다. 안전하게 병합할 수 없는 파일은 위치와 함께 오류를 반환합니다.
+fn parse_slint_file<'a>(content: &'a str, path: &Path) -> anyhow::Result<SlintFile<'a>> {
+    SlintFile::parse(content).map_err(|err| anyhow::anyhow!("cannot merge generated code into {}:{}", path.display(), err))
+}
+
+fn replace_or_append_block<F>(content: String, path: &Path, kind: DeclarationKind, name: &str, new_body: &str, default_block_gen: F) -> anyhow::Result<String>
 where F: Fn() -> String
 {
-    match find_block_range(&content, header_pattern) {
-        Some((start, end)) => {
-            let mut new_content = String::with_capacity(content.len() + new_body.len());
-            new_content.push_str(&content[..start+1]);
-            new_content.push('\n');
-            new_content.push_str(new_body);
-            new_content.push('\n');
-            new_content.push_str(&content[end..]);
-            new_content
-        },
-        None => {
-            if !content.is_empty() && !content.ends_with('\n') {
-                content.push('\n');
-            }
-            if !content.is_empty() {
-                content.push('\n');
-            }
-            content.push_str(&default_block_gen());
-            content.push('\n');
-            content
-        }
-    }
+    let file = parse_slint_file(&content, path)?;
+    Ok(match file.find(kind, name) {
+        Some(declaration) => replace_body(&file, declaration, new_body)?,
+        None => append_block(content, &default_block_gen()),
+    })
 }
 
-fn find_block_range(content: &str, header_pattern: &str) -> Option<(usize, usize)> {
-    let header_start = content.find(header_pattern)?;
-    let rest = &content[header_start..];
-    let brace_offset = rest.find('{')?;
-    let start_index = header_start + brace_offset;
+/// 선언의 본문을 새 본문과 맞춥니다. 선언 밖의 내용은 그대로 유지합니다.
+///
+/// 구문은 `Statement::key` 로 짝을 지으며, 내용이 바뀐 구문만 새 구문으로 바꾸고 새 본문에 없는 구문은 지웁니다.
+/// 새 구문은 새 본문에서 바로 앞에 오는 구문 뒤에 삽입합니다. 구문 사이의 공백과 주석은 그대로 두되,
+/// 새 구문에 문서 주석이 있으면 바로 위에 붙은 주석을 문서 주석으로 바꿉니다.
+fn replace_body(file: &SlintFile, declaration: &Declaration, new_body: &str) -> anyhow::Result<String> {
+    let content = file.source();
+    let generated_source = format!("{} Generated {{\n{}\n}}", declaration.kind.keyword(), new_body);
+    let generated_file = SlintFile::parse(&generated_source)
+        .map_err(|err| anyhow::anyhow!("generated code for `{}` cannot be parsed: {}", declaration.name, err))?;
+    let generated = generated_file.statements(&generated_file.declarations()[0]);
+    let existing = file.statements(declaration);
+
+    // (시작, 끝, 바꿀 내용). 같은 위치의 삽입은 넣은 순서대로 적용합니다.
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+    let mut used = vec![false; existing.len()];
+    let mut anchor = declaration.open_brace + 1;
+
+    for new in &generated {
+        let new_comments = &generated_source[new.comment_start..new.start];
+        let new_text = &generated_source[new.start..new.end];
+
+        let Some(index) = existing.iter().enumerate().position(|(i, old)| !used[i] && old.key == new.key) else {
+            let line_start = generated_source[..new.comment_start].rfind('\n').map_or(0, |i| i + 1);
+            edits.push((anchor, anchor, format!("\n{}", &generated_source[line_start..new.end])));
+            continue;
+        };
+        used[index] = true;
+        let old = &existing[index];
+
+        if !new_comments.is_empty() && content[old.comment_start..old.start] != *new_comments {
+            edits.push((old.comment_start, old.start, new_comments.to_string()));
+        }
+        if old.text != new.text {
+            edits.push((old.start, old.end, new_text.to_string()));
+        }
 
-    let mut balance = 0;
+        // 같은 줄에 다른 구문이 없으면 줄 끝(뒤따르는 주석 포함) 뒤에 삽입합니다.
+        let next_start = existing.iter().map(|s| s.comment_start).filter(|&start| start > old.end).min().unwrap_or(declaration.close_brace);
+        anchor = match content[old.end..next_start].find('\n') {
+            Some(offset) => old.end + offset,
+            None => old.end,
+        };
+    }
 
-    for (i, c) in content[start_index..].char_indices() {
-        if c == '{' {
-            balance += 1;
-        } else if c == '}' {
-            balance -= 1;
-            if balance == 0 {
-                return Some((start_index, start_index + i));
-            }
-        }
+    // 새 본문에 없는 구문은 바로 위의 주석과 함께 지우고, 줄에 남는 것이 없으면 줄도 지웁니다.
+    for (old, _) in existing.iter().zip(&used).filter(|(_, used)| !**used) {
+        let line_start = content[..old.comment_start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = content[old.end..].find('\n').map_or(content.len(), |i| old.end + i + 1);
+        let start = if content[line_start..old.comment_start].trim().is_empty() { line_start } else { old.comment_start };
+        let end = if content[old.end..line_end].trim().is_empty() && line_end <= declaration.close_brace { line_end } else { old.end };
+        edits.push((start, end, String::new()));
     }
 
-    None
+    edits.sort_by_key(|&(start, end, _)| (start, end));
+    let mut new_content = String::with_capacity(content.len() + new_body.len());
+    let mut cursor = 0;
+    for (start, end, replacement) in edits {
+        new_content.push_str(&content[cursor..start]);
+        new_content.push_str(&replacement);
+        cursor = end;
+    }
+    new_content.push_str(&content[cursor..]);
+    Ok(new_content)
 }
 
-
+fn append_block(mut content: String, block: &str) -> String {
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(block);
+    content.push('\n');
+    content
+}
@@ -1,7 +1,14 @@
 
 pub mod parser;
 pub mod generator;
+pub mod types;
+pub mod slint_syntax;
+pub mod output;
+pub mod reverse;
+pub mod schema;
 
+mod cache;
 mod scanner;
 
 pub use scanner::*;
+pub use output::{SlintChange, SlintFileDiff, SlintOutput};
@@ -0,0 +1,469 @@
+use crate::slint_syntax::{DeclarationKind, SlintFile};
+use crate::types::UserTypes;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 생성기가 만든 선언과 export 목록을 기록하는 파일 (출력 디렉토리 기준)
+pub const MANIFEST_FILE: &str = ".frand-property-manifest";
+
+const MANIFEST_HEADER: &str = "# frand-property 가 생성한 선언과 export 목록입니다. 직접 수정하지 마세요.";
+
+/// 생성기가 소유한 출력 항목입니다. 이전 실행의 항목 중 이번 실행에 없는 항목은 정리됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OwnedItem {
+    /// `.slint` 파일(출력 디렉토리 기준 경로)의 최상위 선언
+    Declaration { path: String, kind: DeclarationKind, name: String },
+    /// `global/index.slint` 의 export 문
+    Export { statement: String },
+}
+
+impl fmt::Display for OwnedItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OwnedItem::Declaration { path, kind, name } => write!(f, "declaration {} {} {}", path, kind.keyword(), name),
+            OwnedItem::Export { statement } => write!(f, "index {statement}"),
+        }
+    }
+}
+
+impl OwnedItem {
+    fn parse(line: &str) -> Option<Self> {
+        if let Some(statement) = line.strip_prefix("index ") {
+            return Some(OwnedItem::Export { statement: statement.to_string() });
+        }
+        // 경로에 공백이 있을 수 있으므로 뒤에서부터 나눕니다.
+        let mut parts = line.strip_prefix("declaration ")?.rsplitn(3, ' ');
+        let name = parts.next()?.to_string();
+        let kind = DeclarationKind::from_keyword(parts.next()?)?;
+        let path = parts.next()?.to_string();
+        Some(OwnedItem::Declaration { path, kind, name })
+    }
+}
+
+/// 생성할 `.slint` 파일들의 내용을 메모리에 모읍니다.
+/// 기존 파일은 디스크에서 읽어 병합하지만, `write` 를 호출하기 전에는 파일 시스템을 바꾸지 않습니다.
+#[derive(Debug)]
+pub struct SlintOutput {
+    output_dir: PathBuf,
+    /// 출력 디렉토리 기준 경로 → (디스크의 원래 내용, 생성된 내용). 생성된 내용이 `None` 이면 파일을 지웁니다.
+    files: BTreeMap<PathBuf, (Option<String>, Option<String>)>,
+    owned: BTreeSet<OwnedItem>,
+    /// 병합한 `slint_enum!`, `slint_struct!` 타입 이름. 필드 타입을 분류하고 모델 초기값의 `Enum::Variant` 를 옮길 때 사용합니다.
+    user_types: UserTypes,
+}
+
+impl SlintOutput {
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            files: BTreeMap::new(),
+            owned: BTreeSet::new(),
+            user_types: UserTypes::default(),
+        }
+    }
+
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// 지금까지 기록한 `slint_enum!`, `slint_struct!` 타입 이름입니다.
+    pub fn user_types(&self) -> &UserTypes {
+        &self.user_types
+    }
+
+    /// `slint_enum!` 타입을 기록합니다. 모델보다 먼저 기록해야 모델 초기값과 필드 타입에서 사용할 수 있습니다.
+    pub fn declare_enum(&mut self, name: &str) {
+        self.user_types.enums.insert(name.to_string());
+    }
+
+    /// `slint_struct!` 타입을 기록합니다. 이 이름을 쓰는 필드는 알려진 타입과 이름이 같아도 사용자 타입으로 분류합니다.
+    pub fn declare_struct(&mut self, name: &str) {
+        self.user_types.structs.insert(name.to_string());
+    }
+
+    /// 출력 디렉토리 기준 경로의 현재 내용을 읽습니다. 아직 없는 파일은 빈 문자열입니다.
+    pub fn read(&mut self, rel_path: &Path) -> anyhow::Result<String> {
+        if let Some((_, content)) = self.files.get(rel_path) {
+            return Ok(content.clone().unwrap_or_default());
+        }
+
+        let path = self.output_dir.join(rel_path);
+        let original = if path.exists() {
+            Some(fs::read_to_string(&path)?)
+        } else {
+            None
+        };
+        let content = original.clone().unwrap_or_default();
+        self.files.insert(rel_path.to_path_buf(), (original.clone(), original));
+        Ok(content)
+    }
+
+    /// 파일의 새 내용을 기록합니다.
+    pub fn stage(&mut self, rel_path: &Path, content: String) {
+        match self.files.get_mut(rel_path) {
+            Some((_, current)) => *current = Some(content),
+            None => {
+                self.files.insert(rel_path.to_path_buf(), (None, Some(content)));
+            }
+        }
+    }
+
+    /// 생성한 선언을 소유 목록에 기록합니다.
+    pub fn own_declaration(&mut self, rel_path: &Path, kind: DeclarationKind, name: &str) {
+        self.owned.insert(OwnedItem::Declaration {
+            path: slint_path(rel_path),
+            kind,
+            name: name.to_string(),
+        });
+    }
+
+    /// `global/index.slint` 에 추가한 export 문을 소유 목록에 기록합니다.
+    pub fn own_export(&mut self, statement: &str) {
+        self.owned.insert(OwnedItem::Export { statement: statement.to_string() });
+    }
+
+    /// 이전 실행의 매니페스트에 있었지만 이번 실행에서 생성되지 않은 선언과 export 를 지우고, 매니페스트를 갱신합니다.
+    /// 매니페스트에 없는 내용(사용자가 작성한 선언 등)은 건드리지 않습니다.
+    /// 모든 모델을 병합한 뒤에 호출해야 합니다.
+    pub fn prune(&mut self) -> anyhow::Result<()> {
+        let manifest_path = Path::new(MANIFEST_FILE);
+        let previous: Vec<OwnedItem> = self.read(manifest_path)?
+            .lines()
+            .filter_map(OwnedItem::parse)
+            .filter(|item| !self.owned.contains(item))
+            .collect();
+
+        for item in previous {
+            match item {
+                OwnedItem::Declaration { path, kind, name } => self.remove_declaration(Path::new(&path), kind, &name)?,
+                OwnedItem::Export { statement } => self.remove_export(&statement)?,
+            }
+        }
+
+        let mut manifest = String::from(MANIFEST_HEADER);
+        manifest.push('\n');
+        for item in &self.owned {
+            manifest.push_str(&item.to_string());
+            manifest.push('\n');
+        }
+        self.stage(manifest_path, manifest);
+
+        Ok(())
+    }
+
+    fn remove_declaration(&mut self, rel_path: &Path, kind: DeclarationKind, name: &str) -> anyhow::Result<()> {
+        let content = self.read(rel_path)?;
+        let file = SlintFile::parse(&content)
+            .map_err(|err| anyhow::anyhow!("cannot remove stale `{}` from {}:{}", name, self.output_dir.join(rel_path).display(), err))?;
+        let Some(declaration) = file.find(kind, name) else {
+            return Ok(());
+        };
+
+        // 선언 뒤의 줄바꿈과 빈 줄 하나를 함께 지웁니다.
+        let mut end = declaration.close_brace + 1;
+        for _ in 0..2 {
+            let rest = &content[end..];
+            let line_end = rest.find('\n').map(|i| i + 1).unwrap_or(rest.len());
+            if !rest[..line_end].trim().is_empty() {
+                break;
+            }
+            end += line_end;
+        }
+
+        let remaining = format!("{}{}", &content[..declaration.start], &content[end..]);
+        self.set_or_remove(rel_path, remaining);
+        Ok(())
+    }
+
+    fn remove_export(&mut self, statement: &str) -> anyhow::Result<()> {
+        let index_path = Path::new("global").join("index.slint");
+        let content = self.read(&index_path)?;
+        let remaining: String = content
+            .split_inclusive('\n')
+            .filter(|line| line.trim() != statement)
+            .collect();
+        self.set_or_remove(&index_path, remaining);
+        Ok(())
+    }
+
+    /// 내용이 공백뿐이면 파일을 지우고, 아니면 새 내용을 기록합니다.
+    fn set_or_remove(&mut self, rel_path: &Path, content: String) {
+        if content.trim().is_empty() {
+            if let Some((_, current)) = self.files.get_mut(rel_path) {
+                *current = None;
+            }
+        } else {
+            self.stage(rel_path, content);
+        }
+    }
+
+    /// 디스크의 내용과 달라지는 파일들의 변경 목록입니다.
+    pub fn diffs(&self) -> Vec<SlintFileDiff> {
+        self.files.iter()
+            .filter(|(_, (original, generated))| original != generated)
+            .map(|(rel_path, (original, generated))| SlintFileDiff {
+                path: rel_path.clone(),
+                changes: changes(original.as_deref(), generated.as_deref()),
+                original: original.clone(),
+                generated: generated.clone(),
+            })
+            .collect()
+    }
+
+    /// 내용이 달라진 파일만 디스크에 쓰거나 지웁니다.
+    pub fn write(&self) -> anyhow::Result<()> {
+        if !self.output_dir.exists() {
+            fs::create_dir_all(&self.output_dir)?;
+        }
+
+        for diff in self.diffs() {
+            let path = self.output_dir.join(&diff.path);
+            match &diff.generated {
+                Some(content) => {
+                    if let Some(parent) = path.parent() {
+                        if !parent.exists() {
+                            fs::create_dir_all(parent)?;
+                        }
+                    }
+                    fs::write(&path, content)?;
+                }
+                None => fs::remove_file(&path)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Slint 경로 표기(`/` 구분)
+fn slint_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// 한 파일에서 디스크의 내용과 생성될 내용의 차이입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlintFileDiff {
+    /// 출력 디렉토리 기준 경로
+    pub path: PathBuf,
+    /// 디스크의 내용. 파일이 없으면 `None`
+    pub original: Option<String>,
+    /// 생성될 내용. 파일이 지워지면 `None`
+    pub generated: Option<String>,
+    pub changes: Vec<SlintChange>,
+}
+
+impl SlintFileDiff {
+    /// `diff -u` 형식의 차이를 반환합니다.
+    pub fn unified_diff(&self) -> String {
+        let path = slint_path(&self.path);
+        let old_label = match self.original {
+            Some(_) => format!("a/{path}"),
+            None => "/dev/null".to_string(),
+        };
+        let new_label = match self.generated {
+            Some(_) => format!("b/{path}"),
+            None => "/dev/null".to_string(),
+        };
+        unified_diff(self.original.as_deref().unwrap_or_default(), self.generated.as_deref().unwrap_or_default(), &old_label, &new_label)
+    }
+}
+
+/// 파일 안의 변경 사항입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlintChange {
+    /// 파일이 없어 새로 만들어집니다.
+    NewFile,
+    /// 생성된 내용이 모두 정리되어 파일이 지워집니다.
+    DeletedFile,
+    /// 선언이 새로 추가됩니다.
+    AddedDeclaration { kind: DeclarationKind, name: String },
+    /// 선언의 본문이 바뀝니다. `added`/`removed` 는 추가되거나 사라진 본문 줄(필드, 프로퍼티 등)입니다.
+    ChangedDeclaration { kind: DeclarationKind, name: String, added: Vec<String>, removed: Vec<String> },
+    /// 선언이 지워집니다.
+    RemovedDeclaration { kind: DeclarationKind, name: String },
+    /// `index.slint` 에 내보내기 문이 없습니다.
+    MissingExport { statement: String },
+    /// `index.slint` 의 내보내기 문이 지워집니다.
+    StaleExport { statement: String },
+}
+
+impl fmt::Display for SlintChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlintChange::NewFile => write!(f, "new file"),
+            SlintChange::DeletedFile => write!(f, "deleted file"),
+            SlintChange::AddedDeclaration { kind, name } => write!(f, "added {} `{}`", kind.keyword(), name),
+            SlintChange::RemovedDeclaration { kind, name } => write!(f, "removed {} `{}`", kind.keyword(), name),
+            SlintChange::ChangedDeclaration { kind, name, added, removed } => {
+                write!(f, "changed {} `{}`", kind.keyword(), name)?;
+                for line in added {
+                    write!(f, "\n  + {line}")?;
+                }
+                for line in removed {
+                    write!(f, "\n  - {line}")?;
+                }
+                Ok(())
+            }
+            SlintChange::MissingExport { statement } => write!(f, "missing export `{statement}`"),
+            SlintChange::StaleExport { statement } => write!(f, "stale export `{statement}`"),
+        }
+    }
+}
+
+fn changes(original: Option<&str>, generated: Option<&str>) -> Vec<SlintChange> {
+    let mut changes = Vec::new();
+    if original.is_none() {
+        changes.push(SlintChange::NewFile);
+    }
+    if generated.is_none() {
+        changes.push(SlintChange::DeletedFile);
+    }
+    let original = original.unwrap_or_default();
+    let generated = generated.unwrap_or_default();
+
+    // 생성 과정에서 두 내용 모두 해석에 성공했으므로, 실패하면 선언 단위 비교만 생략합니다.
+    if let (Ok(old_file), Ok(new_file)) = (SlintFile::parse(original), SlintFile::parse(generated)) {
+        for declaration in new_file.declarations() {
+            let new_body = &generated[declaration.open_brace + 1..declaration.close_brace];
+            match old_file.find(declaration.kind, &declaration.name) {
+                None => changes.push(SlintChange::AddedDeclaration {
+                    kind: declaration.kind,
+                    name: declaration.name.clone(),
+                }),
+                Some(old) => {
+                    let old_body = &original[old.open_brace + 1..old.close_brace];
+                    if old_body != new_body {
+                        changes.push(SlintChange::ChangedDeclaration {
+                            kind: declaration.kind,
+                            name: declaration.name.clone(),
+                            added: line_difference(new_body, old_body),
+                            removed: line_difference(old_body, new_body),
+                        });
+                    }
+                }
+            }
+        }
+        for declaration in old_file.declarations() {
+            if new_file.find(declaration.kind, &declaration.name).is_none() {
+                changes.push(SlintChange::RemovedDeclaration {
+                    kind: declaration.kind,
+                    name: declaration.name.clone(),
+                });
+            }
+        }
+    }
+
+    let old_lines: Vec<&str> = original.lines().map(str::trim).collect();
+    let new_lines: Vec<&str> = generated.lines().map(str::trim).collect();
+    let is_export = |line: &str| line.starts_with("export {") && line.contains(" from ");
+    for line in new_lines.iter().filter(|line| is_export(line)) {
+        if !old_lines.contains(line) {
+            changes.push(SlintChange::MissingExport { statement: line.to_string() });
+        }
+    }
+    for line in old_lines.iter().filter(|line| is_export(line)) {
+        if !new_lines.contains(line) {
+            changes.push(SlintChange::StaleExport { statement: line.to_string() });
+        }
+    }
+
+    changes
+}
+
+/// `a` 에만 있는 비어 있지 않은 줄(앞뒤 공백 제거)
+fn line_difference(a: &str, b: &str) -> Vec<String> {
+    let b_lines: Vec<&str> = b.lines().map(str::trim).collect();
+    a.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !b_lines.contains(line))
+        .map(str::to_string)
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+const DIFF_CONTEXT: usize = 3;
+
+/// 두 텍스트의 `diff -u` 형식 차이를 만듭니다. 내용이 같으면 빈 문자열입니다.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+
+    if ops.iter().all(|(op, _, _)| *op == LineOp::Equal) {
+        return String::new();
+    }
+
+    let mut out = format!("--- {old_label}\n+++ {new_label}\n");
+
+    // 변경된 줄과 그 주변 문맥을 묶어 hunk 를 만듭니다. 문맥이 맞닿는 변경은 하나의 hunk 로 합칩니다.
+    let changed: Vec<usize> = ops.iter().enumerate().filter(|(_, (op, _, _))| *op != LineOp::Equal).map(|(i, _)| i).collect();
+    let mut hunk_start = 0;
+    while hunk_start < changed.len() {
+        let mut hunk_end = hunk_start;
+        while hunk_end + 1 < changed.len() && changed[hunk_end + 1] - changed[hunk_end] <= DIFF_CONTEXT * 2 + 1 {
+            hunk_end += 1;
+        }
+
+        let from = changed[hunk_start].saturating_sub(DIFF_CONTEXT);
+        let to = (changed[hunk_end] + DIFF_CONTEXT + 1).min(ops.len());
+        let hunk = &ops[from..to];
+
+        let old_count = hunk.iter().filter(|(op, _, _)| *op != LineOp::Insert).count();
+        let new_count = hunk.iter().filter(|(op, _, _)| *op != LineOp::Delete).count();
+        let (_, old_index, new_index) = hunk[0];
+        let old_start = if old_count == 0 { old_index } else { old_index + 1 };
+        let new_start = if new_count == 0 { new_index } else { new_index + 1 };
+        out.push_str(&format!("@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"));
+
+        for (op, old_index, new_index) in hunk {
+            match op {
+                LineOp::Equal => out.push_str(&format!(" {}\n", old_lines[*old_index])),
+                LineOp::Delete => out.push_str(&format!("-{}\n", old_lines[*old_index])),
+                LineOp::Insert => out.push_str(&format!("+{}\n", new_lines[*new_index])),
+            }
+        }
+
+        hunk_start = hunk_end + 1;
+    }
+
+    out
+}
+
+/// 최장 공통 부분열로 줄 단위 편집 목록을 만듭니다. 각 항목은 (연산, 이전 줄 위치, 새 줄 위치)입니다.
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<(LineOp, usize, usize)> {
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::with_capacity(old.len() + new.len());
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((LineOp::Equal, i, j));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push((LineOp::Delete, i, j));
+            i += 1;
+        } else {
+            ops.push((LineOp::Insert, i, j));
+            j += 1;
+        }
+    }
+    ops
+}
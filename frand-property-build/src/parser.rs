@@ -1,7 +1,7 @@
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    token, Ident, Token, Type, Visibility,
+    token, Attribute, Expr, Ident, Token, Type, Visibility,
 };
 use quote::quote;
 
@@ -21,16 +21,147 @@ pub fn parse_len_expr(input: ParseStream) -> syn::Result<proc_macro2::TokenStrea
     }
 }
 
+/// 필드 타입 뒤의 `= expr` 초기값을 파싱합니다.
+pub fn parse_init_expr(input: ParseStream) -> syn::Result<Option<Expr>> {
+    if input.peek(Token![=]) {
+        input.parse::<Token![=]>()?;
+        Ok(Some(input.parse()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// 필드 속성 중 `#[frand(...)]`로 지정하는 frand 전용 옵션입니다.
+#[derive(Default, Clone)]
+pub struct FieldOptions {
+    /// `#[frand(rename = "slint-name")]`: Slint 측 프로퍼티 이름을 지정합니다.
+    pub rename: Option<syn::LitStr>,
+    /// `#[frand(overflow = "saturate" | "wrap" | "reject")]`: Slint `int` 와 정수, Slint `duration` 과 `Duration` 변환 시 범위를 벗어난 값의 처리 방식.
+    pub overflow: Option<(Overflow, proc_macro2::Span)>,
+}
+
+/// Slint `int`(`i32`) 범위를 벗어난 정수 값의 처리 방식입니다.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    #[default]
+    Saturate,
+    Wrap,
+    Reject,
+}
+
+impl FieldOptions {
+    /// 지정된 처리 방식. 지정하지 않으면 `Saturate` 입니다.
+    pub fn overflow(&self) -> Overflow {
+        self.overflow.map(|(overflow, _)| overflow).unwrap_or_default()
+    }
+}
+
+/// 속성 목록을 `#[frand(...)]` 옵션과 그대로 전달할 나머지 속성으로 나눕니다.
+pub fn split_frand_attrs(attrs: Vec<Attribute>) -> syn::Result<(Vec<Attribute>, FieldOptions)> {
+    let mut passthrough = Vec::new();
+    let mut options = FieldOptions::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("frand") {
+            passthrough.push(attr);
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let name: syn::LitStr = meta.value()?.parse()?;
+                if !is_slint_ident(&name.value()) {
+                    return Err(syn::Error::new(name.span(), "`rename` must be a valid Slint identifier"));
+                }
+                options.rename = Some(name);
+                Ok(())
+            } else if meta.path.is_ident("overflow") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                let overflow = match value.value().as_str() {
+                    "saturate" => Overflow::Saturate,
+                    "wrap" => Overflow::Wrap,
+                    "reject" => Overflow::Reject,
+                    _ => return Err(syn::Error::new(value.span(), "`overflow` must be one of \"saturate\", \"wrap\", \"reject\"")),
+                };
+                options.overflow = Some((overflow, value.span()));
+                Ok(())
+            } else {
+                Err(meta.error("unknown frand option, expected `rename` or `overflow`"))
+            }
+        })?;
+    }
+
+    Ok((passthrough, options))
+}
+
+/// 문서 주석(`///`, `#[doc = "..."]`)을 줄 단위 문자열로 추출합니다.
+pub fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }),
+                ..
+            }) => Some(lit.value()),
+            _ => None,
+        })
+        .flat_map(|doc| doc.split('\n').map(|line| {
+            line.strip_prefix(' ').unwrap_or(line).trim_end().to_string()
+        }).collect::<Vec<_>>())
+        .collect()
+}
+
+/// 문서 주석 속성만 걸러냅니다.
+pub fn doc_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("doc")).collect()
+}
+
+fn is_slint_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn parse_model_attrs(input: ParseStream) -> syn::Result<Vec<Attribute>> {
+    let attrs = input.call(Attribute::parse_outer)?;
+    if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("frand")) {
+        return Err(syn::Error::new_spanned(attr, "`#[frand(...)]` options are only supported on fields"));
+    }
+    Ok(attrs)
+}
+
 #[derive(PartialEq, Clone)]
 pub enum Direction {
     In,
     Out,
     Model,
     Callback,
+    /// 다른 필드로부터 계산되는 읽기 전용 필드. 초기값 자리에 계산 클로저를 지정합니다.
+    Computed,
+    /// `model!` 전용 양방향 필드. 키워드가 없는 필드와 같습니다.
+    InOut,
+    /// `slint_model!` 전용 비동기 요청 필드. Slint 가 요청하고 Rust 가 처리한 뒤 결과를 알립니다.
+    Action,
+}
+
+impl Direction {
+    /// 필드 선언에 쓰는 방향 키워드
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Direction::In => "in",
+            Direction::Out => "out",
+            Direction::Model => "model",
+            Direction::Callback => "callback",
+            Direction::Computed => "computed",
+            Direction::InOut => "inout",
+            Direction::Action => "action",
+        }
+    }
 }
 
 // Model 구조체 정의
 pub struct Model {
+    pub attrs: Vec<Attribute>,
     pub vis: Visibility,
     pub model_name: Ident,
     pub len: Option<proc_macro2::TokenStream>,
@@ -41,15 +172,23 @@ pub struct Model {
 
 // Model 필드 정의
 pub struct ModelField {
+    /// `#[frand(...)]`을 제외한 속성. 생성되는 필드에 그대로 전달됩니다.
+    pub attrs: Vec<Attribute>,
+    pub options: FieldOptions,
     pub vis: Visibility,
-    pub is_model: bool,
+    /// 키워드가 없는 필드는 `Direction::InOut` 입니다.
+    pub direction: Direction,
     pub name: Ident,
     pub _colon_token: Token![:],
     pub ty: Type,
+    /// `= expr` 로 선언된 초기값. 배열 필드는 요소를 순회할 수 있는 식(`[1, 2, 3]`)이어야 합니다.
+    /// `computed` 필드는 소스 필드 이름을 인자로 받는 클로저(`|a, b| a + b`)입니다.
+    pub init: Option<Expr>,
 }
 
 impl Parse for Model {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = parse_model_attrs(input)?;
         let vis: Visibility = input.parse()?;
         
         let model_name: Ident = input.parse()?;
@@ -64,6 +203,7 @@ impl Parse for Model {
 
         let content;
         Ok(Model {
+            attrs,
             vis,
             model_name,
             len,
@@ -74,22 +214,40 @@ impl Parse for Model {
     }
 }
 
+impl ModelField {
+    pub fn is_model(&self) -> bool {
+        self.direction == Direction::Model
+    }
+
+    pub fn is_computed(&self) -> bool {
+        self.direction == Direction::Computed
+    }
+}
+
 impl Parse for ModelField {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (attrs, options) = split_frand_attrs(input.call(Attribute::parse_outer)?)?;
         let vis: Visibility = input.parse()?;
         
-        // 'model' 키워드 확인
-        let is_model = if input.peek(Ident) {
+        // 방향 키워드 확인 (없으면 inout)
+        let direction = if input.peek(Token![in]) {
+            input.parse::<Token![in]>()?;
+            Direction::In
+        } else if input.peek(Ident) && !input.peek2(Token![:]) {
             let fork = input.fork();
             let ident: Ident = fork.parse()?;
-            if ident == "model" {
-                input.parse::<Ident>()?;
-                true
-            } else {
-                false
-            }
+            let direction = match ident.to_string().as_str() {
+                "out" => Direction::Out,
+                "inout" => Direction::InOut,
+                "callback" => Direction::Callback,
+                "model" => Direction::Model,
+                "computed" => Direction::Computed,
+                _ => return Err(syn::Error::new(ident.span(), "expected `in`, `out`, `inout`, `callback`, `model`, `computed` or a field name")),
+            };
+            input.parse::<Ident>()?;
+            direction
         } else {
-            false
+            Direction::InOut
         };
 
         let name = input.parse()?;
@@ -107,12 +265,17 @@ impl Parse for ModelField {
             }
         }
 
+        let init = parse_init_expr(input)?;
+
         Ok(ModelField {
+            attrs,
+            options,
             vis,
-            is_model,
+            direction,
             name,
             _colon_token,
             ty,
+            init,
         })
     }
 }
@@ -122,8 +285,22 @@ mod kw {
     syn::custom_keyword!(to);
 }
 
+/// `export to "path.slint";` 선언을 파싱합니다.
+fn parse_export_path(input: ParseStream) -> syn::Result<Option<String>> {
+    if input.peek(kw::export) {
+        input.parse::<kw::export>()?;
+        input.parse::<kw::to>()?;
+        let path: syn::LitStr = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(Some(path.value()))
+    } else {
+        Ok(None)
+    }
+}
+
 pub struct SlintModel {
     pub export_path: Option<String>,
+    pub attrs: Vec<Attribute>,
     pub vis: Visibility,
     pub model_name: Ident,
     pub len: Option<proc_macro2::TokenStream>,
@@ -135,25 +312,28 @@ pub struct SlintModel {
 }
 
 pub struct SlintModelField {
+    /// `#[frand(...)]`을 제외한 속성. 생성되는 필드에 그대로 전달됩니다.
+    pub attrs: Vec<Attribute>,
+    pub options: FieldOptions,
     pub vis: Visibility,
     pub direction: Direction,
     pub name: Ident,
     pub _colon_token: Token![:],
     pub ty: Type,
+    /// `callback name: (Args) -> Ret` 로 선언된 반환 타입. Slint 가 Rust 핸들러를 동기적으로 호출하고 결과를 받습니다.
+    /// `action` 필드는 비동기 처리 결과의 타입입니다.
+    pub ret: Option<Type>,
+    /// `= expr` 로 선언된 초기값. 배열 필드는 요소를 순회할 수 있는 식(`[1, 2, 3]`)이어야 합니다.
+    /// `computed` 필드는 소스 필드 이름을 인자로 받는 클로저(`|a, b| a + b`)입니다.
+    pub init: Option<Expr>,
 }
 
 impl Parse for SlintModel {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let export_path = if input.peek(kw::export) {
-            input.parse::<kw::export>()?;
-            input.parse::<kw::to>()?;
-            let path: syn::LitStr = input.parse()?;
-            input.parse::<Token![;]>()?;
-            Some(path.value())
-        } else {
-            None
-        };
-
+        // 속성은 `export to "...";` 앞뒤 어디에 두어도 됩니다.
+        let mut attrs = parse_model_attrs(input)?;
+        let export_path = parse_export_path(input)?;
+        attrs.extend(parse_model_attrs(input)?);
         let vis: Visibility = input.parse()?;
         
         let model_name: Ident = input.parse()?;
@@ -169,6 +349,7 @@ impl Parse for SlintModel {
         let content;
         Ok(SlintModel {
             export_path,
+            attrs,
             vis,
             model_name,
             len,
@@ -181,8 +362,24 @@ impl Parse for SlintModel {
     }
 }
 
+impl SlintModelField {
+    /// Slint 측 프로퍼티 이름 (kebab-case). `#[frand(rename = "...")]`이 있으면 그 이름을 사용합니다.
+    pub fn slint_name(&self) -> String {
+        match &self.options.rename {
+            Some(name) => name.value(),
+            None => self.name.to_string().replace("_", "-"),
+        }
+    }
+
+    /// Slint 가 생성하는 Rust 코드에서 이 프로퍼티를 가리키는 식별자 (snake_case).
+    pub fn slint_ident(&self) -> Ident {
+        Ident::new(&self.slint_name().replace("-", "_"), self.name.span())
+    }
+}
+
 impl Parse for SlintModelField {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (attrs, options) = split_frand_attrs(input.call(Attribute::parse_outer)?)?;
         let vis: Visibility = input.parse()?;
         
         let direction = if input.peek(Token![in]) {
@@ -200,11 +397,17 @@ impl Parse for SlintModelField {
              } else if ident == "callback" {
                  input.parse::<Ident>()?;
                  Direction::Callback
+             } else if ident == "computed" {
+                 input.parse::<Ident>()?;
+                 Direction::Computed
+             } else if ident == "action" {
+                 input.parse::<Ident>()?;
+                 Direction::Action
              } else {
-                 return Err(syn::Error::new(ident.span(), "expected `in`, `out`, `model`, `callback`"));
+                 return Err(syn::Error::new(ident.span(), "expected `in`, `out`, `model`, `callback`, `computed`, `action`"));
              }
         } else {
-             return Err(input.error("expected `in`, `out`, `model`, `callback`"));
+             return Err(input.error("expected `in`, `out`, `model`, `callback`, `computed`, `action`"));
         };
 
         let name = input.parse()?;
@@ -221,12 +424,147 @@ impl Parse for SlintModelField {
             }
         }
 
+        let ret = if input.peek(Token![->]) {
+            let arrow = input.parse::<Token![->]>()?;
+            if !matches!(direction, Direction::Callback | Direction::Action) {
+                return Err(syn::Error::new(arrow.spans[0], "return types are only supported on `callback` and `action` fields"));
+            }
+            // `(f32) -> String` 의 괄호는 인자 목록이므로 벗겨 냅니다.
+            if let Type::Paren(paren) = ty {
+                ty = *paren.elem;
+            }
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let init = parse_init_expr(input)?;
+
         Ok(SlintModelField {
+            attrs,
+            options,
             vis,
             direction,
             name,
             _colon_token,
             ty,
+            ret,
+            init,
         })
     }
 }
+
+/// `slint_enum!` 정의. Slint `export enum` 과 같은 이름의 Rust 열거형을 선언합니다.
+pub struct SlintEnum {
+    pub export_path: Option<String>,
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub _enum_token: Token![enum],
+    pub name: Ident,
+    pub _brace_token: token::Brace,
+    pub variants: Punctuated<SlintEnumVariant, Token![,]>,
+}
+
+pub struct SlintEnumVariant {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+}
+
+/// `slint_struct!` 정의. Slint `export struct` 와 같은 이름의 Rust 구조체를 선언합니다.
+pub struct SlintStruct {
+    pub export_path: Option<String>,
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub _struct_token: Token![struct],
+    pub name: Ident,
+    pub _brace_token: token::Brace,
+    pub fields: Punctuated<SlintStructField, Token![,]>,
+}
+
+pub struct SlintStructField {
+    /// `#[frand(...)]`을 제외한 속성. 생성되는 필드에 그대로 전달됩니다.
+    pub attrs: Vec<Attribute>,
+    pub options: FieldOptions,
+    pub vis: Visibility,
+    pub name: Ident,
+    pub _colon_token: Token![:],
+    pub ty: Type,
+}
+
+impl Parse for SlintEnum {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = parse_model_attrs(input)?;
+        let export_path = parse_export_path(input)?;
+        attrs.extend(parse_model_attrs(input)?);
+
+        let content;
+        Ok(SlintEnum {
+            export_path,
+            attrs,
+            vis: input.parse()?,
+            _enum_token: input.parse()?,
+            name: input.parse()?,
+            _brace_token: syn::braced!(content in input),
+            variants: Punctuated::<SlintEnumVariant, Token![,]>::parse_terminated(&content)?,
+        })
+    }
+}
+
+impl Parse for SlintEnumVariant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = parse_model_attrs(input)?;
+        let name: Ident = input.parse()?;
+        if !input.is_empty() && !input.peek(Token![,]) {
+            return Err(input.error("`slint_enum!` variants cannot have fields or discriminants"));
+        }
+        Ok(SlintEnumVariant { attrs, name })
+    }
+}
+
+impl Parse for SlintStruct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = parse_model_attrs(input)?;
+        let export_path = parse_export_path(input)?;
+        attrs.extend(parse_model_attrs(input)?);
+
+        let content;
+        Ok(SlintStruct {
+            export_path,
+            attrs,
+            vis: input.parse()?,
+            _struct_token: input.parse()?,
+            name: input.parse()?,
+            _brace_token: syn::braced!(content in input),
+            fields: Punctuated::<SlintStructField, Token![,]>::parse_terminated(&content)?,
+        })
+    }
+}
+
+impl Parse for SlintStructField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (attrs, options) = split_frand_attrs(input.call(Attribute::parse_outer)?)?;
+        Ok(SlintStructField {
+            attrs,
+            options,
+            vis: input.parse()?,
+            name: input.parse()?,
+            _colon_token: input.parse()?,
+            ty: input.parse()?,
+        })
+    }
+}
+
+impl SlintStructField {
+    /// Slint 측 필드 이름 (kebab-case). `#[frand(rename = "...")]`이 있으면 그 이름을 사용합니다.
+    pub fn slint_name(&self) -> String {
+        match &self.options.rename {
+            Some(name) => name.value(),
+            None => self.name.to_string().replace("_", "-"),
+        }
+    }
+
+    /// Slint 가 생성하는 Rust 구조체에서 이 필드를 가리키는 식별자 (snake_case).
+    pub fn slint_ident(&self) -> Ident {
+        Ident::new(&self.slint_name().replace("-", "_"), self.name.span())
+    }
+}
@@ -0,0 +1,232 @@
+//! `.slint` 파일의 global 과 데이터 struct 로부터 `slint_model!` 선언을 만듭니다.
+//!
+//! `in-out property <[{Name}Data]> data` 를 가진 global 을 모델로 보고, 데이터 struct 의 필드를 모델 필드로 옮깁니다.
+//! 필드의 방향은 같은 파일의 컴포넌트(또는 global)에 선언된 `x`/`global-x` 프로퍼티의 `in-out`/`out` 으로 정하고,
+//! global 의 콜백은 첫 번째 인자(데이터 인덱스)를 뺀 `callback` 필드가 됩니다.
+//! 생성기가 만드는 `has-x`(`Option`)와 `x-pending`/`x-error`/`x-result`(`action`) 필드도 원래 형태로 되돌립니다.
+
+use crate::slint_syntax::{DeclarationKind, Member, MemberKind, SlintFile};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// 하나의 global 로부터 만든 `slint_model!` 선언입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReversedModel {
+    pub global_name: String,
+    /// `slint_model! { ... }` 소스
+    pub source: String,
+    /// 옮기지 못했거나 추측으로 옮긴 필드
+    pub issues: Vec<ReverseIssue>,
+}
+
+/// 옮기지 못했거나 추측으로 옮긴 필드와 그 이유입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReverseIssue {
+    pub field: String,
+    pub message: String,
+}
+
+/// `content` 의 모든 모델 global 에 대한 `slint_model!` 선언을 만듭니다.
+/// `export_path` 가 있으면 `export to "...";` 를 추가합니다.
+pub fn reverse_slint_models(content: &str, path: &Path, export_path: Option<&str>) -> anyhow::Result<Vec<ReversedModel>> {
+    let file = SlintFile::parse(content).map_err(|err| anyhow::anyhow!("cannot read models from {}:{}", path.display(), err))?;
+
+    let type_names: HashSet<&str> = file.declarations().iter()
+        .filter(|d| matches!(d.kind, DeclarationKind::Struct | DeclarationKind::Enum))
+        .map(|d| d.name.as_str())
+        .collect();
+
+    // 모든 컴포넌트와 global 의 프로퍼티 방향. 생성된 컴포넌트는 `global-` 접두사를 붙입니다.
+    let mut directions: HashMap<String, &str> = HashMap::new();
+    for declaration in file.declarations().iter().filter(|d| matches!(d.kind, DeclarationKind::Component | DeclarationKind::Global)) {
+        for member in file.members(declaration).into_iter().filter(|m| m.kind == MemberKind::Property) {
+            let direction = match member.qualifier.as_deref() {
+                Some("in" | "in-out") => "in",
+                Some("out") => "out",
+                _ => continue,
+            };
+            let name = member.name.strip_prefix("global-").unwrap_or(&member.name).to_string();
+            directions.entry(name).or_insert(direction);
+        }
+    }
+
+    let mut models = Vec::new();
+    for global in file.declarations().iter().filter(|d| d.kind == DeclarationKind::Global) {
+        let members = file.members(global);
+        let Some(data) = members.iter().find(|m| m.kind == MemberKind::Property && m.name == "data") else {
+            continue;
+        };
+        let Some(data_struct) = data.ty.as_deref()
+            .and_then(|ty| ty.strip_prefix('[')?.strip_suffix(']'))
+            .and_then(|name| file.find(DeclarationKind::Struct, name))
+        else {
+            continue;
+        };
+
+        let fields = file.members(data_struct);
+        let callbacks: Vec<&Member> = members.iter().filter(|m| m.kind == MemberKind::Callback).collect();
+        let mut reverser = Reverser { type_names: &type_names, directions: &directions, lines: Vec::new(), issues: Vec::new() };
+        reverser.model(&fields, &callbacks);
+
+        let model_name = format!("{}Model", global.name.strip_suffix("Global").unwrap_or(&global.name));
+        let mut source = String::from("slint_model! {\n");
+        if let Some(export_path) = export_path {
+            source.push_str(&format!("    export to \"{export_path}\";\n"));
+        }
+        push_doc(&mut source, "    ", &data.comments);
+        source.push_str(&format!("    pub {}: {} {{\n", model_name, global.name));
+        for line in &reverser.lines {
+            source.push_str(&format!("        {line}\n"));
+        }
+        source.push_str("    }\n}\n");
+
+        models.push(ReversedModel { global_name: global.name.clone(), source, issues: reverser.issues });
+    }
+
+    Ok(models)
+}
+
+struct Reverser<'a> {
+    type_names: &'a HashSet<&'a str>,
+    directions: &'a HashMap<String, &'a str>,
+    lines: Vec<String>,
+    issues: Vec<ReverseIssue>,
+}
+
+impl Reverser<'_> {
+    fn model(&mut self, fields: &[Member], callbacks: &[&Member]) {
+        let field_names: HashSet<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+        let actions: HashSet<&str> = callbacks.iter()
+            .filter(|c| field_names.contains(format!("{}-pending", c.name).as_str()) && field_names.contains(format!("{}-error", c.name).as_str()))
+            .map(|c| c.name.as_str())
+            .collect();
+        // 생성기가 추가한 필드는 원래 필드나 action 으로 되돌리므로 건너뜁니다.
+        let generated = |name: &str| {
+            name.strip_prefix("has-").is_some_and(|inner| field_names.contains(inner))
+                || actions.iter().any(|action| [format!("{action}-error"), format!("{action}-result")].iter().any(|f| f == name))
+        };
+
+        // 콜백은 global 에 선언된 순서를 따릅니다. action 은 `x-pending` 필드 자리에 두고,
+        // 그보다 앞에 선언된 콜백을 먼저 둡니다.
+        let mut emitted = HashSet::new();
+        for field in fields {
+            if let Some(action) = field.name.strip_suffix("-pending").filter(|name| actions.contains(name)) {
+                let Some(position) = callbacks.iter().position(|c| c.name == action) else { continue };
+                for callback in &callbacks[..position] {
+                    if !actions.contains(callback.name.as_str()) && emitted.insert(callback.name.as_str()) {
+                        self.callback("callback", callback, callback.ret.as_deref());
+                    }
+                }
+                let result = fields.iter().find(|f| f.name == format!("{action}-result")).and_then(|f| f.ty.as_deref());
+                self.callback("action", callbacks[position], result);
+            } else if !generated(&field.name) {
+                self.field(field, field_names.contains(format!("has-{}", field.name).as_str()));
+            }
+        }
+
+        for callback in callbacks {
+            if !actions.contains(callback.name.as_str()) && emitted.insert(callback.name.as_str()) {
+                self.callback("callback", callback, callback.ret.as_deref());
+            }
+        }
+    }
+
+    fn field(&mut self, field: &Member, optional: bool) {
+        let ty = field.ty.as_deref().unwrap_or_default();
+        let Some(mut rust_ty) = self.rust_type(&field.name, ty) else { return };
+        if optional {
+            rust_ty = format!("Option<{rust_ty}>");
+        }
+
+        let direction = match self.directions.get(&field.name) {
+            Some(direction) => *direction,
+            None => {
+                self.issue(&field.name, "no `in-out` or `out` property binds this field; assuming `in`");
+                "in"
+            }
+        };
+
+        push_doc_lines(&mut self.lines, &field.comments);
+        let (rename, name) = rust_name(&field.name);
+        self.lines.extend(rename);
+        self.lines.push(format!("{direction} {name}: {rust_ty},"));
+    }
+
+    fn callback(&mut self, keyword: &str, callback: &Member, ret: Option<&str>) {
+        let mut args = callback.args.as_slice();
+        match args.first().map(String::as_str) {
+            Some("int") => args = &args[1..],
+            _ => self.issue(&callback.name, "the first argument is not the data index (`int`); keeping all arguments"),
+        }
+
+        let mut rust_args = Vec::new();
+        for arg in args {
+            let Some(ty) = self.rust_type(&callback.name, arg) else { return };
+            rust_args.push(ty);
+        }
+        let ret = match ret {
+            Some(ret) => match self.rust_type(&callback.name, ret) {
+                Some(ty) => format!(" -> {ty}"),
+                None => return,
+            },
+            None => String::new(),
+        };
+
+        push_doc_lines(&mut self.lines, &callback.comments);
+        let (rename, name) = rust_name(&callback.name);
+        self.lines.extend(rename);
+        self.lines.push(format!("{keyword} {name}: ({}){ret},", rust_args.join(", ")));
+    }
+
+    /// Slint 타입을 Rust 타입으로 바꿉니다. 바꿀 수 없으면 이유를 기록하고 필드 자리에 주석을 남깁니다.
+    fn rust_type(&mut self, field: &str, ty: &str) -> Option<String> {
+        let rust_ty = match ty {
+            "int" => "i32",
+            "float" => "f32",
+            "bool" => "bool",
+            "string" => "String",
+            "color" => "slint::Color",
+            "brush" => "slint::Brush",
+            "image" => "frand_property::slint::PixelImage",
+            "duration" => "std::time::Duration",
+            "length" => "frand_property::slint::Length",
+            "physical-length" => "frand_property::slint::PhysicalLength",
+            _ if self.type_names.contains(ty) || ty.starts_with(|c: char| c.is_ascii_uppercase()) => ty,
+            _ => {
+                let message = if ty.starts_with('[') {
+                    format!("array type `{ty}` has no fixed length; declare it as `T[N]`")
+                } else {
+                    format!("Slint type `{ty}` has no Rust counterpart")
+                };
+                self.lines.push(format!("// TODO: `{field}`: {message}"));
+                self.issue(field, &message);
+                return None;
+            }
+        };
+        Some(rust_ty.to_string())
+    }
+
+    fn issue(&mut self, field: &str, message: &str) {
+        self.issues.push(ReverseIssue { field: field.to_string(), message: message.to_string() });
+    }
+}
+
+/// Slint 이름에 대응하는 Rust 필드 이름. 기본 규칙(`_` → `-`)으로 되돌릴 수 없으면 `#[frand(rename)]` 을 붙입니다.
+fn rust_name(slint_name: &str) -> (Option<String>, String) {
+    let name = slint_name.replace('-', "_");
+    if syn::parse_str::<syn::Ident>(&name).is_err() {
+        return (Some(format!("#[frand(rename = \"{slint_name}\")]")), format!("{name}_"));
+    }
+    let rename = (name.replace('_', "-") != slint_name).then(|| format!("#[frand(rename = \"{slint_name}\")]"));
+    (rename, name)
+}
+
+fn push_doc_lines(lines: &mut Vec<String>, comments: &[String]) {
+    lines.extend(comments.iter().map(|comment| format!("/// {comment}")));
+}
+
+fn push_doc(source: &mut String, indent: &str, comments: &[String]) {
+    for comment in comments {
+        source.push_str(&format!("{indent}/// {comment}\n"));
+    }
+}
@@ -1,54 +1,608 @@
-use crate::parser::SlintModel;
-use crate::generator::generate_slint_file;
-use std::path::Path;
-use walkdir::WalkDir;
-use syn::visit::Visit;
-use syn::{ItemMacro, parse_file};
+use crate::cache::{hash_of, CachedFile, ScanCache, SCAN_CACHE_FILE};
+use crate::parser::{Model, SlintEnum, SlintModel, SlintStruct};
+use crate::generator::{merge_slint_enum, merge_slint_model, merge_slint_struct};
+use crate::output::{SlintFileDiff, SlintOutput};
+use crate::types::UserTypes;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
+use std::path::{Path, PathBuf};
+use proc_macro2::TokenStream;
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::{Attribute, Item, ItemMacro, ItemMod, Meta, Token, UseTree};
+
+/// `src_dir` 의 모델로 `.slint` 파일을 생성하여 `output_dir` 에 씁니다. 내용이 바뀐 파일만 씁니다.
+/// 생성한 선언과 export 는 `output_dir` 의 매니페스트에 기록하며, 모델이 사라지거나 이름이 바뀌면 이전에 생성한 항목을 지웁니다.
+///
+/// 크레이트 루트(`lib.rs`, `main.rs`, `bin/*.rs` 와 `Cargo.toml` 의 `[lib]`, `[[bin]]` 경로)에서 시작하여 `mod` 선언을 따라가며,
+/// `cfg` 는 빌드 스크립트의 환경 변수로 평가합니다.
+///
+/// 빌드 스크립트에서 호출하면 읽은 소스 파일마다 `cargo:rerun-if-changed` 를 출력하고,
+/// 파싱한 모델 정의를 `OUT_DIR` 에 캐시하여 내용이 바뀐 파일만 다시 파싱합니다.
+pub fn generate_slint_files(src_dir: impl AsRef<Path>, output_dir: impl AsRef<Path>) -> anyhow::Result<()> {
+    let cfg = CfgContext::from_env();
+    let Some(out_dir) = std::env::var_os("OUT_DIR") else {
+        return plan_slint_files(src_dir.as_ref(), output_dir.as_ref(), &cfg)?.write();
+    };
+
+    let cache_path = PathBuf::from(out_dir).join(SCAN_CACHE_FILE);
+    let mut cache = ScanCache::load(&cache_path, cfg.fingerprint());
+    let mut tracked = Vec::new();
+    let items = scan_crate(src_dir.as_ref(), &cfg, ScanOptions::default(), &mut cache, &mut tracked);
+
+    // 오류가 있어도 해당 파일을 고치면 다시 실행되도록 먼저 출력합니다.
+    for path in &tracked {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    cache.save(&cache_path)?;
+
+    build_output(items?, output_dir.as_ref())?.write()
+}
+
+/// 파일 시스템을 바꾸지 않고, `generate_slint_files` 가 쓸 내용과 디스크의 내용이 다른 파일 목록을 반환합니다.
+/// 목록이 비어 있으면 생성된 파일이 최신 상태입니다.
+pub fn check_slint_files(src_dir: impl AsRef<Path>, output_dir: impl AsRef<Path>) -> anyhow::Result<Vec<SlintFileDiff>> {
+    Ok(plan_slint_files(src_dir.as_ref(), output_dir.as_ref(), &CfgContext::from_env())?.diffs())
+}
+
+/// 주어진 `cfg` 조건으로 생성할 내용을 계산합니다. 파일 시스템은 바꾸지 않으며, 반환값의 `write` 로 씁니다.
+pub fn plan_slint_files(src_dir: &Path, output_dir: &Path, cfg: &CfgContext) -> anyhow::Result<SlintOutput> {
+    build_output(scan_slint_items(src_dir, cfg)?, output_dir)
+}
+
+/// 주어진 `cfg` 조건으로 크레이트의 `slint_model!`, `slint_enum!`, `slint_struct!` 선언을 모읍니다.
+/// `model!` 은 모으지 않으므로, 같은 이름의 다른 매크로가 있어도 오류가 되지 않습니다.
+pub fn scan_slint_items(src_dir: impl AsRef<Path>, cfg: &CfgContext) -> anyhow::Result<ScannedItems> {
+    scan_items(src_dir, cfg, ScanOptions::default())
+}
 
-struct SlintModelVisitor {
-    models: Vec<SlintModel>,
+/// 주어진 `cfg` 조건과 `options` 로 크레이트의 모델 선언을 모읍니다.
+pub fn scan_items(src_dir: impl AsRef<Path>, cfg: &CfgContext, options: ScanOptions) -> anyhow::Result<ScannedItems> {
+    scan_crate(src_dir.as_ref(), cfg, options, &mut ScanCache::default(), &mut Vec::new())
+}
+
+/// 크레이트를 탐색할 때 모을 선언입니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScanOptions {
+    /// Slint 와 관계없는 `model!` 도 모읍니다. 스키마를 생성할 때만 필요하며, 해석하지 못한 `model!` 은 오류입니다.
+    pub rust_models: bool,
+}
+
+impl ScanOptions {
+    fn collects(&self, kind: MacroKind) -> bool {
+        kind != MacroKind::RustModel || self.rust_models
+    }
+}
+
+fn build_output(items: ScannedItems, output_dir: &Path) -> anyhow::Result<SlintOutput> {
+    let mut output = SlintOutput::new(output_dir);
+
+    // 구조체와 모델의 필드 타입을 분류할 때 크레이트의 모든 타입 이름을 알 수 있도록 먼저 기록합니다.
+    let user_types = items.user_types();
+    for name in &user_types.enums {
+        output.declare_enum(name);
+    }
+    for name in &user_types.structs {
+        output.declare_struct(name);
+    }
+
+    // 타입 선언을 먼저 써서, 모델 블록이 새로 추가될 때 타입 선언보다 뒤에 오도록 합니다.
+    for item in &items.enums {
+        merge_slint_enum(item, &mut output)?;
+    }
+    for item in &items.structs {
+        merge_slint_struct(item, &mut output)?;
+    }
+    for model in &items.models {
+        merge_slint_model(model, &mut output)?;
+    }
+
+    // 이번에 생성되지 않은 이전 모델의 선언과 export 를 정리합니다.
+    output.prune()?;
+    Ok(output)
+}
+
+/// 모델 선언을 해석하지 못한 위치입니다. 줄과 열은 1부터 시작합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ScanError {
+    fn new(path: &Path, error: &syn::Error) -> Self {
+        let start = error.span().start();
+        Self {
+            path: path.to_path_buf(),
+            line: start.line.max(1),
+            column: start.column + 1,
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}: {}", self.path.display(), self.line, self.column, self.message)
+    }
 }
 
-impl<'ast> Visit<'ast> for SlintModelVisitor {
-    fn visit_item_macro(&mut self, i: &'ast ItemMacro) {
-        if let Some(segment) = i.mac.path.segments.last() {
-            if segment.ident == "slint_model" {
-                 if let Ok(model) = syn::parse2::<SlintModel>(i.mac.tokens.clone()) {
-                     self.models.push(model);
-                 }
+/// 크레이트를 탐색하며 모은 모든 오류입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanErrors(pub Vec<ScanError>);
+
+impl fmt::Display for ScanErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} error(s) while scanning Slint models", self.0.len())?;
+        for error in &self.0 {
+            write!(f, "\n{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ScanErrors {}
+
+/// `cfg` 속성을 평가할 조건입니다.
+#[derive(Debug, Clone, Default)]
+pub struct CfgContext {
+    /// 켜진 기능. `CARGO_FEATURE_*` 와 같이 대문자와 `_` 로 정규화합니다.
+    features: HashSet<String>,
+    /// `target_os = "linux"` 등의 값. 값이 없는 설정(`unix` 등)은 빈 목록입니다.
+    cfgs: HashMap<String, Vec<String>>,
+}
+
+impl CfgContext {
+    /// 빌드 스크립트의 `CARGO_FEATURE_*`, `CARGO_CFG_*` 환경 변수로 조건을 만듭니다.
+    /// 빌드 스크립트 밖에서는 현재 호스트의 대상 정보를 사용합니다.
+    pub fn from_env() -> Self {
+        let mut context = Self::default();
+
+        for (key, value) in std::env::vars() {
+            if let Some(feature) = key.strip_prefix("CARGO_FEATURE_") {
+                context.features.insert(feature.to_string());
+            } else if let Some(name) = key.strip_prefix("CARGO_CFG_") {
+                let values = value.split(',').filter(|v| !v.is_empty()).map(str::to_string).collect();
+                context.cfgs.insert(name.to_lowercase(), values);
+            }
+        }
+
+        if !context.cfgs.contains_key("target_os") {
+            context.cfgs.insert("target_os".to_string(), vec![std::env::consts::OS.to_string()]);
+            context.cfgs.insert("target_family".to_string(), vec![std::env::consts::FAMILY.to_string()]);
+            context.cfgs.insert("target_arch".to_string(), vec![std::env::consts::ARCH.to_string()]);
+            context.cfgs.insert(std::env::consts::FAMILY.to_string(), Vec::new());
+            if cfg!(debug_assertions) {
+                context.cfgs.insert("debug_assertions".to_string(), Vec::new());
+            }
+        }
+
+        context
+    }
+
+    /// 기능을 켭니다.
+    pub fn with_feature(mut self, feature: &str) -> Self {
+        self.features.insert(normalize_feature(feature));
+        self
+    }
+
+    /// 캐시를 구분하기 위한 조건의 해시입니다.
+    fn fingerprint(&self) -> u64 {
+        let mut features: Vec<_> = self.features.iter().collect();
+        features.sort();
+        let mut cfgs: Vec<_> = self.cfgs.iter().collect();
+        cfgs.sort();
+        hash_of((features, cfgs))
+    }
+
+    /// 속성 목록의 모든 `#[cfg(...)]` 가 참인지 평가합니다.
+    pub fn is_enabled(&self, attrs: &[Attribute]) -> bool {
+        attrs.iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            // 해석할 수 없는 조건은 모델을 놓치지 않도록 참으로 봅니다.
+            .all(|attr| attr.parse_args::<Meta>().map_or(true, |meta| self.eval(&meta)))
+    }
+
+    fn eval(&self, meta: &Meta) -> bool {
+        match meta {
+            Meta::Path(path) => path.get_ident().is_some_and(|name| self.cfgs.contains_key(&name.to_string())),
+            Meta::NameValue(name_value) => {
+                let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(value), .. }) = &name_value.value else {
+                    return false;
+                };
+                let Some(key) = name_value.path.get_ident().map(|i| i.to_string()) else {
+                    return false;
+                };
+                if key == "feature" {
+                    self.features.contains(&normalize_feature(&value.value()))
+                } else {
+                    self.cfgs.get(&key).is_some_and(|values| values.contains(&value.value()))
+                }
+            }
+            Meta::List(list) => {
+                let Ok(nested) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+                    return true;
+                };
+                match list.path.get_ident().map(|i| i.to_string()).as_deref() {
+                    Some("all") => nested.iter().all(|meta| self.eval(meta)),
+                    Some("any") => nested.iter().any(|meta| self.eval(meta)),
+                    Some("not") => nested.len() == 1 && !self.eval(&nested[0]),
+                    _ => false,
+                }
             }
         }
     }
 }
 
-pub fn generate_slint_files(src_dir: impl AsRef<Path>, output_dir: impl AsRef<Path>) -> anyhow::Result<()> {
-    let src_dir = src_dir.as_ref();
-    let output_dir = output_dir.as_ref();
-    
-    if !output_dir.exists() {
-        fs::create_dir_all(output_dir)?;
-    }
-
-    for entry in WalkDir::new(src_dir) {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().map(|e| e == "rs").unwrap_or(false) {
-            let content = match fs::read_to_string(path) {
-                Ok(c) => c,
-                Err(_) => continue, // 읽을 수 없는 파일 건너뛰기
-            };
-            
-            // 전체 파일 파싱
-            if let Ok(file) = parse_file(&content) {
-                let mut visitor = SlintModelVisitor { models: Vec::new() };
+fn normalize_feature(feature: &str) -> String {
+    feature.to_uppercase().replace('-', "_")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MacroKind {
+    Model,
+    Enum,
+    Struct,
+    /// Slint 와 관계없는 `model!`. 스키마 생성에만 사용합니다.
+    RustModel,
+}
+
+impl MacroKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "slint_model" => Some(Self::Model),
+            "slint_enum" => Some(Self::Enum),
+            "slint_struct" => Some(Self::Struct),
+            "model" => Some(Self::RustModel),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Model => "slint_model",
+            Self::Enum => "slint_enum",
+            Self::Struct => "slint_struct",
+            Self::RustModel => "model",
+        }
+    }
+}
+
+/// 크레이트에서 찾은 `slint_model!`, `slint_enum!`, `slint_struct!`, `model!` 선언입니다. 모듈을 방문한 순서입니다.
+/// `rust_models` 는 `ScanOptions::rust_models` 로 탐색했을 때만 채워집니다.
+#[derive(Default)]
+pub struct ScannedItems {
+    pub models: Vec<SlintModel>,
+    pub enums: Vec<SlintEnum>,
+    pub structs: Vec<SlintStruct>,
+    pub rust_models: Vec<Model>,
+}
+
+impl ScannedItems {
+    /// 모은 `slint_enum!`, `slint_struct!` 타입 이름입니다.
+    pub fn user_types(&self) -> UserTypes {
+        UserTypes {
+            enums: self.enums.iter().map(|item| item.name.to_string()).collect(),
+            structs: self.structs.iter().map(|item| item.name.to_string()).collect(),
+        }
+    }
+
+    fn push(&mut self, kind: MacroKind, tokens: TokenStream) -> syn::Result<()> {
+        match kind {
+            MacroKind::Model => self.models.push(syn::parse2(tokens)?),
+            MacroKind::Enum => self.enums.push(syn::parse2(tokens)?),
+            MacroKind::Struct => self.structs.push(syn::parse2(tokens)?),
+            MacroKind::RustModel => self.rust_models.push(syn::parse2(tokens)?),
+        }
+        Ok(())
+    }
+}
+
+/// 크레이트 루트에서 시작하여 `mod` 선언을 따라가며 모델 선언을 모읍니다.
+/// 읽은 파일과, 모듈 파일을 찾지 못한 디렉토리를 `tracked` 에 기록합니다.
+fn scan_crate(src_dir: &Path, cfg: &CfgContext, options: ScanOptions, cache: &mut ScanCache, tracked: &mut Vec<PathBuf>) -> anyhow::Result<ScannedItems> {
+    let roots = crate_roots(src_dir, tracked)?;
+    if roots.is_empty() {
+        tracked.push(src_dir.to_path_buf());
+        anyhow::bail!("no crate root (`lib.rs`, `main.rs` or `bin/*.rs`) in {}", src_dir.display());
+    }
+
+    let mut items = ScannedItems::default();
+    let mut errors = Vec::new();
+    let mut visited = HashSet::new();
+    // (파일, 그 파일의 하위 모듈을 찾을 디렉토리)
+    let mut queue: Vec<(PathBuf, PathBuf)> = roots.into_iter()
+        .map(|root| {
+            let dir = root.parent().unwrap_or(Path::new("")).to_path_buf();
+            (root, dir)
+        })
+        .collect();
+
+    while let Some((path, module_dir)) = queue.pop() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        tracked.push(path.clone());
+
+        let content = fs::read_to_string(&path).map_err(|err| anyhow::anyhow!("cannot read {}: {}", path.display(), err))?;
+        let hash = hash_of((&content, &module_dir));
+
+        let scanned = match cache.get(&path, hash) {
+            Some(cached) => {
+                for (name, tokens) in &cached.macros {
+                    let result = MacroKind::from_name(name)
+                        .ok_or_else(|| syn::Error::new(proc_macro2::Span::call_site(), format!("unknown macro `{name}` in scan cache")))
+                        .and_then(|kind| Ok((kind, syn::parse_str::<TokenStream>(tokens)?)))
+                        .and_then(|(kind, tokens)| if options.collects(kind) { items.push(kind, tokens) } else { Ok(()) });
+                    if let Err(err) = result {
+                        errors.push(ScanError::new(&path, &err));
+                    }
+                }
+                cached
+            }
+            None => {
+                let file = match syn::parse_file(&content) {
+                    Ok(file) => file,
+                    Err(err) => {
+                        errors.push(ScanError::new(&path, &err));
+                        continue;
+                    }
+                };
+
+                let mut visitor = ModuleVisitor {
+                    cfg,
+                    path: &path,
+                    module_dir: module_dir.clone(),
+                    inline_depth: 0,
+                    aliases: collect_aliases(cfg, &file.items),
+                    macros: Vec::new(),
+                    modules: Vec::new(),
+                    errors: Vec::new(),
+                    tracked: &mut *tracked,
+                };
                 visitor.visit_file(&file);
+                let ModuleVisitor { macros, modules, errors: mut file_errors, .. } = visitor;
 
-                for model in visitor.models {
-                    generate_slint_file(&model, output_dir)?;
+                let mut cached_macros = Vec::new();
+                for (kind, tokens) in macros.into_iter().filter(|(kind, _)| options.collects(*kind)) {
+                    let text = tokens.to_string();
+                    match items.push(kind, tokens) {
+                        Ok(()) => cached_macros.push((kind.name().to_string(), text)),
+                        Err(err) => file_errors.extend(err.into_iter().map(|err| ScanError::new(&path, &err))),
+                    }
                 }
+
+                let scanned = CachedFile { hash, macros: cached_macros, modules };
+                // 오류가 있는 파일은 다음 빌드에서 다시 보고하도록 캐시하지 않습니다.
+                if file_errors.is_empty() {
+                    cache.insert(&path, scanned.clone());
+                }
+                errors.extend(file_errors);
+                scanned
+            }
+        };
+
+        // 선언 순서대로 방문하도록 뒤집어서 넣습니다.
+        queue.extend(scanned.modules.into_iter().rev());
+    }
+
+    if errors.is_empty() {
+        Ok(items)
+    } else {
+        Err(ScanErrors(errors).into())
+    }
+}
+
+/// 크레이트 루트 파일 목록입니다. Cargo 의 자동 탐색과 같이 `lib.rs`, `main.rs`, `bin/*.rs`, `bin/*/main.rs` 를 찾고,
+/// `src_dir` 옆의 `Cargo.toml` 에 `[lib]`, `[[bin]]` 의 `path` 가 있으면 함께 사용합니다.
+fn crate_roots(src_dir: &Path, tracked: &mut Vec<PathBuf>) -> anyhow::Result<Vec<PathBuf>> {
+    let manifest_dir = src_dir.parent().unwrap_or(Path::new(""));
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    let manifest = if manifest_path.exists() {
+        tracked.push(manifest_path.clone());
+        let content = fs::read_to_string(&manifest_path).map_err(|err| anyhow::anyhow!("cannot read {}: {}", manifest_path.display(), err))?;
+        content.parse::<toml::Table>().map_err(|err| anyhow::anyhow!("cannot parse {}: {}", manifest_path.display(), err))?
+    } else {
+        toml::Table::new()
+    };
+
+    let mut roots: Vec<PathBuf> = ["lib.rs", "main.rs"].iter().map(|name| src_dir.join(name)).collect();
+
+    let autobins = manifest.get("package")
+        .and_then(|package| package.get("autobins"))
+        .and_then(toml::Value::as_bool)
+        .unwrap_or(true);
+    let bin_dir = src_dir.join("bin");
+    if autobins && bin_dir.is_dir() {
+        // 새 바이너리가 추가되면 다시 실행되도록 디렉토리를 감시합니다.
+        tracked.push(bin_dir.clone());
+        let mut bins: Vec<PathBuf> = fs::read_dir(&bin_dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter_map(|path| match path.is_dir() {
+                true => Some(path.join("main.rs")),
+                false => (path.extension().is_some_and(|ext| ext == "rs")).then_some(path),
+            })
+            .collect();
+        bins.sort();
+        roots.extend(bins);
+    }
+
+    let lib_path = manifest.get("lib").and_then(|lib| lib.get("path"));
+    let bin_paths = manifest.get("bin")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|bin| bin.get("path"));
+    roots.extend(lib_path.into_iter().chain(bin_paths)
+        .filter_map(toml::Value::as_str)
+        .map(|path| manifest_dir.join(path)));
+
+    let mut seen = HashSet::new();
+    roots.retain(|path| path.exists() && seen.insert(path.clone()));
+    Ok(roots)
+}
+
+/// 모듈의 `use ... as alias;` 로 가져온 매크로 이름을 모읍니다.
+/// 별칭은 선언한 모듈 안에서만 유효하므로, 하위 모듈(인라인 모듈 포함)의 `use` 는 모으지 않습니다.
+fn collect_aliases(cfg: &CfgContext, items: &[Item]) -> HashMap<String, MacroKind> {
+    let mut aliases = HashMap::new();
+    for item in items {
+        if let Item::Use(item) = item {
+            if cfg.is_enabled(&item.attrs) {
+                collect_use_tree(&item.tree, &mut aliases);
+            }
+        }
+    }
+    aliases
+}
+
+fn collect_use_tree(tree: &UseTree, aliases: &mut HashMap<String, MacroKind>) {
+    match tree {
+        UseTree::Path(path) => collect_use_tree(&path.tree, aliases),
+        UseTree::Rename(rename) => {
+            if let Some(kind) = MacroKind::from_name(&rename.ident.to_string()) {
+                aliases.insert(rename.rename.to_string(), kind);
+            }
+        }
+        UseTree::Group(group) => group.items.iter().for_each(|tree| collect_use_tree(tree, aliases)),
+        UseTree::Name(_) | UseTree::Glob(_) => {}
+    }
+}
+
+struct ModuleVisitor<'a> {
+    cfg: &'a CfgContext,
+    path: &'a Path,
+    /// 현재 위치(인라인 모듈 포함)의 하위 모듈을 찾을 디렉토리
+    module_dir: PathBuf,
+    inline_depth: usize,
+    /// 현재 모듈에서 `use ... as alias;` 로 가져온 매크로 이름
+    aliases: HashMap<String, MacroKind>,
+    macros: Vec<(MacroKind, TokenStream)>,
+    /// 따라갈 모듈 파일과 그 파일의 하위 모듈 디렉토리
+    modules: Vec<(PathBuf, PathBuf)>,
+    errors: Vec<ScanError>,
+    /// 모듈 파일을 찾지 못한 디렉토리. 파일이 생기면 다시 실행되도록 감시합니다.
+    tracked: &'a mut Vec<PathBuf>,
+}
+
+impl ModuleVisitor<'_> {
+    fn macro_kind(&self, path: &syn::Path) -> Option<MacroKind> {
+        let segment = path.segments.last()?;
+        let name = segment.ident.to_string();
+        if path.segments.len() == 1 {
+            if let Some(kind) = self.aliases.get(&name) {
+                return Some(*kind);
+            }
+        }
+        let kind = MacroKind::from_name(&name)?;
+        // `model!` 은 흔한 이름이므로 경로 없이 호출했거나 `frand_property::model!` 인 경우만 인식합니다.
+        if kind == MacroKind::RustModel && path.segments.len() > 1 && path.segments[0].ident != "frand_property" {
+            return None;
+        }
+        Some(kind)
+    }
+
+    fn push_errors(&mut self, error: syn::Error) {
+        for error in error {
+            self.errors.push(ScanError::new(self.path, &error));
+        }
+    }
+
+    /// `mod name;` 의 파일 경로와, 그 파일의 하위 모듈 디렉토리를 찾습니다.
+    fn resolve_module(&mut self, item: &ItemMod) -> Result<(PathBuf, PathBuf), syn::Error> {
+        let name = item.ident.to_string();
+
+        let path_attr = item.attrs.iter().find(|attr| attr.path().is_ident("path"));
+        if let Some(attr) = path_attr {
+            let value = match &attr.meta {
+                Meta::NameValue(syn::MetaNameValue { value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(value), .. }), .. }) => value.value(),
+                _ => return Err(syn::Error::new_spanned(attr, "expected `#[path = \"...\"]`")),
+            };
+            // 인라인 모듈 밖의 `#[path]` 는 현재 파일의 디렉토리를 기준으로 합니다.
+            let base = if self.inline_depth == 0 {
+                self.path.parent().unwrap_or(Path::new("")).to_path_buf()
+            } else {
+                self.module_dir.clone()
+            };
+            let file = base.join(value);
+            if !file.exists() {
+                self.tracked.push(file.parent().unwrap_or(Path::new("")).to_path_buf());
+                return Err(syn::Error::new_spanned(attr, format!("file not found for module `{}`: {}", name, file.display())));
             }
+            let dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+            return Ok((file, dir));
+        }
+
+        let file = self.module_dir.join(format!("{name}.rs"));
+        let mod_file = self.module_dir.join(&name).join("mod.rs");
+        match (file.exists(), mod_file.exists()) {
+            // rustc 와 같이 두 파일이 모두 있으면 오류입니다.
+            (true, true) => {
+                return Err(syn::Error::new(item.ident.span(), format!("file for module `{}` found at both {} and {}", name, file.display(), mod_file.display())));
+            }
+            (true, false) => return Ok((file, self.module_dir.join(&name))),
+            (false, true) => return Ok((mod_file, self.module_dir.join(&name))),
+            (false, false) => {}
+        }
+
+        self.tracked.push(self.module_dir.clone());
+        Err(syn::Error::new(item.ident.span(), format!("file not found for module `{}` (looked for {} and {})", name, file.display(), mod_file.display())))
+    }
+}
+
+impl<'ast> Visit<'ast> for ModuleVisitor<'_> {
+    fn visit_item(&mut self, item: &'ast Item) {
+        if self.cfg.is_enabled(item_attrs(item)) {
+            syn::visit::visit_item(self, item);
         }
     }
-    Ok(())
+
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        match &item.content {
+            Some((_, items)) => {
+                let parent_dir = self.module_dir.clone();
+                let parent_aliases = std::mem::replace(&mut self.aliases, collect_aliases(self.cfg, items));
+                self.module_dir = parent_dir.join(item.ident.to_string());
+                self.inline_depth += 1;
+                syn::visit::visit_item_mod(self, item);
+                self.inline_depth -= 1;
+                self.module_dir = parent_dir;
+                self.aliases = parent_aliases;
+            }
+            None => match self.resolve_module(item) {
+                Ok(module) => self.modules.push(module),
+                Err(err) => self.push_errors(err),
+            },
+        }
+    }
+
+    fn visit_item_macro(&mut self, item: &'ast ItemMacro) {
+        if let Some(kind) = self.macro_kind(&item.mac.path) {
+            self.macros.push((kind, item.mac.tokens.clone()));
+        }
+    }
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Const(item) => &item.attrs,
+        Item::Enum(item) => &item.attrs,
+        Item::ExternCrate(item) => &item.attrs,
+        Item::Fn(item) => &item.attrs,
+        Item::ForeignMod(item) => &item.attrs,
+        Item::Impl(item) => &item.attrs,
+        Item::Macro(item) => &item.attrs,
+        Item::Mod(item) => &item.attrs,
+        Item::Static(item) => &item.attrs,
+        Item::Struct(item) => &item.attrs,
+        Item::Trait(item) => &item.attrs,
+        Item::TraitAlias(item) => &item.attrs,
+        Item::Type(item) => &item.attrs,
+        Item::Union(item) => &item.attrs,
+        Item::Use(item) => &item.attrs,
+        _ => &[],
+    }
 }
@@ -0,0 +1,531 @@
+//! `model!`/`slint_model!` 정의로부터 JSON Schema 와 TypeScript 선언(`.d.ts`)을 생성합니다.
+//!
+//! 매크로와 같은 `parser` 타입을 사용하므로, Rust 코드의 모델 정의와 어긋나지 않습니다.
+//! 데이터 필드는 `properties` 에, `callback`/`action` 필드는 `x-callbacks`/`x-actions` 에 시그니처로 기록합니다.
+
+use crate::parser::{self, Direction, Model, SlintModel};
+use crate::scanner::{scan_items, CfgContext, ScanOptions, ScannedItems};
+use crate::types::{option_inner, rust_type_string};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::Type;
+
+const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// `model!`, `slint_model!` 의 공통 형태입니다.
+pub struct SchemaModel<'a> {
+    pub name: String,
+    pub doc: Vec<String>,
+    /// `Model[N]` 의 인스턴스 수
+    pub len: Option<&'a proc_macro2::TokenStream>,
+    /// `slint_model!` 의 global 이름
+    pub slint_global: Option<String>,
+    pub fields: Vec<SchemaField<'a>>,
+}
+
+pub struct SchemaField<'a> {
+    pub name: String,
+    pub doc: Vec<String>,
+    pub direction: &'a Direction,
+    pub ty: &'a Type,
+    pub ret: Option<&'a Type>,
+}
+
+impl<'a> From<&'a Model> for SchemaModel<'a> {
+    fn from(model: &'a Model) -> Self {
+        Self {
+            name: model.model_name.to_string(),
+            doc: parser::doc_lines(&model.attrs),
+            len: model.len.as_ref(),
+            slint_global: None,
+            fields: model.fields.iter().map(|field| SchemaField {
+                name: field.name.to_string(),
+                doc: parser::doc_lines(&field.attrs),
+                direction: &field.direction,
+                ty: &field.ty,
+                ret: None,
+            }).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a SlintModel> for SchemaModel<'a> {
+    fn from(model: &'a SlintModel) -> Self {
+        Self {
+            name: model.model_name.to_string(),
+            doc: parser::doc_lines(&model.attrs),
+            len: model.len.as_ref(),
+            slint_global: Some(model.type_name.to_string()),
+            fields: model.fields.iter().map(|field| SchemaField {
+                name: field.name.to_string(),
+                doc: parser::doc_lines(&field.attrs),
+                direction: &field.direction,
+                ty: &field.ty,
+                ret: field.ret.as_ref(),
+            }).collect(),
+        }
+    }
+}
+
+/// 크레이트의 모든 모델을 `SchemaModel` 로 모읍니다. `slint_model!` 다음에 `model!` 순서입니다.
+pub fn schema_models(items: &ScannedItems) -> Vec<SchemaModel<'_>> {
+    items.models.iter().map(SchemaModel::from)
+        .chain(items.rust_models.iter().map(SchemaModel::from))
+        .collect()
+}
+
+/// `src_dir` 의 모델마다 `{모델 이름}.schema.json` 을 `output_dir` 에 씁니다. 내용이 바뀐 파일만 씁니다.
+/// `typescript` 가 참이면 모든 모델의 TypeScript 선언을 담은 `models.d.ts` 도 씁니다.
+pub fn generate_schema_files(src_dir: impl AsRef<Path>, output_dir: impl AsRef<Path>, typescript: bool) -> anyhow::Result<()> {
+    let items = scan_items(src_dir, &CfgContext::from_env(), ScanOptions { rust_models: true })?;
+    write_schema_files(&items, output_dir.as_ref(), typescript)?;
+    Ok(())
+}
+
+/// 이미 모은 모델로 `generate_schema_files` 와 같은 파일을 씁니다. 쓴 파일 경로를 반환합니다.
+pub fn write_schema_files(items: &ScannedItems, output_dir: &Path, typescript: bool) -> anyhow::Result<Vec<PathBuf>> {
+    fs::create_dir_all(output_dir)?;
+
+    let mut written = Vec::new();
+    for model in schema_models(items) {
+        let path = output_dir.join(format!("{}.schema.json", model.name));
+        if write_if_changed(&path, &json_schema(&model, items))? {
+            written.push(path);
+        }
+    }
+    if typescript {
+        let path = output_dir.join("models.d.ts");
+        if write_if_changed(&path, &typescript_declarations(items))? {
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+fn write_if_changed(path: &Path, content: &str) -> anyhow::Result<bool> {
+    if fs::read_to_string(path).ok().as_deref() == Some(content) {
+        return Ok(false);
+    }
+    fs::write(path, content)?;
+    Ok(true)
+}
+
+/// 모델의 JSON Schema 문서입니다. 중첩 모델과 `slint_enum!`/`slint_struct!` 타입은 `$defs` 에 포함합니다.
+pub fn json_schema(model: &SchemaModel, items: &ScannedItems) -> String {
+    let mut builder = SchemaBuilder { items, defs: BTreeMap::new() };
+    let model_ref = builder.reference(&model.name, |builder| builder.model_schema(model));
+
+    let mut root = vec![
+        ("$schema", Json::from(JSON_SCHEMA_DRAFT)),
+        ("title", Json::from(model.name.as_str())),
+    ];
+    if !model.doc.is_empty() {
+        root.push(("description", Json::from(model.doc.join("\n"))));
+    }
+    match model.len {
+        Some(len) => {
+            root.push(("type", Json::from("array")));
+            root.push(("items", model_ref));
+            root.extend(length_keywords(len, "minItems", "maxItems"));
+        }
+        None => root.push(("$ref", Json::from(format!("#/$defs/{}", model.name)))),
+    }
+    root.push(("$defs", Json::Object(builder.defs.into_iter().collect())));
+
+    let mut output = String::new();
+    Json::object(root).write(&mut output, 0);
+    output.push('\n');
+    output
+}
+
+struct SchemaBuilder<'a> {
+    items: &'a ScannedItems,
+    /// 이미 만든(또는 만드는 중인) 정의
+    defs: BTreeMap<String, Json>,
+}
+
+impl SchemaBuilder<'_> {
+    /// `$defs` 의 정의를 가리키는 `$ref` 를 반환합니다. 정의가 없으면 `build` 로 만듭니다.
+    fn reference(&mut self, name: &str, build: impl FnOnce(&mut Self) -> Json) -> Json {
+        if !self.defs.contains_key(name) {
+            // 재귀 참조를 위해 먼저 자리를 잡습니다.
+            self.defs.insert(name.to_string(), Json::Null);
+            let schema = build(self);
+            self.defs.insert(name.to_string(), schema);
+        }
+        Json::object(vec![("$ref", Json::from(format!("#/$defs/{name}")))])
+    }
+
+    fn model_schema(&mut self, model: &SchemaModel) -> Json {
+        let mut properties = Vec::new();
+        let mut required = Vec::new();
+        let mut callbacks = Vec::new();
+        let mut actions = Vec::new();
+
+        for field in &model.fields {
+            let mut schema = Vec::new();
+            if !field.doc.is_empty() {
+                schema.push(("description", Json::from(field.doc.join("\n"))));
+            }
+
+            match field.direction {
+                Direction::Callback | Direction::Action => {
+                    let params = callback_params(field.ty).into_iter().map(|ty| self.type_schema(ty)).collect();
+                    schema.push(("params", Json::Array(params)));
+                    if let Some(ret) = field.ret {
+                        schema.push(("returns", self.type_schema(ret)));
+                    }
+                    let target = if *field.direction == Direction::Action { &mut actions } else { &mut callbacks };
+                    target.push((field.name.clone(), Json::object(schema)));
+                }
+                direction => {
+                    schema.push(("x-direction", Json::from(direction.keyword())));
+                    schema.push(("x-rust-type", Json::from(rust_type_string(field.ty))));
+                    if matches!(direction, Direction::Out | Direction::Computed) {
+                        schema.push(("readOnly", Json::Bool(true)));
+                    }
+                    if option_inner(field.ty).is_none() {
+                        required.push(Json::from(field.name.as_str()));
+                    }
+                    let Json::Object(type_schema) = self.type_schema(field.ty) else { unreachable!() };
+                    let mut merged: Vec<(String, Json)> = type_schema;
+                    merged.extend(schema.into_iter().map(|(key, value)| (key.to_string(), value)));
+                    properties.push((field.name.clone(), Json::Object(merged)));
+                }
+            }
+        }
+
+        let mut schema = vec![("type", Json::from("object"))];
+        if !model.doc.is_empty() {
+            schema.push(("description", Json::from(model.doc.join("\n"))));
+        }
+        if let Some(global) = &model.slint_global {
+            schema.push(("x-slint-global", Json::from(global.as_str())));
+        }
+        schema.push(("properties", Json::Object(properties)));
+        schema.push(("required", Json::Array(required)));
+        schema.push(("additionalProperties", Json::Bool(false)));
+        if !callbacks.is_empty() {
+            schema.push(("x-callbacks", Json::Object(callbacks)));
+        }
+        if !actions.is_empty() {
+            schema.push(("x-actions", Json::Object(actions)));
+        }
+        Json::object(schema)
+    }
+
+    /// Rust 타입의 스키마. 항상 객체입니다.
+    fn type_schema(&mut self, ty: &Type) -> Json {
+        let simple = |ty: &str| Json::object(vec![("type", Json::from(ty))]);
+
+        match ty {
+            Type::Array(array) => {
+                let mut schema = vec![("type", Json::from("array")), ("items", self.type_schema(&array.elem))];
+                let len = &array.len;
+                schema.extend(length_keywords(&quote::quote!(#len), "minItems", "maxItems"));
+                return Json::object(schema);
+            }
+            Type::Slice(slice) => return Json::object(vec![("type", Json::from("array")), ("items", self.type_schema(&slice.elem))]),
+            Type::Reference(reference) => return self.type_schema(&reference.elem),
+            Type::Paren(paren) => return self.type_schema(&paren.elem),
+            Type::Group(group) => return self.type_schema(&group.elem),
+            Type::Tuple(tuple) if tuple.elems.is_empty() => return simple("null"),
+            Type::Tuple(tuple) => {
+                let items: Vec<_> = tuple.elems.iter().map(|elem| self.type_schema(elem)).collect();
+                let len = Json::Number(items.len().to_string());
+                return Json::object(vec![
+                    ("type", Json::from("array")),
+                    ("prefixItems", Json::Array(items)),
+                    ("minItems", len.clone()),
+                    ("maxItems", len),
+                ]);
+            }
+            _ => {}
+        }
+
+        if let Some(inner) = option_inner(ty) {
+            return Json::object(vec![("anyOf", Json::Array(vec![self.type_schema(inner), simple("null")]))]);
+        }
+
+        let Type::Path(type_path) = ty else { return Json::object(Vec::new()) };
+        let Some(segment) = type_path.path.segments.last() else { return Json::object(Vec::new()) };
+        let name = segment.ident.to_string();
+
+        match name.as_str() {
+            "i8" | "i16" | "i32" | "i64" | "isize" => simple("integer"),
+            "u8" | "u16" | "u32" | "u64" | "usize" => Json::object(vec![("type", Json::from("integer")), ("minimum", Json::Number("0".to_string()))]),
+            "f32" | "f64" => simple("number"),
+            "bool" => simple("boolean"),
+            "char" | "str" | "String" | "ArrayString" | "SharedString" => simple("string"),
+            "Color" | "Brush" => Json::object(vec![("type", Json::from("string")), ("format", Json::from("color"))]),
+            "Duration" => Json::object(vec![("type", Json::from("number")), ("x-unit", Json::from("ms"))]),
+            "Length" => Json::object(vec![("type", Json::from("number")), ("x-unit", Json::from("px"))]),
+            "PhysicalLength" => Json::object(vec![("type", Json::from("number")), ("x-unit", Json::from("phx"))]),
+            "Vec" | "VecDeque" => match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(syn::GenericArgument::Type(elem)) => Json::object(vec![("type", Json::from("array")), ("items", self.type_schema(elem))]),
+                    _ => simple("array"),
+                },
+                _ => simple("array"),
+            },
+            _ => self.named_schema(&name),
+        }
+    }
+
+    /// 크레이트에 선언된 모델, `slint_enum!`, `slint_struct!` 은 `$defs` 로 참조합니다. 그 외의 타입은 제약이 없습니다.
+    fn named_schema(&mut self, name: &str) -> Json {
+        let items = self.items;
+        if let Some(model) = items.models.iter().find(|m| m.model_name == name) {
+            return self.reference(name, |builder| builder.model_schema(&SchemaModel::from(model)));
+        }
+        if let Some(model) = items.rust_models.iter().find(|m| m.model_name == name) {
+            return self.reference(name, |builder| builder.model_schema(&SchemaModel::from(model)));
+        }
+        if let Some(item) = items.enums.iter().find(|e| e.name == name) {
+            return self.reference(name, |_| {
+                let mut schema = vec![("type", Json::from("string"))];
+                let doc = parser::doc_lines(&item.attrs);
+                if !doc.is_empty() {
+                    schema.push(("description", Json::from(doc.join("\n"))));
+                }
+                schema.push(("enum", Json::Array(item.variants.iter().map(|v| Json::from(v.name.to_string())).collect())));
+                Json::object(schema)
+            });
+        }
+        if let Some(item) = items.structs.iter().find(|s| s.name == name) {
+            return self.reference(name, |builder| {
+                let mut properties = Vec::new();
+                let mut required = Vec::new();
+                for field in &item.fields {
+                    properties.push((field.name.to_string(), builder.type_schema(&field.ty)));
+                    if option_inner(&field.ty).is_none() {
+                        required.push(Json::from(field.name.to_string()));
+                    }
+                }
+                let mut schema = vec![("type", Json::from("object"))];
+                let doc = parser::doc_lines(&item.attrs);
+                if !doc.is_empty() {
+                    schema.push(("description", Json::from(doc.join("\n"))));
+                }
+                schema.push(("properties", Json::Object(properties)));
+                schema.push(("required", Json::Array(required)));
+                schema.push(("additionalProperties", Json::Bool(false)));
+                Json::object(schema)
+            });
+        }
+        Json::object(Vec::new())
+    }
+}
+
+/// 콜백 필드 타입의 인자 목록. `(A, B)` 는 두 인자, `()` 는 인자 없음, 그 외에는 한 인자입니다.
+fn callback_params(ty: &Type) -> Vec<&Type> {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.iter().collect(),
+        Type::Paren(paren) => vec![&paren.elem],
+        ty => vec![ty],
+    }
+}
+
+/// 정수 리터럴 길이는 숫자로, 상수 이름 등은 `x-length` 로 기록합니다.
+fn length_keywords(len: &proc_macro2::TokenStream, min: &'static str, max: &'static str) -> Vec<(&'static str, Json)> {
+    match syn::parse2::<syn::LitInt>(len.clone()).ok().and_then(|lit| lit.base10_parse::<usize>().ok()) {
+        Some(len) => vec![(min, Json::Number(len.to_string())), (max, Json::Number(len.to_string()))],
+        None => vec![("x-length", Json::from(rust_type_string(len)))],
+    }
+}
+
+/// 모든 모델과 `slint_enum!`, `slint_struct!` 의 TypeScript 선언입니다.
+/// 데이터 필드는 프로퍼티(`out`/`computed` 는 `readonly`), `callback` 은 메서드, `action` 은 `Promise` 를 반환하는 메서드입니다.
+pub fn typescript_declarations(items: &ScannedItems) -> String {
+    let mut output = String::from("// frand-property 가 생성한 파일입니다. 직접 수정하지 마세요.\n");
+
+    for item in &items.enums {
+        output.push('\n');
+        push_ts_doc(&mut output, "", &parser::doc_lines(&item.attrs));
+        let variants: Vec<_> = item.variants.iter().map(|v| format!("\"{}\"", v.name)).collect();
+        let _ = writeln!(output, "export type {} = {};", item.name, variants.join(" | "));
+    }
+
+    for item in &items.structs {
+        output.push('\n');
+        push_ts_doc(&mut output, "", &parser::doc_lines(&item.attrs));
+        let _ = writeln!(output, "export interface {} {{", item.name);
+        for field in &item.fields {
+            push_ts_doc(&mut output, "    ", &parser::doc_lines(&field.attrs));
+            let _ = writeln!(output, "    {}: {};", field.name, ts_type(&field.ty));
+        }
+        output.push_str("}\n");
+    }
+
+    for model in schema_models(items) {
+        output.push('\n');
+        let mut doc = model.doc.clone();
+        if let Some(len) = model.len {
+            doc.push(format!("@length {}", rust_type_string(len)));
+        }
+        push_ts_doc(&mut output, "", &doc);
+        let _ = writeln!(output, "export interface {} {{", model.name);
+        for field in &model.fields {
+            push_ts_doc(&mut output, "    ", &field.doc);
+            match field.direction {
+                Direction::Callback | Direction::Action => {
+                    let params: Vec<_> = callback_params(field.ty).iter().enumerate()
+                        .map(|(i, ty)| format!("arg{i}: {}", ts_type(ty)))
+                        .collect();
+                    let ret = field.ret.map_or("void".to_string(), ts_type);
+                    let ret = if *field.direction == Direction::Action { format!("Promise<{ret}>") } else { ret };
+                    let _ = writeln!(output, "    {}({}): {};", field.name, params.join(", "), ret);
+                }
+                direction => {
+                    let readonly = if matches!(direction, Direction::Out | Direction::Computed) { "readonly " } else { "" };
+                    let _ = writeln!(output, "    {}{}: {};", readonly, field.name, ts_type(field.ty));
+                }
+            }
+        }
+        output.push_str("}\n");
+    }
+
+    output
+}
+
+fn ts_type(ty: &Type) -> String {
+    match ty {
+        Type::Array(array) => return format!("{}[]", ts_element_type(&array.elem)),
+        Type::Slice(slice) => return format!("{}[]", ts_element_type(&slice.elem)),
+        Type::Reference(reference) => return ts_type(&reference.elem),
+        Type::Paren(paren) => return ts_type(&paren.elem),
+        Type::Group(group) => return ts_type(&group.elem),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => return "null".to_string(),
+        Type::Tuple(tuple) => return format!("[{}]", tuple.elems.iter().map(ts_type).collect::<Vec<_>>().join(", ")),
+        _ => {}
+    }
+
+    if let Some(inner) = option_inner(ty) {
+        return format!("{} | null", ts_type(inner));
+    }
+
+    let Type::Path(type_path) = ty else { return "unknown".to_string() };
+    let Some(segment) = type_path.path.segments.last() else { return "unknown".to_string() };
+
+    match segment.ident.to_string().as_str() {
+        "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" | "f32" | "f64" => "number".to_string(),
+        "Duration" | "Length" | "PhysicalLength" => "number".to_string(),
+        "bool" => "boolean".to_string(),
+        "char" | "str" | "String" | "ArrayString" | "SharedString" | "Color" | "Brush" => "string".to_string(),
+        "Vec" | "VecDeque" => match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(syn::GenericArgument::Type(elem)) => format!("{}[]", ts_element_type(elem)),
+                _ => "unknown[]".to_string(),
+            },
+            _ => "unknown[]".to_string(),
+        },
+        "PixelImage" | "Image" => "unknown".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// 배열 요소 타입. `T | null` 은 괄호로 감쌉니다.
+fn ts_element_type(ty: &Type) -> String {
+    let ty = ts_type(ty);
+    if ty.contains(' ') { format!("({ty})") } else { ty }
+}
+
+fn push_ts_doc(output: &mut String, indent: &str, doc: &[String]) {
+    match doc {
+        [] => {}
+        [line] => {
+            let _ = writeln!(output, "{indent}/** {line} */");
+        }
+        lines => {
+            let _ = writeln!(output, "{indent}/**");
+            for line in lines {
+                let _ = writeln!(output, "{indent} * {line}");
+            }
+            let _ = writeln!(output, "{indent} */");
+        }
+    }
+}
+
+/// 키 순서를 유지하는 간단한 JSON 값입니다.
+#[derive(Debug, Clone)]
+enum Json {
+    Null,
+    Bool(bool),
+    /// 이미 JSON 형식으로 쓴 숫자
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl Json {
+    fn object(entries: Vec<(&str, Json)>) -> Self {
+        Json::Object(entries.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+
+    /// 두 칸 들여쓰기로 씁니다. 빈 배열과 객체는 한 줄로 씁니다.
+    fn write(&self, output: &mut String, indent: usize) {
+        match self {
+            Json::Null => output.push_str("null"),
+            Json::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+            Json::Number(value) => output.push_str(value),
+            Json::String(value) => write_json_string(output, value),
+            Json::Array(items) if items.is_empty() => output.push_str("[]"),
+            Json::Array(items) => {
+                output.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    output.push_str(&"  ".repeat(indent + 1));
+                    item.write(output, indent + 1);
+                    output.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&"  ".repeat(indent));
+                output.push(']');
+            }
+            Json::Object(entries) if entries.is_empty() => output.push_str("{}"),
+            Json::Object(entries) => {
+                output.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    output.push_str(&"  ".repeat(indent + 1));
+                    write_json_string(output, key);
+                    output.push_str(": ");
+                    value.write(output, indent + 1);
+                    output.push_str(if i + 1 < entries.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&"  ".repeat(indent));
+                output.push('}');
+            }
+        }
+    }
+}
+
+fn write_json_string(output: &mut String, value: &str) {
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
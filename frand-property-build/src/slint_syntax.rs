@@ -0,0 +1,720 @@
+//! 기존 `.slint` 파일을 갱신하기 위한 Slint 토크나이저와 최상위 선언 파서입니다.
+//!
+//! 전체 문법을 해석하지 않고, 선언(`struct`/`enum`/`global`/`component`)의 이름과 본문 범위만 찾습니다.
+//! 문자열(보간 포함)과 주석을 토큰 단위로 구분하므로, 그 안의 중괄호나 이름에 영향받지 않습니다.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Whitespace,
+    /// `// ...` 또는 `/* ... */` (중첩 가능)
+    Comment,
+    /// `-` 를 포함할 수 있는 Slint 식별자
+    Ident,
+    /// 문자열 리터럴. 보간(`\{ ... }`)이 있으면 보간 앞뒤 조각이 각각 토큰이 됩니다.
+    String,
+    /// 단위(`px`, `ms`, `%` 등)를 포함한 숫자
+    Number,
+    /// 그 외의 한 글자
+    Punct(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Token {
+    fn is_trivia(&self) -> bool {
+        matches!(self.kind, TokenKind::Whitespace | TokenKind::Comment)
+    }
+}
+
+/// 파일을 안전하게 해석할 수 없을 때의 오류입니다. 위치는 1부터 시작합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlintSyntaxError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl SlintSyntaxError {
+    fn at(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |s| s.chars().count()) + 1;
+        Self { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for SlintSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for SlintSyntaxError {}
+
+/// 소스 전체를 토큰으로 나눕니다. 모든 바이트는 정확히 하나의 토큰에 속합니다.
+pub fn tokenize(source: &str) -> Result<Vec<Token>, SlintSyntaxError> {
+    let mut tokens = Vec::new();
+    // 열려 있는 문자열 보간마다 보간 안의 중괄호 깊이를 기록합니다.
+    let mut templates: Vec<usize> = Vec::new();
+    let mut pos = 0;
+
+    while let Some(c) = source[pos..].chars().next() {
+        let rest = &source[pos..];
+
+        let (kind, len) = if c.is_whitespace() {
+            (TokenKind::Whitespace, rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len()))
+        } else if rest.starts_with("//") {
+            (TokenKind::Comment, rest.find(['\n', '\r']).unwrap_or(rest.len()))
+        } else if rest.starts_with("/*") {
+            let len = block_comment_len(rest)
+                .ok_or_else(|| SlintSyntaxError::at(source, pos, "unterminated block comment"))?;
+            (TokenKind::Comment, len)
+        } else if c == '"' || (c == '}' && templates.last() == Some(&0)) {
+            if c == '}' {
+                templates.pop();
+            }
+            let (len, opens_template) = string_len(rest)
+                .ok_or_else(|| SlintSyntaxError::at(source, pos, "unterminated string literal"))?;
+            if opens_template {
+                templates.push(0);
+            }
+            (TokenKind::String, len)
+        } else if c.is_alphabetic() || c == '_' {
+            (TokenKind::Ident, ident_len(rest))
+        } else if c.is_ascii_digit() {
+            let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '.' || c == '%')).unwrap_or(rest.len());
+            (TokenKind::Number, len)
+        } else {
+            if let Some(level) = templates.last_mut() {
+                match c {
+                    '{' => *level += 1,
+                    '}' => *level -= 1,
+                    _ => {}
+                }
+            }
+            (TokenKind::Punct(c), c.len_utf8())
+        };
+
+        tokens.push(Token { kind, start: pos, end: pos + len });
+        pos += len;
+    }
+
+    if !templates.is_empty() {
+        return Err(SlintSyntaxError::at(source, source.len(), "unterminated string interpolation"));
+    }
+
+    Ok(tokens)
+}
+
+fn block_comment_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut nested = 0;
+    let mut offset = 2;
+
+    while offset + 1 < bytes.len() {
+        match (bytes[offset], bytes[offset + 1]) {
+            (b'/', b'*') => {
+                nested += 1;
+                offset += 2;
+            }
+            (b'*', b'/') if nested == 0 => return Some(offset + 2),
+            (b'*', b'/') => {
+                nested -= 1;
+                offset += 2;
+            }
+            _ => offset += 1,
+        }
+    }
+
+    None
+}
+
+/// 문자열 조각의 길이와, 보간(`\{`)으로 끝나는지 여부를 반환합니다. 첫 글자(`"` 또는 `}`)는 건너뜁니다.
+fn string_len(text: &str) -> Option<(usize, bool)> {
+    let mut chars = text.char_indices().skip(1);
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((i + 1, false)),
+            '\\' => {
+                if let (j, '{') = chars.next()? {
+                    return Some((j + 1, true));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn ident_len(text: &str) -> usize {
+    let mut len = 0;
+    for c in text.chars() {
+        if !c.is_alphanumeric() && c != '_' && (c != '-' || len == 0) {
+            break;
+        }
+        len += c.len_utf8();
+    }
+    len
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DeclarationKind {
+    Struct,
+    Enum,
+    Global,
+    Component,
+}
+
+impl DeclarationKind {
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "struct" => Some(Self::Struct),
+            "enum" => Some(Self::Enum),
+            "global" => Some(Self::Global),
+            "component" => Some(Self::Component),
+            _ => None,
+        }
+    }
+
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Global => "global",
+            Self::Component => "component",
+        }
+    }
+}
+
+/// 최상위 선언입니다. 오프셋은 모두 소스의 바이트 위치입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declaration {
+    pub kind: DeclarationKind,
+    pub name: String,
+    pub exported: bool,
+    /// 선언 바로 위에 붙은 주석을 포함한 시작 위치
+    pub start: usize,
+    /// 본문을 여는 `{` 의 위치
+    pub open_brace: usize,
+    /// 본문을 닫는 `}` 의 위치
+    pub close_brace: usize,
+}
+
+/// 선언 본문의 멤버 종류입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+    /// struct 필드 (`name: type,`)
+    Field,
+    /// `[in|out|in-out|private] property <type> name ...;`
+    Property,
+    /// `[pure] callback name(args) -> ret;`
+    Callback,
+}
+
+/// 선언 본문의 멤버입니다. 타입은 토큰을 공백 없이 이어 붙인 원문입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub kind: MemberKind,
+    pub name: String,
+    /// 프로퍼티의 `in`, `out`, `in-out`, `private`. 생략하면 `None` 입니다.
+    pub qualifier: Option<String>,
+    /// 필드와 프로퍼티의 타입
+    pub ty: Option<String>,
+    /// 콜백의 인자 타입
+    pub args: Vec<String>,
+    /// 콜백의 반환 타입
+    pub ret: Option<String>,
+    /// `pure callback` 여부
+    pub pure: bool,
+    /// 멤버 바로 위의 주석. 주석 기호는 제외합니다.
+    pub comments: Vec<String>,
+}
+
+impl MemberKind {
+    fn keyword(&self) -> &'static str {
+        match self {
+            Self::Field => "field",
+            Self::Property => "property",
+            Self::Callback => "callback",
+        }
+    }
+}
+
+/// 선언 본문의 최상위 구문입니다. 오프셋은 모두 소스의 바이트 위치입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statement {
+    /// 같은 구문을 찾기 위한 이름. 프로퍼티와 콜백은 `property name`, `callback name` 이고,
+    /// 그 외에는 `:`, `(`, `{`, `=>`, `;`, `,` 앞의 토큰입니다. (`x`, `changed global-x`, `Rectangle` 등)
+    pub key: String,
+    /// 주석과 공백을 제외한 토큰을 공백 하나로 이은 내용. 구문이 바뀌었는지 비교할 때 사용합니다.
+    pub text: String,
+    /// 바로 위에 붙은 주석의 시작 위치. 주석이 없으면 `start` 와 같습니다.
+    pub comment_start: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+fn is_member_keyword(text: &str) -> bool {
+    matches!(text, "in" | "out" | "in-out" | "private" | "property" | "callback" | "pure" | "public")
+}
+
+fn comment_text(comment: &str) -> String {
+    let text = match comment.strip_prefix("//") {
+        Some(line) => line,
+        None => comment.trim_start_matches("/*").trim_end_matches("*/"),
+    };
+    text.strip_prefix(' ').unwrap_or(text).trim_end().to_string()
+}
+
+/// 해석된 `.slint` 파일입니다.
+#[derive(Debug)]
+pub struct SlintFile<'a> {
+    source: &'a str,
+    tokens: Vec<Token>,
+    declarations: Vec<Declaration>,
+}
+
+impl<'a> SlintFile<'a> {
+    /// 최상위 선언을 찾습니다. `import`/`export { ... }` 외의 알 수 없는 구문이나 짝이 맞지 않는 괄호는 오류입니다.
+    pub fn parse(source: &'a str) -> Result<Self, SlintSyntaxError> {
+        let tokens = tokenize(source)?;
+        let mut file = Self { source, tokens, declarations: Vec::new() };
+        file.parse_items()?;
+        Ok(file)
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    pub fn declarations(&self) -> &[Declaration] {
+        &self.declarations
+    }
+
+    /// 종류와 이름이 정확히 같은 선언을 찾습니다.
+    pub fn find(&self, kind: DeclarationKind, name: &str) -> Option<&Declaration> {
+        self.declarations.iter().find(|d| d.kind == kind && d.name == name)
+    }
+
+    /// 선언 본문에 직접 선언된 프로퍼티(`... property <T> name: value;`)의 초기값 원문을 반환합니다.
+    pub fn property_value(&self, declaration: &Declaration, name: &str) -> Option<&'a str> {
+        let body: Vec<&Token> = self.tokens.iter()
+            .filter(|t| t.start > declaration.open_brace && t.end <= declaration.close_brace && !t.is_trivia())
+            .collect();
+
+        let mut depth = 0usize;
+        for (i, token) in body.iter().enumerate() {
+            match token.kind {
+                TokenKind::Punct('{' | '(' | '[') => depth += 1,
+                TokenKind::Punct('}' | ')' | ']') => depth = depth.saturating_sub(1),
+                TokenKind::Ident if depth == 0 && self.text(token) == "property" => {
+                    if let Some(value) = self.declared_value(&body[i + 1..], name) {
+                        return Some(value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// 선언 본문의 최상위 멤버(struct 필드, 프로퍼티, 콜백)를 순서대로 반환합니다.
+    /// 바인딩, 자식 요소 등 그 외의 구문은 건너뜁니다.
+    pub fn members(&self, declaration: &Declaration) -> Vec<Member> {
+        self.body_statements(declaration).into_iter()
+            .filter_map(|(statement, comments)| {
+                let comments = comments.iter().map(|comment| comment_text(self.text(comment))).collect();
+                self.parse_member(declaration.kind, &statement, comments)
+            })
+            .collect()
+    }
+
+    /// 선언 본문의 최상위 구문을 순서대로 반환합니다. 멤버 외에 바인딩, 콜백 처리기, 자식 요소도 포함합니다.
+    pub fn statements(&self, declaration: &Declaration) -> Vec<Statement> {
+        self.body_statements(declaration).into_iter()
+            .map(|(statement, comments)| {
+                let (first, last) = (statement[0], statement[statement.len() - 1]);
+                let key = match self.parse_member(declaration.kind, &statement, Vec::new()) {
+                    Some(member) if member.kind != MemberKind::Field => format!("{} {}", member.kind.keyword(), member.name),
+                    _ => statement.iter()
+                        .take_while(|t| !matches!(t.kind, TokenKind::Punct(':' | '(' | '{' | '=' | ';' | ',')))
+                        .map(|t| self.text(t))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                };
+                Statement {
+                    key,
+                    text: statement.iter().map(|t| self.text(t)).collect::<Vec<_>>().join(" "),
+                    comment_start: comments.first().map_or(first.start, |comment| comment.start),
+                    start: first.start,
+                    end: last.end,
+                }
+            })
+            .collect()
+    }
+
+    /// 선언 본문을 최상위 구문으로 나눕니다. 각 구문은 (주석을 제외한 토큰, 바로 위에 붙은 주석) 입니다.
+    fn body_statements(&self, declaration: &Declaration) -> Vec<(Vec<&Token>, Vec<&Token>)> {
+        let mut statements = Vec::new();
+        let mut statement: Vec<&Token> = Vec::new();
+        let mut comments = Vec::new();
+        let mut statement_comments = Vec::new();
+        let mut depth = 0usize;
+
+        let body = self.tokens.iter().filter(|t| t.start > declaration.open_brace && t.end <= declaration.close_brace);
+        for token in body {
+            let ends = match token.kind {
+                TokenKind::Whitespace => {
+                    // 빈 줄로 떨어진 주석은 다음 멤버의 설명이 아닙니다.
+                    if statement.is_empty() && self.text(token).matches('\n').count() > 1 {
+                        comments.clear();
+                    }
+                    false
+                }
+                TokenKind::Comment => {
+                    if statement.is_empty() {
+                        comments.push(token);
+                    }
+                    false
+                }
+                kind => {
+                    if statement.is_empty() {
+                        statement_comments = std::mem::take(&mut comments);
+                    }
+                    statement.push(token);
+                    match kind {
+                        TokenKind::Punct('{' | '(' | '[') => {
+                            depth += 1;
+                            false
+                        }
+                        TokenKind::Punct('}' | ')' | ']') => {
+                            depth = depth.saturating_sub(1);
+                            // `changed x => { ... }`, `Text { ... }` 등은 `;` 없이 끝납니다.
+                            depth == 0 && kind == TokenKind::Punct('}') && !is_member_keyword(self.text(statement[0]))
+                        }
+                        TokenKind::Punct(';' | ',') => depth == 0,
+                        _ => false,
+                    }
+                }
+            };
+
+            if ends {
+                statements.push((std::mem::take(&mut statement), std::mem::take(&mut statement_comments)));
+            }
+        }
+        if !statement.is_empty() {
+            statements.push((statement, statement_comments));
+        }
+
+        statements
+    }
+
+    fn parse_member(&self, kind: DeclarationKind, tokens: &[&Token], comments: Vec<String>) -> Option<Member> {
+        let texts: Vec<&str> = tokens.iter().map(|t| self.text(t)).collect();
+        let mut member = Member {
+            kind: MemberKind::Field,
+            name: String::new(),
+            qualifier: None,
+            ty: None,
+            args: Vec::new(),
+            ret: None,
+            pure: false,
+            comments,
+        };
+
+        if kind == DeclarationKind::Struct {
+            if texts.len() < 3 || tokens[0].kind != TokenKind::Ident || texts[1] != ":" {
+                return None;
+            }
+            member.name = texts[0].to_string();
+            member.ty = Some(texts[2..].iter().take_while(|t| !matches!(**t, "," | ";")).copied().collect());
+            return Some(member);
+        }
+
+        let mut pos = 0;
+        if matches!(texts.first(), Some(&("in" | "out" | "in-out" | "private"))) && texts.get(1) == Some(&"property") {
+            member.qualifier = Some(texts[0].to_string());
+            pos = 1;
+        }
+        while matches!(texts.get(pos), Some(&("pure" | "public"))) {
+            member.pure |= texts[pos] == "pure";
+            pos += 1;
+        }
+
+        match texts.get(pos).copied() {
+            Some("property") => {
+                if texts.get(pos + 1) != Some(&"<") {
+                    return None;
+                }
+                let mut angle = 0;
+                let mut end = pos + 1;
+                for (i, text) in texts.iter().enumerate().skip(pos + 1) {
+                    match *text {
+                        "<" => angle += 1,
+                        ">" => angle -= 1,
+                        _ => {}
+                    }
+                    if angle == 0 {
+                        end = i;
+                        break;
+                    }
+                }
+                member.kind = MemberKind::Property;
+                member.ty = Some(texts[pos + 2..end].concat());
+                member.name = texts.get(end + 1).filter(|_| end > pos + 1)?.to_string();
+            }
+            Some("callback") => {
+                member.kind = MemberKind::Callback;
+                member.name = texts.get(pos + 1)?.to_string();
+                let mut pos = pos + 2;
+                if texts.get(pos) == Some(&"(") {
+                    let mut depth = 0;
+                    let mut arg = String::new();
+                    for text in &texts[pos..] {
+                        pos += 1;
+                        match *text {
+                            "(" | "[" | "{" => depth += 1,
+                            ")" | "]" | "}" => depth -= 1,
+                            _ => {}
+                        }
+                        if depth == 0 || (depth == 1 && *text == ",") {
+                            if !arg.trim().is_empty() {
+                                // 이름이 있는 인자(`name: type`)는 타입만 사용합니다.
+                                let ty = arg.rsplit_once(':').map_or(arg.as_str(), |(_, ty)| ty);
+                                member.args.push(ty.to_string());
+                            }
+                            arg.clear();
+                            if depth == 0 {
+                                break;
+                            }
+                        } else if !(depth == 1 && *text == "(") {
+                            arg.push_str(text);
+                        }
+                    }
+                }
+                if texts.get(pos) == Some(&"-") && texts.get(pos + 1) == Some(&">") {
+                    member.ret = Some(texts[pos + 2..].iter().take_while(|t| !matches!(**t, ";" | ",")).copied().collect());
+                }
+            }
+            _ => return None,
+        }
+
+        Some(member)
+    }
+
+    /// `property` 키워드 뒤의 `<T> name: value;` 를 해석합니다.
+    fn declared_value(&self, tokens: &[&Token], name: &str) -> Option<&'a str> {
+        let mut iter = tokens.iter();
+        if iter.next()?.kind != TokenKind::Punct('<') {
+            return None;
+        }
+
+        // 타입 안의 `<`, `>` 짝을 맞춥니다. (`<[{ a: int }]>` 등)
+        let mut angle = 1;
+        for token in iter.by_ref() {
+            match token.kind {
+                TokenKind::Punct('<') => angle += 1,
+                TokenKind::Punct('>') => {
+                    angle -= 1;
+                    if angle == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let ident = iter.next()?;
+        if ident.kind != TokenKind::Ident || self.text(ident) != name {
+            return None;
+        }
+        if iter.next()?.kind != TokenKind::Punct(':') {
+            return None;
+        }
+
+        let mut depth = 0usize;
+        let mut range: Option<(usize, usize)> = None;
+        for token in iter {
+            match token.kind {
+                TokenKind::Punct(';') if depth == 0 => return range.map(|(start, end)| &self.source[start..end]),
+                TokenKind::Punct('{' | '(' | '[') => depth += 1,
+                TokenKind::Punct('}' | ')' | ']') => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            range = Some((range.map_or(token.start, |(start, _)| start), token.end));
+        }
+
+        None
+    }
+
+    fn text(&self, token: &Token) -> &'a str {
+        &self.source[token.start..token.end]
+    }
+
+    fn error(&self, offset: usize, message: impl Into<String>) -> SlintSyntaxError {
+        SlintSyntaxError::at(self.source, offset, message)
+    }
+
+    /// 주석과 공백을 제외한 토큰의 인덱스 목록
+    fn significant(&self) -> Vec<usize> {
+        (0..self.tokens.len()).filter(|&i| !self.tokens[i].is_trivia()).collect()
+    }
+
+    fn parse_items(&mut self) -> Result<(), SlintSyntaxError> {
+        let significant = self.significant();
+        let mut pos = 0;
+
+        while pos < significant.len() {
+            let first = self.tokens[significant[pos]];
+            let text = self.text(&first);
+
+            match (first.kind, text) {
+                (TokenKind::Ident, "import") => {
+                    pos = self.skip_statement(&significant, pos + 1)?;
+                }
+                (TokenKind::Ident, "export") => {
+                    let next = significant.get(pos + 1).map(|&i| self.tokens[i]);
+                    match next.map(|t| t.kind) {
+                        Some(TokenKind::Punct('{' | '*')) => pos = self.skip_statement(&significant, pos + 1)?,
+                        Some(_) => pos = self.parse_declaration(&significant, pos, pos + 1)?,
+                        None => return Err(self.error(first.end, "expected a declaration after `export`")),
+                    }
+                }
+                (TokenKind::Ident, _) => {
+                    pos = self.parse_declaration(&significant, pos, pos)?;
+                }
+                (TokenKind::Punct('}'), _) => return Err(self.error(first.start, "unmatched `}`")),
+                _ => return Err(self.error(first.start, format!("unexpected `{text}`, expected a declaration"))),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `import ...;`, `export { ... } from "...";`, `export * from "...";` 를 건너뜁니다.
+    /// `export { ... }` 는 `;` 없이 끝날 수도 있습니다.
+    fn skip_statement(&self, significant: &[usize], mut pos: usize) -> Result<usize, SlintSyntaxError> {
+        while pos < significant.len() {
+            let token = self.tokens[significant[pos]];
+            match token.kind {
+                TokenKind::Punct(';') => return Ok(pos + 1),
+                TokenKind::Punct('{') => {
+                    let close = self.matching_brace(significant, pos)?;
+                    pos = close + 1;
+                    let next = significant.get(pos).map(|&i| self.tokens[i]);
+                    let continues = next.is_some_and(|t| t.kind == TokenKind::Punct(';') || self.text(&t) == "from");
+                    if !continues {
+                        return Ok(pos);
+                    }
+                }
+                _ => pos += 1,
+            }
+        }
+        Ok(pos)
+    }
+
+    /// `struct|enum|global|component Name ... { ... }` 또는 `Name := Base { ... }` 를 해석합니다.
+    fn parse_declaration(&mut self, significant: &[usize], start_pos: usize, mut pos: usize) -> Result<usize, SlintSyntaxError> {
+        let first = self.tokens[significant[pos]];
+        let exported = start_pos != pos;
+
+        let (kind, name_token) = match DeclarationKind::from_keyword(self.text(&first)) {
+            Some(kind) => {
+                let name = significant.get(pos + 1).map(|&i| self.tokens[i])
+                    .filter(|t| t.kind == TokenKind::Ident)
+                    .ok_or_else(|| self.error(first.end, format!("expected a name after `{}`", self.text(&first))))?;
+                pos += 2;
+                (kind, name)
+            }
+            None => {
+                // 이전 문법의 컴포넌트 선언: `Name := Base { ... }`
+                let is_legacy = significant.get(pos + 1).is_some_and(|&i| self.tokens[i].kind == TokenKind::Punct(':'))
+                    && significant.get(pos + 2).is_some_and(|&i| self.tokens[i].kind == TokenKind::Punct('='));
+                if !is_legacy {
+                    return Err(self.error(first.start, format!("unexpected `{}`, expected a declaration", self.text(&first))));
+                }
+                pos += 3;
+                (DeclarationKind::Component, first)
+            }
+        };
+
+        // 본문 앞의 `inherits Base` 등을 건너뜁니다.
+        while pos < significant.len() && self.tokens[significant[pos]].kind != TokenKind::Punct('{') {
+            let token = self.tokens[significant[pos]];
+            if matches!(token.kind, TokenKind::Punct(';' | '}')) {
+                return Err(self.error(token.start, format!("expected `{{` to open the body of `{}`", self.text(&name_token))));
+            }
+            pos += 1;
+        }
+        if pos == significant.len() {
+            return Err(self.error(self.source.len(), format!("expected `{{` to open the body of `{}`", self.text(&name_token))));
+        }
+
+        let close = self.matching_brace(significant, pos)?;
+        let name = self.text(&name_token).to_string();
+
+        if self.find(kind, &name).is_some() {
+            return Err(self.error(name_token.start, format!("`{name}` is declared more than once")));
+        }
+
+        self.declarations.push(Declaration {
+            kind,
+            name,
+            exported,
+            start: self.leading_comment_start(significant[start_pos]),
+            open_brace: self.tokens[significant[pos]].start,
+            close_brace: self.tokens[significant[close]].start,
+        });
+
+        Ok(close + 1)
+    }
+
+    /// `significant[open]` 의 `{` 와 짝이 맞는 `}` 의 위치(`significant` 인덱스)를 찾습니다.
+    fn matching_brace(&self, significant: &[usize], open: usize) -> Result<usize, SlintSyntaxError> {
+        let mut depth = 0usize;
+        for (pos, &i) in significant.iter().enumerate().skip(open) {
+            match self.tokens[i].kind {
+                TokenKind::Punct('{') => depth += 1,
+                TokenKind::Punct('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(pos);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(self.error(self.tokens[significant[open]].start, "unclosed `{`"))
+    }
+
+    /// 선언 바로 위 줄들에 붙은 주석의 시작 위치. 빈 줄이나 코드 뒤에 오는 주석은 포함하지 않습니다.
+    fn leading_comment_start(&self, index: usize) -> usize {
+        let mut i = index;
+
+        while i >= 2 {
+            let whitespace = self.tokens[i - 1];
+            let comment = self.tokens[i - 2];
+            if whitespace.kind != TokenKind::Whitespace || comment.kind != TokenKind::Comment || self.text(&whitespace).matches('\n').count() != 1 {
+                break;
+            }
+            // 주석이 줄의 시작에 있어야 합니다.
+            let own_line = i == 2 || (self.tokens[i - 3].kind == TokenKind::Whitespace && self.text(&self.tokens[i - 3]).contains('\n'));
+            if !own_line {
+                break;
+            }
+            i -= 2;
+        }
+
+        self.tokens[i].start
+    }
+}
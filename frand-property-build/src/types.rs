@@ -0,0 +1,161 @@
+use std::collections::BTreeSet;
+use quote::quote;
+use syn::Type;
+
+/// Rust 필드 타입이 Slint 에서 표현되는 타입입니다.
+/// `.slint` 생성기와 `slint_model!` 의 값 변환 코드가 같은 분류를 사용합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SlintType {
+    Int,
+    Float,
+    Bool,
+    /// `String`, `ArrayString<N>`, `char`
+    String,
+    /// `slint::Color`
+    Color,
+    /// `slint::Brush`
+    Brush,
+    /// `frand_property::slint::PixelImage` (`slint::Image` 는 `Send` 가 아니므로 픽셀 버퍼로 다룹니다.)
+    Image,
+    /// `std::time::Duration`. Slint 측 값은 밀리초(`i64`)입니다.
+    Duration,
+    /// `frand_property::slint::Length`
+    Length,
+    /// `frand_property::slint::PhysicalLength`
+    PhysicalLength,
+    /// `()`
+    Void,
+    Array(Box<SlintType>),
+    /// `Option<T>`: `T` 값 필드와 `has-*` 플래그 필드로 표현됩니다.
+    Option(Box<SlintType>),
+    /// 그 외의 타입은 Rust 타입 이름을 그대로 사용합니다. (`slint_enum!`, `slint_struct!` 등)
+    Other(String),
+}
+
+impl SlintType {
+    /// Slint 타입 이름. `Option<T>` 는 값 필드의 타입(`T`)입니다.
+    pub fn slint_name(&self) -> String {
+        match self {
+            SlintType::Int => "int".to_string(),
+            SlintType::Float => "float".to_string(),
+            SlintType::Bool => "bool".to_string(),
+            SlintType::String => "string".to_string(),
+            SlintType::Color => "color".to_string(),
+            SlintType::Brush => "brush".to_string(),
+            SlintType::Image => "image".to_string(),
+            SlintType::Duration => "duration".to_string(),
+            SlintType::Length => "length".to_string(),
+            SlintType::PhysicalLength => "physical-length".to_string(),
+            SlintType::Void => "void".to_string(),
+            SlintType::Array(elem) => format!("[{}]", elem.slint_name()),
+            SlintType::Option(inner) => inner.slint_name(),
+            SlintType::Other(name) => name.clone(),
+        }
+    }
+}
+
+/// 크레이트가 `slint_enum!`, `slint_struct!` 로 선언한 타입 이름입니다.
+/// 한 단어로 쓴 타입이 이 이름이면 `Color` 처럼 알려진 타입과 이름이 같아도 사용자 타입으로 분류합니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserTypes {
+    /// `slint_enum!` 타입 이름. 모델 초기값의 `Enum::Variant` 를 Slint 리터럴로 옮길 때도 사용합니다.
+    pub enums: BTreeSet<String>,
+    /// `slint_struct!` 타입 이름
+    pub structs: BTreeSet<String>,
+}
+
+impl UserTypes {
+    pub fn contains(&self, name: &str) -> bool {
+        self.enums.contains(name) || self.structs.contains(name)
+    }
+
+    /// 선언된 타입 이름을 고려하여 `ty` 를 분류합니다.
+    pub fn classify(&self, ty: &Type) -> SlintType {
+        classify_with(ty, &|name| self.contains(name))
+    }
+}
+
+/// Rust 타입을 Slint 타입으로 분류합니다. 크레이트가 선언한 타입 이름은 고려하지 않습니다.
+pub fn classify(ty: &Type) -> SlintType {
+    classify_with(ty, &|_| false)
+}
+
+/// Rust 타입을 Slint 타입으로 분류합니다. `is_user_type` 은 한 단어 타입 이름이 크레이트가 선언한 타입인지 알려 줍니다.
+///
+/// 정수, 실수, `bool` 과 문자열 타입은 마지막 세그먼트 이름으로 판단합니다.
+/// `Color`, `Duration` 처럼 사용자 타입과 이름이 겹칠 수 있는 타입은 전체 경로(`slint::Color`, `std::time::Duration` 등)가 일치해야 하며,
+/// `use` 로 가져온 한 단어 이름은 크레이트가 같은 이름의 타입을 선언하지 않았을 때만 알려진 타입으로 봅니다.
+pub fn classify_with(ty: &Type, is_user_type: &dyn Fn(&str) -> bool) -> SlintType {
+    match ty {
+        Type::Array(array) => return SlintType::Array(Box::new(classify_with(&array.elem, is_user_type))),
+        Type::Slice(slice) => return SlintType::Array(Box::new(classify_with(&slice.elem, is_user_type))),
+        Type::Paren(paren) => return classify_with(&paren.elem, is_user_type),
+        Type::Group(group) => return classify_with(&group.elem, is_user_type),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => return SlintType::Void,
+        _ => {}
+    }
+
+    if let Some(inner) = option_inner(ty) {
+        return SlintType::Option(Box::new(classify_with(inner, is_user_type)));
+    }
+
+    let Type::Path(type_path) = ty else {
+        return SlintType::Other(quote!(#ty).to_string());
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return SlintType::Other(quote!(#ty).to_string());
+    };
+
+    match segment.ident.to_string().as_str() {
+        "i8" | "i16" | "i32" | "i64" | "isize" => return SlintType::Int,
+        "u8" | "u16" | "u32" | "u64" | "usize" => return SlintType::Int,
+        "f32" | "f64" => return SlintType::Float,
+        "bool" => return SlintType::Bool,
+        "char" | "String" | "ArrayString" => return SlintType::String,
+        _ => {}
+    }
+
+    let segments: Vec<String> = type_path.path.segments.iter().map(|seg| seg.ident.to_string()).collect();
+    let known = match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [name] if is_user_type(name) => None,
+        ["Color"] | ["slint", "Color"] => Some(SlintType::Color),
+        ["Brush"] | ["slint", "Brush"] => Some(SlintType::Brush),
+        ["Duration"] | ["std" | "core", "time", "Duration"] => Some(SlintType::Duration),
+        ["PixelImage"] | ["frand_property", "slint", "PixelImage"] => Some(SlintType::Image),
+        ["Length"] | ["frand_property", "slint", "Length"] => Some(SlintType::Length),
+        ["PhysicalLength"] | ["frand_property", "slint", "PhysicalLength"] => Some(SlintType::PhysicalLength),
+        _ => None,
+    };
+    known.unwrap_or_else(|| SlintType::Other(quote!(#ty).to_string()))
+}
+
+/// `Option<T>` 이면 `T` 를 반환합니다.
+pub fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// 토큰 사이의 공백을 줄여 Rust 코드처럼 보이는 타입 이름을 만듭니다. (`std :: time :: Duration` → `std::time::Duration`)
+pub fn rust_type_string(tokens: &impl quote::ToTokens) -> String {
+    tokens.to_token_stream().to_string()
+        .replace(" :: ", "::")
+        .replace(" < ", "<")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace("[ ", "[")
+        .replace(" ]", "]")
+        .replace(" ;", ";")
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::{lock_env, model, model_to, TempCrate};
+use frand_property_build::{generate_slint_files, scan_items, CfgContext, ScanErrors, ScanOptions};
+
+/// `OUT_DIR` 을 임시 크레이트 아래로 맞춥니다. 환경 변수를 바꾸므로 `lock_env` 를 잡은 채로 부릅니다.
+fn out_dir(krate: &TempCrate) -> std::path::PathBuf {
+    let out_dir = krate.path("out");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    std::env::set_var("OUT_DIR", &out_dir);
+    out_dir
+}
+
+#[test]
+fn test_scan_cache_in_out_dir() {
+    let _env = lock_env();
+    let krate = TempCrate::new("cache");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    let out_dir = out_dir(&krate);
+
+    let model = |field: &str| model_to("Adder", "adder", &format!("in {field}: i32,"));
+    krate.write("src/lib.rs", "mod adder;\n");
+    krate.write("src/adder.rs", &model("x"));
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+
+    let cache_path = out_dir.join("frand-property-scan-cache");
+    let cache = std::fs::read_to_string(&cache_path).unwrap();
+
+    // 소스가 그대로면 캐시된 정의를 사용합니다.
+    std::fs::write(&cache_path, cache.replace("x : i32", "cached : i32")).unwrap();
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+    let from_cache = krate.read("slint/components/adder.slint");
+
+    // 소스가 바뀌면 다시 파싱합니다.
+    krate.write("src/adder.rs", &model("y"));
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+    let reparsed = krate.read("slint/components/adder.slint");
+
+    assert!(cache.contains("macro slint_model "), "{cache}");
+    assert!(cache.contains("mod ") && cache.contains("adder.rs"), "{cache}");
+    assert!(from_cache.contains("    cached: int,"), "{from_cache}");
+    assert!(reparsed.contains("    y: int,") && !reparsed.contains("cached"), "{reparsed}");
+}
+
+#[test]
+fn test_scan_cache_invalidated_by_cfg_change() {
+    let _env = lock_env();
+    let krate = TempCrate::new("cache-cfg");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    let out_dir = out_dir(&krate);
+    std::env::remove_var("CARGO_FEATURE_EXTRA_UI");
+
+    krate.write("src/lib.rs", "mod adder;\n#[cfg(feature = \"extra-ui\")]\nmod extra;\n");
+    krate.write("src/adder.rs", &model("Adder"));
+    krate.write("src/extra.rs", &model("Extra"));
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+    let extra_without_feature = krate.path("slint/components/extra.slint").exists();
+
+    // 다른 조건으로 만든 캐시는 쓰지 않습니다. 그대로 쓰면 `cached` 필드가 생성됩니다.
+    let cache_path = out_dir.join("frand-property-scan-cache");
+    let cache = std::fs::read_to_string(&cache_path).unwrap();
+    std::fs::write(&cache_path, cache.replace("x : i32", "cached : i32")).unwrap();
+
+    std::env::set_var("CARGO_FEATURE_EXTRA_UI", "1");
+    let result = generate_slint_files(&src_dir, &output_dir);
+    std::env::remove_var("CARGO_FEATURE_EXTRA_UI");
+    result.unwrap();
+
+    let adder = krate.read("slint/components/adder.slint");
+    assert!(!extra_without_feature);
+    assert!(krate.path("slint/components/extra.slint").exists());
+    assert!(adder.contains("    x: int,") && !adder.contains("cached"), "{adder}");
+}
+
+#[test]
+fn test_scan_cache_recovers_from_missing_module() {
+    let _env = lock_env();
+    let krate = TempCrate::new("cache-missing");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    out_dir(&krate);
+
+    krate.write("src/lib.rs", "mod adder;\n");
+    let error = generate_slint_files(&src_dir, &output_dir).unwrap_err();
+
+    // 오류가 난 파일은 캐시하지 않으므로, 모듈 파일을 추가하면 다시 찾습니다.
+    krate.write("src/adder.rs", &model("Adder"));
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+
+    assert!(error.to_string().contains("file not found for module `adder`"), "{error}");
+    assert!(krate.read("slint/components/adder.slint").contains("    x: int,"));
+}
+
+#[test]
+fn test_unrelated_model_macro_is_ignored() {
+    let _env = lock_env();
+    let krate = TempCrate::new("cache-unrelated-model");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    out_dir(&krate);
+
+    // 크레이트가 직접 정의한 `model!` 은 `frand_property::model!` 문법이 아닙니다.
+    krate.write("src/lib.rs", "macro_rules! model { ($($t:tt)*) => {} }\nmodel!(1 + 2);\nmod adder;\n");
+    krate.write("src/adder.rs", &model("Adder"));
+
+    // 캐시가 없을 때와 캐시를 사용할 때 모두 오류가 없어야 합니다.
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+
+    // 스키마를 만들 때만 `model!` 을 해석하므로 오류가 됩니다.
+    let options = ScanOptions { rust_models: true };
+    let error = scan_items(&src_dir, &CfgContext::default(), options).err().unwrap();
+
+    assert!(krate.read("slint/components/adder.slint").contains("    x: int,"));
+    assert_eq!(error.downcast_ref::<ScanErrors>().unwrap().0.len(), 1, "{error}");
+}
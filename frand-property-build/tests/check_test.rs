@@ -0,0 +1,113 @@
+mod common;
+
+use common::{model, model_to, slint_paths, TempCrate};
+use frand_property_build::output::unified_diff;
+use frand_property_build::slint_syntax::DeclarationKind;
+use frand_property_build::{check_slint_files, generate_slint_files, SlintChange};
+
+#[test]
+fn test_check_reports_diffs_without_writing() {
+    let krate = TempCrate::new("check");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    krate.write("src/lib.rs", "mod adder;\n");
+    krate.write("src/adder.rs", &model("Adder"));
+
+    let missing = check_slint_files(&src_dir, &output_dir).unwrap();
+    let output_exists = output_dir.exists();
+
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+    let up_to_date = check_slint_files(&src_dir, &output_dir).unwrap();
+
+    krate.write("src/adder.rs", &model_to("Adder", "adder", "in x: i32,\n                out y: i32,"));
+    let changed = check_slint_files(&src_dir, &output_dir).unwrap();
+    let content = krate.read("slint/components/adder.slint");
+
+    assert!(!output_exists);
+    let paths = slint_paths(missing.iter().map(|d| d.path.as_path()));
+    assert_eq!(paths, vec![".frand-property-manifest", "components/adder.slint", "global/index.slint"]);
+    assert_eq!(missing[1].changes[..2], [
+        SlintChange::NewFile,
+        SlintChange::AddedDeclaration { kind: DeclarationKind::Struct, name: "AdderGlobalData".to_string() },
+    ]);
+    assert_eq!(missing[2].changes[1], SlintChange::MissingExport {
+        statement: "export { AdderGlobal } from \"../components/adder.slint\";".to_string(),
+    });
+
+    assert!(up_to_date.is_empty());
+
+    assert_eq!(changed.len(), 1);
+    assert!(!content.contains("y: int"), "check must not write files");
+    assert!(matches!(
+        &changed[0].changes[0],
+        SlintChange::ChangedDeclaration { kind: DeclarationKind::Struct, added, removed, .. }
+            if added == &["y: int,".to_string()] && removed.is_empty()
+    ));
+    assert!(changed[0].unified_diff().contains("\n     x: int,\n+    y: int,\n"), "{}", changed[0].unified_diff());
+}
+
+#[test]
+fn test_unified_diff() {
+    let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+    let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+
+    assert_eq!(unified_diff(old, old, "a/x", "b/x"), "");
+    assert_eq!(unified_diff(old, new, "a/x", "b/x"), concat!(
+        "--- a/x\n+++ b/x\n",
+        "@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n",
+        "@@ -8,3 +8,4 @@\n h\n i\n j\n+k\n",
+    ));
+    assert_eq!(unified_diff("", "x\n", "/dev/null", "b/x"), "--- /dev/null\n+++ b/x\n@@ -0,0 +1,1 @@\n+x\n");
+    // 사이의 문맥이 맞닿으면 하나의 hunk 가 됩니다.
+    assert_eq!(unified_diff("a\nb\nc\nd\ne\nf\ng\nh\n", "A\nb\nc\nd\ne\nf\ng\nH\n", "a/x", "b/x").matches("@@ -").count(), 1);
+}
+
+#[test]
+fn test_prune_stale_generated_output() {
+    let krate = TempCrate::new("prune");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    // 사용자가 작성한 선언과 export 는 정리 대상이 아닙니다.
+    krate.write("slint/components/shared.slint", "export struct UserItem {\n    id: int,\n}\n");
+    krate.write("slint/global/index.slint", "export { UserItem } from \"../components/shared.slint\";\n");
+
+    let model = |name: &str, file: &str| model_to(name, file, "in x: i32,");
+    krate.write("src/lib.rs", "mod model;\n");
+    krate.write("src/model.rs", &(model("Old", "shared") + &model("Gone", "gone")));
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+    let gone_created = krate.path("slint/components/gone.slint").exists();
+
+    krate.write("src/model.rs", &model("New", "shared"));
+    let diffs = check_slint_files(&src_dir, &output_dir).unwrap();
+    generate_slint_files(&src_dir, &output_dir).unwrap();
+
+    let shared = krate.read("slint/components/shared.slint");
+    let index = krate.read("slint/global/index.slint");
+    let gone_exists = krate.path("slint/components/gone.slint").exists();
+    let up_to_date = check_slint_files(&src_dir, &output_dir).unwrap();
+
+    assert!(gone_created);
+    assert!(!gone_exists);
+    assert!(shared.starts_with("export struct UserItem {\n    id: int,\n}\n\nexport struct NewGlobalData {"), "{shared}");
+    assert!(!shared.contains("OldGlobal"), "{shared}");
+    assert_eq!(index, "export { UserItem } from \"../components/shared.slint\";\nexport { NewGlobal } from \"../components/shared.slint\";\n");
+    assert!(up_to_date.is_empty());
+
+    let changes: Vec<_> = diffs.iter().flat_map(|d| d.changes.iter().cloned()).collect();
+    assert!(changes.contains(&SlintChange::DeletedFile));
+    assert!(changes.contains(&SlintChange::RemovedDeclaration { kind: DeclarationKind::Global, name: "OldGlobal".to_string() }));
+    assert!(changes.contains(&SlintChange::StaleExport { statement: "export { GoneGlobal } from \"../components/gone.slint\";".to_string() }));
+}
+
+#[test]
+fn test_check_fails_without_writing_on_scan_errors() {
+    let krate = TempCrate::new("check-errors");
+    krate.write("src/lib.rs", "mod adder;\nmod missing;\n");
+    krate.write("src/adder.rs", &model("Adder"));
+
+    let error = check_slint_files(krate.src_dir(), krate.output_dir()).unwrap_err();
+    let generate_error = generate_slint_files(krate.src_dir(), krate.output_dir()).unwrap_err();
+
+    assert!(error.to_string().contains("file not found for module `missing`"), "{error}");
+    assert_eq!(generate_error.to_string(), error.to_string());
+    // 일부 모델만으로 파일을 쓰지 않습니다.
+    assert!(!krate.output_dir().exists());
+}
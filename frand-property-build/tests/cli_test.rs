@@ -0,0 +1,89 @@
+mod common;
+
+use common::{model, TempCrate};
+use std::process::Command;
+
+fn run(args: &[&str]) -> (i32, String) {
+    let (code, stdout, _) = run_with_stderr(args);
+    (code, stdout)
+}
+
+fn run_with_stderr(args: &[&str]) -> (i32, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_frand-property-gen")).args(args).output().unwrap();
+    (output.status.code().unwrap(), String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn test_cli_subcommands() {
+    let krate = TempCrate::new("cli");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    krate.write("src/lib.rs", r#"
+        slint_enum! {
+            export to "components/adder.slint";
+            pub enum Mode { Add, Sub }
+        }
+
+        #[cfg(feature = "adder")]
+        slint_model! {
+            export to "components/adder.slint";
+            pub AdderModel: AdderGlobal {
+                in x: i32,
+                out mode: Mode,
+                callback format: (i32) -> String,
+            }
+        }
+    "#);
+    let (src, out) = (src_dir.to_str().unwrap(), output_dir.to_str().unwrap());
+
+    let (list_code, list) = run(&["list", "--src", src, "--features", "adder"]);
+    let (print_code, print) = run(&["print", "AdderModel", "--src", src, "--features", "adder"]);
+    let (missing_code, _) = run(&["print", "AdderModel", "--src", src]);
+    let (stale_code, _) = run(&["check", "--src", src, "--out", out, "--features", "adder"]);
+    let (generate_code, generated) = run(&["generate", "--src", src, "--out", out, "--features", "adder"]);
+    let (check_code, _) = run(&["check", "--src", src, "--out", out, "--features", "adder"]);
+    let (unknown_code, _) = run(&["list", "extra"]);
+    let schema_dir = krate.path("schema");
+    let (schema_code, schema) = run(&["schema", "--src", src, "--out", schema_dir.to_str().unwrap(), "--typescript", "--features", "adder"]);
+    let (schema_again_code, schema_again) = run(&["schema", "--src", src, "--out", schema_dir.to_str().unwrap(), "--typescript", "--features", "adder"]);
+    let (typescript_code, _) = run(&["list", "--typescript"]);
+
+    assert_eq!(list_code, 0);
+    assert!(list.contains("model AdderModel: AdderGlobal -> components/adder.slint\n    in x: i32\n    out mode: Mode\n    callback format: i32 -> String\n"), "{list}");
+    assert!(list.contains("enum Mode -> components/adder.slint\n    Add, Sub\n"), "{list}");
+    assert_eq!(print_code, 0);
+    assert!(print.starts_with("// components/adder.slint\nexport struct AdderGlobalData {"), "{print}");
+    assert!(print.contains("export global AdderGlobal {"), "{print}");
+    assert_eq!(missing_code, 2);
+    assert_eq!(stale_code, 1);
+    assert_eq!(generate_code, 0);
+    assert!(generated.contains("created ") && generated.contains("adder.slint"), "{generated}");
+    assert_eq!(check_code, 0);
+    assert_eq!(unknown_code, 2);
+    assert_eq!(schema_code, 0);
+    assert!(schema.contains("AdderModel.schema.json") && schema.contains("models.d.ts"), "{schema}");
+    assert_eq!(schema_again_code, 0);
+    assert_eq!(schema_again, "");
+    assert_eq!(typescript_code, 2);
+}
+
+#[test]
+fn test_cli_reports_scan_errors() {
+    let krate = TempCrate::new("cli-errors");
+    let (src_dir, output_dir) = (krate.src_dir(), krate.output_dir());
+    krate.write("src/lib.rs", &format!("mod missing;\n{}", model("Adder")));
+    let (src, out) = (src_dir.to_str().unwrap(), output_dir.to_str().unwrap());
+
+    let (list_code, list, list_err) = run_with_stderr(&["list", "--src", src]);
+    let (generate_code, generated, generate_err) = run_with_stderr(&["generate", "--src", src, "--out", out]);
+    let (check_code, _, check_err) = run_with_stderr(&["check", "--src", src, "--out", out]);
+
+    // 탐색 오류는 오래된 파일(종료 코드 1)과 구분해 2 로 끝나고, 아무 파일도 쓰지 않습니다.
+    for (code, stderr) in [(list_code, &list_err), (generate_code, &generate_err), (check_code, &check_err)] {
+        assert_eq!(code, 2, "{stderr}");
+        assert!(stderr.starts_with("error: "), "{stderr}");
+        assert!(stderr.contains("lib.rs:1:") && stderr.contains("file not found for module `missing`"), "{stderr}");
+    }
+    assert_eq!(list, "");
+    assert_eq!(generated, "");
+    assert!(!output_dir.exists());
+}
@@ -0,0 +1,80 @@
+//! 생성기 통합 테스트가 함께 쓰는 임시 크레이트와 모델 선언입니다.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// 테스트마다 따로 만드는 임시 크레이트 디렉토리입니다. 테스트가 실패해도 버릴 때 지웁니다.
+pub struct TempCrate {
+    pub root: PathBuf,
+}
+
+impl TempCrate {
+    /// `name` 은 같은 프로세스의 다른 테스트와 겹치지 않아야 합니다.
+    pub fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("frand-property-build-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        Self { root }
+    }
+
+    pub fn src_dir(&self) -> PathBuf {
+        self.root.join("src")
+    }
+
+    pub fn output_dir(&self) -> PathBuf {
+        self.root.join("slint")
+    }
+
+    pub fn path(&self, rel_path: &str) -> PathBuf {
+        self.root.join(rel_path)
+    }
+
+    /// 크레이트 루트 기준 경로에 파일을 씁니다. 디렉토리가 없으면 만듭니다.
+    pub fn write(&self, rel_path: &str, content: &str) {
+        write(&self.root.join(rel_path), content);
+    }
+
+    pub fn read(&self, rel_path: &str) -> String {
+        std::fs::read_to_string(self.root.join(rel_path)).unwrap()
+    }
+}
+
+impl Drop for TempCrate {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+pub fn write(path: &Path, content: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+/// `components/{file}.slint` 로 내보내는 `{name}Model: {name}Global` 선언입니다. `fields` 는 필드 선언 목록입니다.
+pub fn model_to(name: &str, file: &str, fields: &str) -> String {
+    format!(r#"
+        slint_model! {{
+            export to "components/{file}.slint";
+            pub {name}Model: {name}Global {{
+                {fields}
+            }}
+        }}
+    "#)
+}
+
+/// `in x: i32` 필드 하나를 가지고 `components/{name 소문자}.slint` 로 내보내는 모델 선언입니다.
+pub fn model(name: &str) -> String {
+    model_to(name, &name.to_lowercase(), "in x: i32,")
+}
+
+/// 출력 디렉토리 기준 경로 목록입니다. 경로 구분자는 `/` 입니다.
+pub fn slint_paths<'a>(paths: impl IntoIterator<Item = &'a Path>) -> Vec<String> {
+    paths.into_iter().map(|path| path.to_string_lossy().replace('\\', "/")).collect()
+}
+
+/// 환경 변수(`OUT_DIR`, `CARGO_FEATURE_*`)를 바꾸는 테스트를 차례로 실행하기 위한 잠금입니다.
+pub fn lock_env() -> MutexGuard<'static, ()> {
+    static ENV: Mutex<()> = Mutex::new(());
+    ENV.lock().unwrap_or_else(PoisonError::into_inner)
+}
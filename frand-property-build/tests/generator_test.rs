@@ -0,0 +1,343 @@
+use frand_property_build::generator::{generate_slint_code_with_types, generate_slint_doc, generate_slint_enum_file, generate_slint_file, generate_slint_struct_doc, merge_slint_enum, merge_slint_model};
+use frand_property_build::SlintOutput;
+use std::collections::BTreeSet;
+use frand_property_build::parser::{SlintEnum, SlintModel, SlintStruct};
+use frand_property_build::types::UserTypes;
+
+#[test]
+fn test_declared_init_in_slint_data() {
+    let model: SlintModel = syn::parse_quote! {
+        pub AdderModel: AdderGlobal {
+            in x: i32 = 1,
+            in values: i32[3] = [1, 2, -3],
+            out label: String = "sum",
+            out screen: ScreenVariant = ScreenVariant::Pay,
+            out sum: i32,
+        }
+    };
+
+    let code = generate_slint_code_with_types(&model, &UserTypes { enums: BTreeSet::from(["ScreenVariant".to_string()]), ..Default::default() });
+    assert!(code.contains(
+        r#"in-out property <[AdderGlobalData]> data: [{ x: 1, values: [1, 2, -3], label: "sum", screen: ScreenVariant.Pay }];"#
+    ), "{code}");
+
+    // 알려진 `slint_enum!` 타입이 아니면 경로를 옮기지 않습니다.
+    let doc = generate_slint_doc(&model);
+    assert!(doc.contains(r#"data: [{ x: 1, values: [1, 2, -3], label: "sum" }];"#), "{doc}");
+}
+
+#[test]
+fn test_merged_enum_init_in_slint_data() {
+    let item: SlintEnum = syn::parse_quote! {
+        pub enum ScreenVariant { Start, Pay }
+    };
+    let model: SlintModel = syn::parse_quote! {
+        pub ScreenModel: ScreenGlobal {
+            in screen: ScreenVariant = ScreenVariant::Pay,
+        }
+    };
+
+    let mut output = SlintOutput::new(std::env::temp_dir().join(format!("frand-property-build-enum-init-{}", std::process::id())));
+    merge_slint_enum(&item, &mut output).unwrap();
+    merge_slint_model(&model, &mut output).unwrap();
+    let content = output.diffs().into_iter()
+        .filter_map(|diff| diff.generated)
+        .find(|content| content.contains("export global ScreenGlobal"))
+        .unwrap();
+
+    assert!(content.contains("data: [{ screen: ScreenVariant.Pay }];"), "{content}");
+}
+
+#[test]
+fn test_user_enum_named_like_known_type() {
+    let item: SlintEnum = syn::parse_quote! {
+        pub enum Color { Red, Green }
+    };
+    let model: SlintModel = syn::parse_quote! {
+        pub PaintModel: PaintGlobal {
+            in color: Color = Color::Green,
+            out fill: slint::Color,
+        }
+    };
+
+    let mut output = SlintOutput::new(std::env::temp_dir().join(format!("frand-property-build-user-color-{}", std::process::id())));
+    merge_slint_enum(&item, &mut output).unwrap();
+    merge_slint_model(&model, &mut output).unwrap();
+    let content = output.diffs().into_iter()
+        .filter_map(|diff| diff.generated)
+        .find(|content| content.contains("export global PaintGlobal"))
+        .unwrap();
+
+    // 크레이트의 `Color` 열거형은 Slint `color` 가 아니라 열거형으로, `slint::Color` 는 `color` 로 생성합니다.
+    assert!(content.contains("    color: Color,"), "{content}");
+    assert!(content.contains("    fill: color,"), "{content}");
+    assert!(content.contains("data: [{ color: Color.Green }];"), "{content}");
+}
+
+#[test]
+fn test_declared_init_literal_conversion() {
+    let model: SlintModel = syn::parse_quote! {
+        pub LimitModel: LimitGlobal {
+            in max: i32 = i32::MAX,
+            in big: u64 = 5_000_000_000,
+            in low: i64 = -5_000_000_000,
+            #[frand(overflow = "wrap")]
+            in wrapped: u64 = 5_000_000_000,
+            #[frand(overflow = "reject")]
+            in rejected: u64 = 5_000_000_000,
+            #[frand(overflow = "reject")]
+            in values: i64[2] = [1, 5_000_000_000],
+            in ratio: f64 = 5_000_000_000.0,
+            out text: String = "{a} \\{b}",
+        }
+    };
+
+    let doc = generate_slint_doc(&model);
+
+    assert!(doc.contains(
+        r#"data: [{ big: 2147483647, low: -2147483648, wrapped: 705032704, ratio: 5000000000.0, text: "{a} \\{b}" }];"#
+    ), "{doc}");
+}
+
+#[test]
+fn test_declared_init_for_model_array() {
+    let model: SlintModel = syn::parse_quote! {
+        pub RowsModel[3]: RowsGlobal {
+            in x: i32 = 1,
+        }
+    };
+    let doc = generate_slint_doc(&model);
+    assert!(doc.contains("data: [{ x: 1 }, { x: 1 }, { x: 1 }];"), "{doc}");
+
+    // 길이를 알 수 없으면 행 수가 다른 기본값을 쓰지 않습니다.
+    let model: SlintModel = syn::parse_quote! {
+        pub RowsModel[ROWS]: RowsGlobal {
+            in x: i32 = 1,
+        }
+    };
+    let doc = generate_slint_doc(&model);
+    assert!(doc.contains("data: [{}];"), "{doc}");
+}
+
+#[test]
+fn test_doc_comments_and_rename_in_slint() {
+    let model: SlintModel = syn::parse_quote! {
+        /// 모델 설명
+        pub AdderModel: AdderGlobal {
+            /// 입력 값
+            #[frand(rename = "input-value")]
+            in x: i32 = 1,
+            /// 클릭 이벤트
+            callback clicked: (),
+        }
+    };
+
+    let doc = generate_slint_doc(&model);
+
+    assert!(doc.contains("    // 입력 값\n    input-value: int,"), "{doc}");
+    assert!(doc.contains("    // 모델 설명\n    in-out property <[AdderGlobalData]> data: [{ input-value: 1 }];"), "{doc}");
+    assert!(doc.contains("    // 클릭 이벤트\n    callback clicked(int);"), "{doc}");
+    assert!(doc.contains("    // 입력 값\n    in-out property <int> global-input-value: AdderGlobal.data[global-data-index].input-value;"), "{doc}");
+}
+
+#[test]
+fn test_computed_field_as_out_property() {
+    let model: SlintModel = syn::parse_quote! {
+        pub AdderModel: AdderGlobal {
+            in x: i32 = 1,
+            in y: i32,
+            computed sum: i32 = |x, y| x + y,
+        }
+    };
+
+    let doc = generate_slint_doc(&model);
+
+    assert!(doc.contains("    sum: int,"), "{doc}");
+    assert!(doc.contains("data: [{ x: 1 }];"), "{doc}");
+    assert!(doc.contains("    out property <int> global-sum: AdderGlobal.data[global-data-index].sum;"), "{doc}");
+}
+
+#[test]
+fn test_callback_with_return_type() {
+    let model: SlintModel = syn::parse_quote! {
+        pub PriceModel: PriceGlobal {
+            callback format_price: (f32) -> String,
+            callback describe: (i32, bool) -> String,
+        }
+    };
+
+    let doc = generate_slint_doc(&model);
+
+    assert!(doc.contains("    pure callback format-price(int, float) -> string;"), "{doc}");
+    assert!(doc.contains("    pure callback global-format-price(float) -> string;"), "{doc}");
+    assert!(doc.contains("    global-format-price(arg0) => { PriceGlobal.format-price(global-data-index, arg0) }"), "{doc}");
+    assert!(doc.contains("    pure callback describe(int, int, bool) -> string;"), "{doc}");
+    assert!(doc.contains("    global-describe(arg0, arg1) => { PriceGlobal.describe(global-data-index, arg0, arg1) }"), "{doc}");
+}
+
+#[test]
+fn test_action_with_pending_state() {
+    let model: SlintModel = syn::parse_quote! {
+        pub PayModel: PayGlobal {
+            action pay: (i32, String) -> bool,
+            action refresh: (),
+        }
+    };
+
+    let doc = generate_slint_doc(&model);
+
+    assert!(doc.contains("    pay-pending: bool,\n    pay-error: string,\n    pay-result: bool,"), "{doc}");
+    assert!(doc.contains("    refresh-pending: bool,\n    refresh-error: string,\n}"), "{doc}");
+    assert!(doc.contains("    callback pay(int, int, string);"), "{doc}");
+    assert!(doc.contains("    global-pay(arg0, arg1) => { PayGlobal.pay(global-data-index, arg0, arg1); }"), "{doc}");
+    assert!(doc.contains("    global-refresh => { PayGlobal.refresh(global-data-index); }"), "{doc}");
+    assert!(doc.contains("    out property <bool> global-pay-pending: PayGlobal.data[global-data-index].pay-pending;"), "{doc}");
+}
+
+#[test]
+fn test_slint_struct_doc() {
+    let item: SlintStruct = syn::parse_quote! {
+        /// 장바구니 항목
+        pub struct CartItem {
+            pub name: String,
+            #[frand(rename = "unit-price")]
+            pub price: i32,
+            pub kind: ItemKind,
+        }
+    };
+
+    let doc = generate_slint_struct_doc(&item);
+
+    assert!(doc.contains("export struct CartItem {\n    // 장바구니 항목\n    name: string,\n    unit-price: int,\n    kind: ItemKind,\n}"), "{doc}");
+}
+
+#[test]
+fn test_slint_enum_inserted_before_declarations() {
+    let output_dir = std::env::temp_dir().join(format!("frand-property-build-enum-{}", std::process::id()));
+    let path = output_dir.join("screen/screen.slint");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "import { Start } from \"start.slint\";\n\nexport struct ScreenGlobalData {\n    current-screen: ScreenVariant,\n}\n").unwrap();
+
+    let item: SlintEnum = syn::parse_quote! {
+        export to "screen/screen.slint";
+        pub enum ScreenVariant { Start, Pay }
+    };
+    generate_slint_enum_file(&item, &output_dir).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    let index = std::fs::read_to_string(output_dir.join("global/index.slint")).unwrap();
+    std::fs::remove_dir_all(&output_dir).unwrap();
+
+    assert_eq!(content, "import { Start } from \"start.slint\";\n\nexport enum ScreenVariant {\n    Start,\n    Pay,\n}\n\nexport struct ScreenGlobalData {\n    current-screen: ScreenVariant,\n}\n");
+    assert!(index.contains("export { ScreenVariant } from \"../screen/screen.slint\";"), "{index}");
+}
+
+#[test]
+fn test_rich_type_mapping_and_option_flag() {
+    let model: SlintModel = syn::parse_quote! {
+        pub StyleModel: StyleGlobal {
+            in delay: std::time::Duration = Duration::from_millis(300),
+            in limit: Option<i32> = Some(3),
+            out label: Option<String>,
+            out color: slint::Color,
+            out background: slint::Brush,
+            out icon: PixelImage,
+            out width: Length,
+            out border: PhysicalLength,
+        }
+    };
+
+    let doc = generate_slint_doc(&model);
+
+    assert!(doc.contains("    delay: duration,\n    limit: int,\n    has-limit: bool,\n    label: string,\n    has-label: bool,"), "{doc}");
+    assert!(doc.contains("    color: color,\n    background: brush,\n    icon: image,\n    width: length,\n    border: physical-length,"), "{doc}");
+    assert!(doc.contains("data: [{ delay: 300ms, limit: 3, has-limit: true }];"), "{doc}");
+    assert!(doc.contains("    changed global-has-limit => { StyleGlobal.data[global-data-index].has-limit = self.global-has-limit; }"), "{doc}");
+    assert!(doc.contains("    out property <bool> global-has-label: StyleGlobal.data[global-data-index].has-label;"), "{doc}");
+}
+
+#[test]
+fn test_merge_preserves_tricky_user_content() {
+    let output_dir = std::env::temp_dir().join(format!("frand-property-build-merge-{}", std::process::id()));
+    let path = output_dir.join("components/adder.slint");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let original = concat!(
+        "// 사용자 주석 { 는 무시됩니다\n",
+        "export struct AdderGlobalDataExtra {\n    label: string,\n}\n\n",
+        "export global AdderGlobal {\n",
+        "    // data: [{ x: 9 }];\n",
+        "    in-out property <[AdderGlobalData]> data: [{ x: 3 }];\n",
+        "}\n\n",
+        "export component Panel inherits Rectangle {\n",
+        "    Text { text: \"} \\{ \"{\" } }\"; }\n",
+        "}\n",
+    );
+    std::fs::write(&path, original).unwrap();
+
+    let model: SlintModel = syn::parse_quote! {
+        export to "components/adder.slint";
+        pub AdderModel: AdderGlobal {
+            in x: i32,
+        }
+    };
+    generate_slint_file(&model, &output_dir).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(&path, "export global AdderGlobal {\n    in property <string> s: \"unterminated;\n}\n").unwrap();
+    let error = generate_slint_file(&model, &output_dir).unwrap_err().to_string();
+    std::fs::remove_dir_all(&output_dir).unwrap();
+
+    assert!(content.starts_with("// 사용자 주석 { 는 무시됩니다\nexport struct AdderGlobalDataExtra {\n    label: string,\n}\n"), "{content}");
+    assert!(content.contains("    in-out property <[AdderGlobalData]> data: [{ x: 3 }];"), "{content}");
+    assert!(content.contains("    Text { text: \"} \\{ \"{\" } }\"; }\n"), "{content}");
+    assert!(content.contains("export struct AdderGlobalData {\n    x: int,\n}"), "{content}");
+    assert!(content.contains("export component AdderGlobalComponent inherits Rectangle {"), "{content}");
+    assert!(error.ends_with("adder.slint:2:29: unterminated string literal"), "{error}");
+}
+
+#[test]
+fn test_regeneration_keeps_user_comments() {
+    let output_dir = std::env::temp_dir().join(format!("frand-property-build-comments-{}", std::process::id()));
+    let path = output_dir.join("global/counter.slint");
+    let model: SlintModel = syn::parse_quote! {
+        pub CounterModel: CounterGlobal {
+            out count: i32,
+            callback reset: (),
+            callback label: i32,
+        }
+    };
+    generate_slint_file(&model, &output_dir).unwrap();
+
+    // 생성된 global 안에 사용자 주석을 넣습니다.
+    let content = std::fs::read_to_string(&path).unwrap()
+        .replace("    callback reset(int);", "    // 떨어진 주석\n\n    callback reset(int); // 줄 끝 주석")
+        .replace("    callback label", "    // 바로 위에 붙은 주석\n    callback label");
+    std::fs::write(&path, &content).unwrap();
+
+    // 콜백 인자를 바꾸고, 필드를 지우고, 새 필드를 더합니다.
+    let model: SlintModel = syn::parse_quote! {
+        pub CounterModel: CounterGlobal {
+            callback reset: (),
+            callback label: String,
+            /// 새 필드
+            out total: i32,
+        }
+    };
+    generate_slint_file(&model, &output_dir).unwrap();
+    let regenerated = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_dir_all(&output_dir).unwrap();
+
+    assert!(regenerated.contains(concat!(
+        "export global CounterGlobal {\n",
+        "    in-out property <[CounterGlobalData]> data: [{}];\n",
+        "    // 떨어진 주석\n",
+        "\n",
+        "    callback reset(int); // 줄 끝 주석\n",
+        "    // 바로 위에 붙은 주석\n",
+        "    callback label(int, string);\n",
+        "}\n",
+    )), "{regenerated}");
+    assert!(regenerated.contains("export struct CounterGlobalData {\n    // 새 필드\n    total: int,\n}\n"), "{regenerated}");
+    assert!(regenerated.contains("    global-label(val) => { CounterGlobal.label(global-data-index, val); }\n    // 새 필드\n"), "{regenerated}");
+    assert!(!regenerated.contains("count"), "{regenerated}");
+}
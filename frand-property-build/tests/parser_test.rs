@@ -0,0 +1,70 @@
+use frand_property_build::parser::{doc_lines, Model, Overflow, SlintEnum, SlintModel};
+
+#[test]
+fn test_field_attrs_are_split() {
+    let model: SlintModel = syn::parse_quote! {
+        /// 모델 설명
+        export to "components/adder.slint";
+        #[allow(dead_code)]
+        pub AdderModel: AdderGlobal {
+            /// 첫 줄
+            ///
+            /// 셋째 줄
+            #[frand(rename = "input-value")]
+            #[allow(dead_code)]
+            in x: i32,
+            out sum: i32,
+        }
+    };
+
+    assert_eq!(model.attrs.len(), 2);
+    assert_eq!(doc_lines(&model.attrs), vec!["모델 설명"]);
+
+    let x = &model.fields[0];
+    assert_eq!(x.attrs.len(), 4);
+    assert_eq!(doc_lines(&x.attrs), vec!["첫 줄", "", "셋째 줄"]);
+    assert_eq!(x.slint_name(), "input-value");
+    assert_eq!(x.slint_ident().to_string(), "input_value");
+    assert_eq!(model.fields[1].slint_name(), "sum");
+}
+
+#[test]
+fn test_invalid_frand_options() {
+    let unknown = syn::parse_str::<Model>("pub M { #[frand(unknown)] pub x: i32 }");
+    assert!(unknown.is_err_and(|e| e.to_string().contains("unknown frand option")));
+
+    let invalid_name = syn::parse_str::<SlintModel>("pub M: G { #[frand(rename = \"1x\")] in x: i32 }");
+    assert!(invalid_name.is_err_and(|e| e.to_string().contains("valid Slint identifier")));
+
+    let on_model = syn::parse_str::<Model>("#[frand(rename = \"x\")] pub M { pub x: i32 }");
+    assert!(on_model.is_err_and(|e| e.to_string().contains("only supported on fields")));
+}
+
+#[test]
+fn test_overflow_option() {
+    let model = syn::parse_str::<SlintModel>("pub M: G { #[frand(overflow = \"reject\")] in x: u64, out y: usize }").unwrap();
+    assert_eq!(model.fields[0].options.overflow(), Overflow::Reject);
+    assert_eq!(model.fields[1].options.overflow(), Overflow::Saturate);
+
+    let invalid = syn::parse_str::<SlintModel>("pub M: G { #[frand(overflow = \"clamp\")] in x: u64 }");
+    assert!(invalid.is_err_and(|e| e.to_string().contains("`overflow` must be one of")));
+}
+
+#[test]
+fn test_callback_return_type() {
+    let model = syn::parse_str::<SlintModel>("pub M: G { callback format_price: (f32) -> String }").unwrap();
+    assert!(model.fields[0].ret.is_some());
+
+    let on_in = syn::parse_str::<SlintModel>("pub M: G { in x: i32 -> String }");
+    assert!(on_in.is_err_and(|e| e.to_string().contains("only supported on `callback` and `action` fields")));
+}
+
+#[test]
+fn test_slint_enum_variants() {
+    let item = syn::parse_str::<SlintEnum>("export to \"a.slint\"; pub enum Kind { A, B, }").unwrap();
+    assert_eq!(item.export_path.as_deref(), Some("a.slint"));
+    assert_eq!(item.variants.len(), 2);
+
+    let with_fields = syn::parse_str::<SlintEnum>("pub enum Kind { A(i32) }");
+    assert!(with_fields.is_err_and(|e| e.to_string().contains("cannot have fields")));
+}
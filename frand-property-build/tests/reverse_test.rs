@@ -0,0 +1,59 @@
+use frand_property_build::generator::generate_slint_code;
+use frand_property_build::parser::SlintModel;
+use frand_property_build::reverse::reverse_slint_models;
+use std::path::Path;
+
+#[test]
+fn test_reverse_round_trip() {
+    let model: SlintModel = syn::parse_quote! {
+        /// 모델 설명
+        pub ShopModel: ShopGlobal {
+            /// 수량
+            in count: i32,
+            #[frand(rename = "item_label")]
+            out label: String,
+            in limit: Option<i32>,
+            out mode: ShopMode,
+            out delay: std::time::Duration,
+            callback format_price: (f32, bool) -> String,
+            callback clicked: (),
+            /// 결제
+            action pay: (i32) -> bool,
+        }
+    };
+    let code = generate_slint_code(&model);
+
+    let reversed = reverse_slint_models(&code, Path::new("shop.slint"), Some("components/shop.slint")).unwrap();
+    assert_eq!(reversed.len(), 1);
+    assert!(reversed[0].issues.is_empty(), "{:?}", reversed[0].issues);
+
+    let source = &reversed[0].source;
+    assert!(source.starts_with("slint_model! {\n    export to \"components/shop.slint\";\n    /// 모델 설명\n    pub ShopModel: ShopGlobal {\n"), "{source}");
+    assert!(source.contains("        /// 수량\n        in count: i32,\n"), "{source}");
+    assert!(source.contains("        #[frand(rename = \"item_label\")]\n        out item_label: String,\n"), "{source}");
+    assert!(source.contains("        in limit: Option<i32>,\n"), "{source}");
+    assert!(source.contains("        /// 결제\n        action pay: (i32) -> bool,\n"), "{source}");
+    assert!(source.contains("        callback format_price: (f32, bool) -> String,\n        callback clicked: (),\n"), "{source}");
+
+    // 되돌린 선언은 원래 모델과 같은 Slint 코드를 생성합니다.
+    let item: syn::ItemMacro = syn::parse_str(source).unwrap();
+    let model: SlintModel = syn::parse2(item.mac.tokens).unwrap();
+    assert_eq!(generate_slint_code(&model), code);
+}
+
+#[test]
+fn test_reverse_reports_unmapped_fields() {
+    let source = concat!(
+        "export struct PanelGlobalData {\n    title: string,\n    ratio: percent,\n    items: [int],\n}\n\n",
+        "export global PanelGlobal {\n    in-out property <[PanelGlobalData]> data;\n    callback pressed();\n}\n\n",
+        "export component Panel inherits Rectangle {\n    out property <string> title: PanelGlobal.data[0].title;\n}\n",
+    );
+
+    let reversed = reverse_slint_models(source, Path::new("panel.slint"), None).unwrap();
+    let issues: Vec<_> = reversed[0].issues.iter().map(|issue| issue.field.as_str()).collect();
+
+    assert_eq!(issues, ["ratio", "items", "pressed"]);
+    assert!(reversed[0].source.contains("        out title: String,\n"), "{}", reversed[0].source);
+    assert!(reversed[0].source.contains("        // TODO: `ratio`: Slint type `percent` has no Rust counterpart\n"), "{}", reversed[0].source);
+    assert!(reversed[0].source.contains("        callback pressed: (),\n"), "{}", reversed[0].source);
+}
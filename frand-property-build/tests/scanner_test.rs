@@ -0,0 +1,127 @@
+mod common;
+
+use common::{model, slint_paths, TempCrate};
+use frand_property_build::{plan_slint_files, CfgContext, ScanErrors};
+
+/// 생성할 `components/` 아래 파일 목록입니다.
+fn planned_paths(krate: &TempCrate, cfg: &CfgContext) -> Vec<String> {
+    let output = plan_slint_files(&krate.src_dir(), &krate.output_dir(), cfg).unwrap();
+    let mut paths: Vec<_> = slint_paths(output.diffs().iter().map(|d| d.path.as_path()))
+        .into_iter()
+        .filter(|p| p.starts_with("components/"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// 크레이트를 탐색하며 모은 오류의 (파일 이름, 줄, 메시지) 목록입니다.
+fn scan_errors(krate: &TempCrate) -> Vec<(String, usize, String)> {
+    let error = plan_slint_files(&krate.src_dir(), &krate.output_dir(), &CfgContext::default()).unwrap_err();
+    error.downcast_ref::<ScanErrors>().unwrap().0.iter()
+        .map(|e| (e.path.file_name().unwrap().to_string_lossy().into_owned(), e.line, e.message.clone()))
+        .collect()
+}
+
+#[test]
+fn test_scan_follows_modules_cfg_and_aliases() {
+    let krate = TempCrate::new("scan");
+
+    krate.write("src/lib.rs", "mod screen;\n#[path = \"custom/place.rs\"]\nmod place;\n#[cfg(feature = \"extra-ui\")]\nmod extra;\n#[cfg(not(feature = \"extra-ui\"))]\nmod fallback;\n");
+    krate.write("src/screen/mod.rs", &format!("mod start;\n{}", model("Screen")));
+    krate.write("src/screen/start.rs", &format!("use frand_property::slint_model as model_of;\nmod inner {{ }}\n{}", model("Start").replace("slint_model!", "model_of!")));
+    krate.write("src/custom/place.rs", &model("Place"));
+    krate.write("src/extra.rs", &model("Extra"));
+    krate.write("src/fallback.rs", &model("Fallback"));
+    // 어느 모듈에서도 선언하지 않은 파일은 무시합니다.
+    krate.write("src/orphan.rs", &model("Orphan"));
+
+    let default = planned_paths(&krate, &CfgContext::default());
+    let extra = planned_paths(&krate, &CfgContext::default().with_feature("extra-ui"));
+
+    assert_eq!(default, ["components/fallback.slint", "components/place.slint", "components/screen.slint", "components/start.slint"]);
+    assert_eq!(extra, ["components/extra.slint", "components/place.slint", "components/screen.slint", "components/start.slint"]);
+}
+
+#[test]
+fn test_scan_binary_targets_and_manifest_paths() {
+    let krate = TempCrate::new("scan-bins");
+
+    krate.write("Cargo.toml", "[package]\nname = \"app\"\n\n[lib]\npath = \"src/app_lib.rs\"\n\n[[bin]]\nname = \"tool\"\npath = \"tools/tool.rs\"\n");
+    krate.write("src/app_lib.rs", &model("Lib"));
+    krate.write("src/bin/single.rs", &model("Single"));
+    // `bin/<name>/main.rs` 의 하위 모듈은 그 디렉토리에서 찾습니다.
+    krate.write("src/bin/multi/main.rs", "mod panel;\n");
+    krate.write("src/bin/multi/panel.rs", &model("Panel"));
+    krate.write("tools/tool.rs", &model("Tool"));
+
+    let paths = planned_paths(&krate, &CfgContext::default());
+
+    assert_eq!(paths, ["components/lib.slint", "components/panel.slint", "components/single.slint", "components/tool.slint"]);
+}
+
+#[test]
+fn test_scan_aliases_are_scoped_to_their_module() {
+    let krate = TempCrate::new("scan-alias-scope");
+
+    // `ui` 는 `first` 에서만 `slint_model!` 의 별칭입니다. `second` 의 `ui!` 는 다른 매크로입니다.
+    krate.write("src/lib.rs", &format!(
+        "mod first {{\n    use frand_property::slint_model as ui;\n    {}\n}}\nmod second {{\n    macro_rules! ui {{ ($($t:tt)*) => {{}} }}\n    ui! {{ not a model }}\n}}\nmod third;\n",
+        model("First").replace("slint_model!", "ui!"),
+    ));
+    krate.write("src/third.rs", "ui! { also not a model }\n");
+
+    assert_eq!(planned_paths(&krate, &CfgContext::default()), ["components/first.slint"]);
+}
+
+#[test]
+fn test_scan_reports_all_errors_with_locations() {
+    let krate = TempCrate::new("scan-errors");
+
+    krate.write("src/main.rs", "mod missing;\nmod broken;\nmod bad_model;\n");
+    krate.write("src/broken.rs", "fn broken( {\n");
+    krate.write("src/bad_model.rs", "slint_model! {\n    pub BadModel: BadGlobal {\n        sideways x: i32,\n    }\n}\n");
+
+    let error = plan_slint_files(&krate.src_dir(), &krate.output_dir(), &CfgContext::default()).unwrap_err();
+
+    let errors = &error.downcast_ref::<ScanErrors>().unwrap().0;
+    let locations: Vec<_> = errors.iter()
+        .map(|e| (e.path.file_name().unwrap().to_string_lossy().into_owned(), e.line, e.column))
+        .collect();
+    assert_eq!(errors.len(), 3, "{error}");
+    assert!(locations.contains(&("main.rs".to_string(), 1, 5)), "{error}");
+    assert!(locations.iter().any(|(file, line, _)| file == "broken.rs" && *line == 1), "{error}");
+    assert!(locations.contains(&("bad_model.rs".to_string(), 3, 9)), "{error}");
+    assert!(error.to_string().contains("file not found for module `missing`"), "{error}");
+}
+
+#[test]
+fn test_scan_missing_nested_module() {
+    let krate = TempCrate::new("scan-missing-nested");
+
+    // 하위 모듈은 부모 모듈의 디렉토리에서 찾습니다. 크레이트 루트에 같은 이름의 파일이 있어도 사용하지 않습니다.
+    krate.write("src/lib.rs", "mod screen;\n");
+    krate.write("src/screen.rs", "mod start;\n");
+    krate.write("src/start.rs", &model("Start"));
+
+    let errors = scan_errors(&krate);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!((errors[0].0.as_str(), errors[0].1), ("screen.rs", 1));
+    assert!(errors[0].2.starts_with("file not found for module `start`"), "{errors:?}");
+    assert!(errors[0].2.contains(&format!("screen{}start.rs", std::path::MAIN_SEPARATOR)), "{errors:?}");
+}
+
+#[test]
+fn test_scan_rejects_module_in_both_file_and_directory() {
+    let krate = TempCrate::new("scan-conflict");
+
+    krate.write("src/lib.rs", "mod screen;\n");
+    krate.write("src/screen.rs", &model("File"));
+    krate.write("src/screen/mod.rs", &model("Directory"));
+
+    let errors = scan_errors(&krate);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!((errors[0].0.as_str(), errors[0].1), ("lib.rs", 1));
+    assert!(errors[0].2.starts_with("file for module `screen` found at both "), "{errors:?}");
+}
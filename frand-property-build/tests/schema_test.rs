@@ -0,0 +1,109 @@
+use frand_property_build::schema::{generate_schema_files, json_schema, schema_models, typescript_declarations};
+use frand_property_build::{scan_items, CfgContext, ScanOptions};
+
+const SOURCE: &str = r#"
+slint_enum! {
+    pub enum Mode { Idle, Busy }
+}
+
+slint_struct! {
+    pub struct Point { x: i32, y: Option<f32> }
+}
+
+model! {
+    /// 안쪽 모델
+    pub InnerModel {
+        pub out total: u32,
+    }
+}
+
+model! {
+    pub OuterModel[4] {
+        pub model inner: InnerModel,
+        pub samples: [i32; 5],
+        pub points: [Point; CAPACITY],
+    }
+}
+
+slint_model! {
+    pub PanelModel[2]: PanelGlobal {
+        /// 현재 모드
+        in mode: Mode,
+        out label: Option<String>,
+        callback clicked: (i32, String),
+        callback measure: Point -> f32,
+        action save: String -> bool,
+    }
+}
+"#;
+
+fn scan(name: &str) -> (std::path::PathBuf, frand_property_build::ScannedItems) {
+    let root = std::env::temp_dir().join(format!("frand-property-build-schema-{name}-{}", std::process::id()));
+    let src_dir = root.join("src");
+    std::fs::create_dir_all(&src_dir).unwrap();
+    std::fs::write(src_dir.join("lib.rs"), SOURCE).unwrap();
+    let items = scan_items(&src_dir, &CfgContext::default(), ScanOptions { rust_models: true }).unwrap();
+    (root, items)
+}
+
+#[test]
+fn test_json_schema() {
+    let (root, items) = scan("json");
+    std::fs::remove_dir_all(&root).unwrap();
+
+    let models = schema_models(&items);
+    let names: Vec<_> = models.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["PanelModel", "InnerModel", "OuterModel"]);
+
+    let outer = json_schema(&models[2], &items);
+    assert!(outer.contains(r#""$schema": "https://json-schema.org/draft/2020-12/schema""#), "{outer}");
+    // 인스턴스 배열
+    assert!(outer.contains("\"type\": \"array\",\n  \"items\": {\n    \"$ref\": \"#/$defs/OuterModel\"\n  },\n  \"minItems\": 4,\n  \"maxItems\": 4"), "{outer}");
+    // 중첩 모델과 slint_struct! 는 $defs 로 참조합니다.
+    assert!(outer.contains("\"InnerModel\": {\n      \"type\": \"object\",\n      \"description\": \"안쪽 모델\""), "{outer}");
+    assert!(outer.contains("\"Point\": {"), "{outer}");
+    assert!(outer.contains("\"$ref\": \"#/$defs/InnerModel\""), "{outer}");
+    // 고정 길이 배열
+    assert!(outer.contains("\"minItems\": 5,\n          \"maxItems\": 5"), "{outer}");
+    assert!(outer.contains("\"x-length\": \"CAPACITY\""), "{outer}");
+    assert!(outer.contains("\"readOnly\": true"), "{outer}");
+
+    let panel = json_schema(&models[0], &items);
+    assert!(panel.contains("\"x-slint-global\": \"PanelGlobal\""), "{panel}");
+    assert!(panel.contains("\"enum\": [\n        \"Idle\",\n        \"Busy\"\n      ]"), "{panel}");
+    assert!(panel.contains("\"required\": [\n        \"mode\"\n      ]"), "{panel}");
+    assert!(panel.contains("\"x-callbacks\": {\n        \"clicked\": {\n          \"params\": [\n            {\n              \"type\": \"integer\"\n            },\n            {\n              \"type\": \"string\"\n            }\n          ]\n        },"), "{panel}");
+    assert!(panel.contains("\"measure\": {\n          \"params\": [\n            {\n              \"$ref\": \"#/$defs/Point\"\n            }\n          ],\n          \"returns\": {\n            \"type\": \"number\"\n          }\n        }"), "{panel}");
+    assert!(panel.contains("\"x-actions\": {\n        \"save\": {"), "{panel}");
+}
+
+#[test]
+fn test_typescript_declarations() {
+    let (root, items) = scan("ts");
+    std::fs::remove_dir_all(&root).unwrap();
+
+    let declarations = typescript_declarations(&items);
+    for expected in [
+        "export type Mode = \"Idle\" | \"Busy\";",
+        "export interface Point {\n    x: number;\n    y: number | null;\n}",
+        "/** @length 2 */\nexport interface PanelModel {\n    /** 현재 모드 */\n    mode: Mode;\n    readonly label: string | null;\n    clicked(arg0: number, arg1: string): void;\n    measure(arg0: Point): number;\n    save(arg0: string): Promise<boolean>;\n}",
+        "export interface OuterModel {\n    inner: InnerModel;\n    samples: number[];\n    points: Point[];\n}",
+    ] {
+        assert!(declarations.contains(expected), "missing {expected:?} in\n{declarations}");
+    }
+}
+
+#[test]
+fn test_generate_schema_files() {
+    let (root, _) = scan("files");
+    let output_dir = root.join("schema");
+    generate_schema_files(root.join("src"), &output_dir, true).unwrap();
+
+    let mut files: Vec<_> = std::fs::read_dir(&output_dir).unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    files.sort();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(files, ["InnerModel.schema.json", "OuterModel.schema.json", "PanelModel.schema.json", "models.d.ts"]);
+}
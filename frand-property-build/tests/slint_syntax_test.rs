@@ -0,0 +1,100 @@
+use frand_property_build::slint_syntax::{tokenize, DeclarationKind, MemberKind, SlintFile, TokenKind};
+
+#[test]
+fn test_tokenize_strings_and_comments() {
+    let source = "a-b /* x /* y */ } */ \"q \\{ f(\"}\") } r\" 10px";
+    let kinds: Vec<_> = tokenize(source).unwrap().into_iter()
+        .filter(|t| t.kind != TokenKind::Whitespace)
+        .map(|t| (t.kind, &source[t.start..t.end]))
+        .collect();
+
+    assert_eq!(kinds, vec![
+        (TokenKind::Ident, "a-b"),
+        (TokenKind::Comment, "/* x /* y */ } */"),
+        (TokenKind::String, "\"q \\{"),
+        (TokenKind::Ident, "f"),
+        (TokenKind::Punct('('), "("),
+        (TokenKind::String, "\"}\""),
+        (TokenKind::Punct(')'), ")"),
+        (TokenKind::String, "} r\""),
+        (TokenKind::Number, "10px"),
+    ]);
+}
+
+#[test]
+fn test_declarations_and_property_value() {
+    let source = concat!(
+        "import { Button } from \"std-widgets.slint\";\n",
+        "export { Other } from \"other.slint\";\n\n",
+        "// 붙은 주석\n",
+        "export struct AdderData { x: int }\n",
+        "export struct Adder { y: int }\n",
+        "global G {\n",
+        "    // property <int> data: 1;\n",
+        "    in-out property <[{ a: int }]> data: [{ a: 1 }, { a: 2 }];\n",
+        "}\n",
+        "Legacy := Rectangle { }\n",
+    );
+    let file = SlintFile::parse(source).unwrap();
+
+    let names: Vec<_> = file.declarations().iter().map(|d| (d.kind, d.name.as_str(), d.exported)).collect();
+    assert_eq!(names, vec![
+        (DeclarationKind::Struct, "AdderData", true),
+        (DeclarationKind::Struct, "Adder", true),
+        (DeclarationKind::Global, "G", false),
+        (DeclarationKind::Component, "Legacy", false),
+    ]);
+
+    let adder = file.find(DeclarationKind::Struct, "Adder").unwrap();
+    assert_eq!(&source[adder.open_brace..=adder.close_brace], "{ y: int }");
+    assert!(source[file.declarations()[0].start..].starts_with("// 붙은 주석\nexport struct AdderData"));
+
+    let global = file.find(DeclarationKind::Global, "G").unwrap();
+    assert_eq!(file.property_value(global, "data"), Some("[{ a: 1 }, { a: 2 }]"));
+    assert_eq!(file.property_value(global, "missing"), None);
+}
+
+#[test]
+fn test_parse_errors() {
+    let unclosed = SlintFile::parse("export global G {\n    in property <int> x;\n").unwrap_err();
+    assert_eq!((unclosed.line, unclosed.column), (1, 17));
+    assert!(unclosed.message.contains("unclosed"));
+
+    let duplicate = SlintFile::parse("struct A {}\nstruct A {}").unwrap_err();
+    assert_eq!(duplicate.to_string(), "2:8: `A` is declared more than once");
+
+    let unknown = SlintFile::parse("export global G {}\n}").unwrap_err();
+    assert_eq!(unknown.to_string(), "2:1: unmatched `}`");
+}
+
+#[test]
+fn test_members() {
+    let source = concat!(
+        "export struct Data {\n    // 값\n    x: int,\n    items: [{ a: int }]\n}\n",
+        "export component C inherits Rectangle {\n",
+        "    // 버려지는 주석\n\n",
+        "    /* 입력 */\n    in-out property <int> x: 1;\n",
+        "    changed x => { debug(\"}\"); }\n",
+        "    property <[Data]> list;\n",
+        "    pure callback format(index: int, [string]) -> string;\n",
+        "    Text { text: \"x\"; }\n",
+        "    callback clicked;\n",
+        "}\n",
+    );
+    let file = SlintFile::parse(source).unwrap();
+
+    let fields = file.members(file.find(DeclarationKind::Struct, "Data").unwrap());
+    let fields: Vec<_> = fields.iter().map(|m| (m.kind, m.name.as_str(), m.ty.as_deref(), m.comments.clone())).collect();
+    assert_eq!(fields, vec![
+        (MemberKind::Field, "x", Some("int"), vec!["값".to_string()]),
+        (MemberKind::Field, "items", Some("[{a:int}]"), vec![]),
+    ]);
+
+    let members = file.members(file.find(DeclarationKind::Component, "C").unwrap());
+    assert_eq!(members.len(), 4, "{members:?}");
+    assert_eq!((members[0].qualifier.as_deref(), members[0].name.as_str(), members[0].ty.as_deref()), (Some("in-out"), "x", Some("int")));
+    assert_eq!(members[0].comments, ["입력"]);
+    assert_eq!((members[1].qualifier.as_deref(), members[1].name.as_str(), members[1].ty.as_deref()), (None, "list", Some("[Data]")));
+    assert_eq!((members[2].kind, members[2].pure, members[2].args.clone(), members[2].ret.as_deref()), (MemberKind::Callback, true, vec!["int".to_string(), "[string]".to_string()], Some("string")));
+    assert_eq!((members[3].name.as_str(), members[3].args.len(), members[3].ret.as_deref()), ("clicked", 0, None));
+}
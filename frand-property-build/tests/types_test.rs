@@ -0,0 +1,38 @@
+use std::collections::BTreeSet;
+use frand_property_build::types::{classify, option_inner, SlintType, UserTypes};
+
+#[test]
+fn test_classify_types() {
+    assert_eq!(classify(&syn::parse_quote!(u8)), SlintType::Int);
+    assert_eq!(classify(&syn::parse_quote!(ArrayString<U20>)), SlintType::String);
+    assert_eq!(classify(&syn::parse_quote!(slint::Color)), SlintType::Color);
+    assert_eq!(classify(&syn::parse_quote!(std::time::Duration)), SlintType::Duration);
+    assert_eq!(classify(&syn::parse_quote!(frand_property::slint::PhysicalLength)), SlintType::PhysicalLength);
+    assert_eq!(classify(&syn::parse_quote!([Length; 3])), SlintType::Array(Box::new(SlintType::Length)));
+    assert_eq!(classify(&syn::parse_quote!(Option<PixelImage>)), SlintType::Option(Box::new(SlintType::Image)));
+    assert_eq!(classify(&syn::parse_quote!(ScreenVariant)), SlintType::Other("ScreenVariant".to_string()));
+
+    assert_eq!(classify(&syn::parse_quote!([Duration; 2])).slint_name(), "[duration]");
+    assert_eq!(classify(&syn::parse_quote!(Option<bool>)).slint_name(), "bool");
+}
+
+#[test]
+fn test_classify_full_paths_and_user_types() {
+    // 다른 모듈의 같은 이름 타입은 알려진 타입이 아닙니다.
+    assert!(matches!(classify(&syn::parse_quote!(palette::Color)), SlintType::Other(_)));
+    assert!(matches!(classify(&syn::parse_quote!(timing::Duration)), SlintType::Other(_)));
+    assert_eq!(classify(&syn::parse_quote!(core::time::Duration)), SlintType::Duration);
+
+    // 크레이트가 선언한 이름은 한 단어로 쓰면 사용자 타입입니다.
+    let user_types = UserTypes { enums: BTreeSet::from(["Color".to_string()]), ..Default::default() };
+    assert_eq!(user_types.classify(&syn::parse_quote!(Color)), SlintType::Other("Color".to_string()));
+    assert_eq!(user_types.classify(&syn::parse_quote!(slint::Color)), SlintType::Color);
+    assert_eq!(user_types.classify(&syn::parse_quote!(Brush)), SlintType::Brush);
+}
+
+#[test]
+fn test_option_inner() {
+    let ty: syn::Type = syn::parse_quote!(std::option::Option<i32>);
+    assert!(option_inner(&ty).is_some());
+    assert!(option_inner(&syn::parse_quote!(Vec<i32>)).is_none());
+}
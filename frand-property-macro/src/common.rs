@@ -1,6 +1,13 @@
 use proc_macro2::{TokenStream};
-use quote::quote;
-use syn::{Type};
+use quote::{quote, format_ident};
+use syn::{Attribute, Expr, Ident, Type};
+use heck::ToUpperCamelCase;
+use frand_property_build::parser::{FieldOptions, Overflow};
+use frand_property_build::types::{classify_with, SlintType, UserTypes};
+use frand_property_build::{scan_slint_items, CfgContext};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock, PoisonError};
 
 pub fn resolve_type(ty: &Type) -> TokenStream {
     if let Type::Path(tp) = ty {
@@ -10,11 +17,9 @@ pub fn resolve_type(ty: &Type) -> TokenStream {
                     if let Some(syn::GenericArgument::Type(Type::Path(type_path))) = args.args.first() {
                          if let Some(inner_seg) = type_path.path.segments.last() {
                              let ident_str = inner_seg.ident.to_string();
-                             if ident_str.starts_with('U') {
-                                 if ident_str[1..].parse::<u32>().is_ok() {
-                                     let n = &inner_seg.ident;
-                                     return quote! { ArrayString<#n> };
-                                 }
+                             if ident_str.strip_prefix('U').is_some_and(|n| n.parse::<u32>().is_ok()) {
+                                 let n = &inner_seg.ident;
+                                 return quote! { ArrayString<#n> };
                              }
                          }
                     }
@@ -55,16 +60,334 @@ pub fn is_unit_ty(ty: &Type) -> bool {
     }
 }
 
-pub fn generate_vec_init_tokens(len: impl quote::ToTokens, elem_ty: impl quote::ToTokens) -> TokenStream {
+/// 배열(`[T; N]`) 또는 슬라이스(`[T]`) 타입이면 요소 타입을 분리합니다.
+pub fn split_array_type(ty: &Type) -> (bool, &Type) {
+    if let Type::Array(arr) = ty {
+        (true, arr.elem.as_ref())
+    } else if let Type::Slice(slice) = ty {
+        (true, slice.elem.as_ref())
+    } else {
+        (false, ty)
+    }
+}
+
+/// 스칼라 필드의 초기값 식을 생성합니다. 초기값이 없으면 `Default::default()`를 사용합니다.
+pub fn generate_init_value(ty: &Type, init: Option<&Expr>) -> TokenStream {
+    let resolved_ty = resolve_type(ty);
+
+    match init {
+        Some(expr) => convert_init_expr(ty, quote! { #expr }),
+        None => quote! { <#resolved_ty as Default>::default() },
+    }
+}
+
+/// 배열 필드의 초기값 목록(`Vec<T>`)을 생성합니다. 초기값이 없으면 모든 요소가 `Default::default()`입니다.
+/// 초기값 목록의 길이는 필드 길이와 같아야 합니다.
+pub fn generate_init_values(f_name: &Ident, len: &Expr, elem_ty: &Type, init: Option<&Expr>) -> TokenStream {
+    let resolved_ty = resolve_type(elem_ty);
+
+    let Some(expr) = init else {
+        return quote! {
+            {
+                let mut v: std::vec::Vec<#resolved_ty> = std::vec::Vec::with_capacity(#len);
+                for _ in 0..#len {
+                    v.push(<#resolved_ty as Default>::default());
+                }
+                v
+            }
+        };
+    };
+
+    // 길이와 초기값 목록이 모두 리터럴이면 컴파일 시점에 검사합니다.
+    if let (Expr::Array(array), Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(len_lit), .. })) = (expr, len) {
+        if len_lit.base10_parse::<usize>().ok() != Some(array.elems.len()) {
+            proc_macro_error::abort!(
+                expr,
+                "initial value of `{}` has {} elements, but the field length is {}",
+                f_name, array.elems.len(), len_lit,
+            );
+        }
+    }
+
+    let convert = convert_init_expr(elem_ty, quote! { value });
+    let message = format!("initial value of `{}` must have exactly `{}` elements", f_name, quote!(#len));
+
     quote! {
         {
-            let mut v = std::vec::Vec::with_capacity(#len);
-            for _ in 0..#len {
-                v.push(<#elem_ty as Default>::default().into());
-            }
+            let v: std::vec::Vec<#resolved_ty> = std::iter::IntoIterator::into_iter(#expr)
+                .map(|value| #convert)
+                .collect();
+            assert_eq!(v.len(), #len, #message);
             v
         }
     }
 }
 
+/// 필드의 정수 변환 방식(`#[frand(overflow = "...")]`)과 범위 오류를 보고할 때 쓸 필드 이름입니다.
+pub struct IntPolicy {
+    overflow: Overflow,
+    field: String,
+}
+
+impl IntPolicy {
+    pub fn new(options: &FieldOptions, field: &Ident) -> Self {
+        Self {
+            overflow: options.overflow(),
+            field: field.to_string(),
+        }
+    }
+
+    fn overflow_tokens(&self) -> TokenStream {
+        match self.overflow {
+            Overflow::Saturate => quote! { frand_property::slint::Overflow::Saturate },
+            Overflow::Wrap => quote! { frand_property::slint::Overflow::Wrap },
+            Overflow::Reject => quote! { frand_property::slint::Overflow::Reject },
+        }
+    }
+}
+
+/// 매크로를 사용하는 크레이트가 `slint_enum!`/`slint_struct!` 로 선언한 타입 이름인지 여부.
+/// `Color` 처럼 알려진 타입과 이름이 같은 한 단어 타입에만 호출되며, 크레이트의 `src` 는 크레이트마다 한 번만 탐색합니다.
+/// 탐색에 실패하면 선언된 타입이 없는 것으로 봅니다.
+fn is_user_type(name: &str) -> bool {
+    static USER_TYPES: OnceLock<Mutex<HashMap<PathBuf, UserTypes>>> = OnceLock::new();
+
+    let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") else { return false };
+    let src_dir = PathBuf::from(manifest_dir).join("src");
+    let mut user_types = USER_TYPES.get_or_init(Default::default).lock().unwrap_or_else(PoisonError::into_inner);
+    user_types.entry(src_dir)
+        .or_insert_with_key(|src_dir| {
+            scan_slint_items(src_dir, &CfgContext::from_env()).map(|items| items.user_types()).unwrap_or_default()
+        })
+        .contains(name)
+}
+
+/// 크레이트가 선언한 타입 이름을 고려하여 Rust 타입을 Slint 타입으로 분류합니다.
+fn classify(ty: &Type) -> SlintType {
+    classify_with(ty, &is_user_type)
+}
+
+/// Slint `int`(`i32`) 와 범위가 달라 검사가 필요한 정수 타입인지 여부.
+fn is_checked_int_type(ty: &Type) -> bool {
+    classify(ty) == SlintType::Int && !matches!(ty, Type::Path(tp) if tp.path.segments.last().is_some_and(|seg| seg.ident == "i32"))
+}
+
+fn is_f64_type(ty: &Type) -> bool {
+    matches!(ty, Type::Path(tp) if tp.path.segments.last().is_some_and(|seg| seg.ident == "f64"))
+}
+
+/// Rust 값을 Slint 데이터 구조체의 필드 값으로 변환하는 식을 생성합니다.
+/// 식의 타입은 `Option<_>` 이며, `overflow = "reject"` 로 값이 버려지면 `None` 입니다.
+/// `Option<T>` 는 값과 `has-*` 플래그 두 필드로 나뉘므로 여기서는 지원하지 않습니다.
+pub fn to_slint_value(ty: &Type, value: TokenStream, policy: &IntPolicy) -> TokenStream {
+    reject_slint_image(ty);
+    match classify(ty) {
+        SlintType::Option(_) => reject_option(ty),
+        SlintType::Duration => {
+            let overflow = policy.overflow_tokens();
+            let field = &policy.field;
+            quote! { frand_property::slint::to_slint_duration(#value, #overflow, #field) }
+        }
+        SlintType::Int if is_checked_int_type(ty) => {
+            let overflow = policy.overflow_tokens();
+            let field = &policy.field;
+            quote! { frand_property::slint::to_slint_int(#value, #overflow, #field) }
+        }
+        SlintType::Float if is_f64_type(ty) => quote! { Some(#value as f32) },
+        _ if is_special_string_type(ty) => quote! { Some(#value.to_string().into()) },
+        _ => quote! { Some(#value.into()) },
+    }
+}
+
+/// Slint 에서 전달된 값을 Rust 타입의 값으로 변환하는 식을 생성합니다.
+/// 식의 타입은 `Option<_>` 이며, `overflow = "reject"` 로 값이 버려지면 `None` 입니다.
+pub fn from_slint_value(ty: &Type, value: TokenStream, policy: &IntPolicy) -> TokenStream {
+    reject_slint_image(ty);
+    let resolved_ty = resolve_type(ty);
+    match classify(ty) {
+        SlintType::Option(_) => reject_option(ty),
+        SlintType::Duration => {
+            let overflow = policy.overflow_tokens();
+            let field = &policy.field;
+            quote! { frand_property::slint::from_slint_duration(#value, #overflow, #field) }
+        }
+        SlintType::Int if is_checked_int_type(ty) => {
+            let overflow = policy.overflow_tokens();
+            let field = &policy.field;
+            quote! { frand_property::slint::from_slint_int::<#resolved_ty>(#value, #overflow, #field) }
+        }
+        _ if is_array_string_type(ty) => quote! { Some(<#resolved_ty>::from_str_truncate(#value.as_str())) },
+        _ if is_std_string_type(ty) => quote! { Some(#value.to_string()) },
+        _ => quote! { Some(#value.into()) },
+    }
+}
+
+/// Slint 값과 변환이 필요한 타입(`Duration`, `PixelImage`, `Length`, `PhysicalLength`, `Option`, `i32` 외의 정수, `f64`)인지 여부.
+/// `in` 배열처럼 Slint 모델을 그대로 공유하는 필드는 이런 타입을 사용할 수 없습니다.
+pub fn is_converted_slint_type(ty: &Type) -> bool {
+    matches!(
+        classify(ty),
+        SlintType::Duration | SlintType::Image | SlintType::Length | SlintType::PhysicalLength | SlintType::Option(_)
+    ) || is_checked_int_type(ty) || is_f64_type(ty)
+}
+
+/// 속성 값은 스레드 사이에서 전달되지만 `slint::Image` 는 `Send`/`Sync` 가 아니므로, `PixelImage` 를 쓰도록 안내합니다.
+fn reject_slint_image(ty: &Type) {
+    let Type::Path(tp) = ty else { return };
+    let segments: Vec<_> = tp.path.segments.iter().map(|seg| seg.ident.to_string()).collect();
+    if segments == ["slint", "Image"] {
+        proc_macro_error::abort!(ty, "`slint::Image` is not `Send`, so it cannot be stored in a property. Use `frand_property::slint::PixelImage` instead.");
+    }
+}
+
+fn reject_option(ty: &Type) -> ! {
+    proc_macro_error::abort!(ty, "`Option` types are only supported on scalar `in`, `out` and `computed` fields")
+}
+
+fn convert_init_expr(ty: &Type, expr: TokenStream) -> TokenStream {
+    let resolved_ty = resolve_type(ty);
+
+    if is_array_string_type(ty) {
+        quote! { <#resolved_ty>::from_str_truncate(#expr) }
+    } else if is_std_string_type(ty) {
+        quote! { std::string::String::from(#expr) }
+    } else {
+        expr
+    }
+}
+
+/// 필드 이름으로부터 `{ModelName}Change` 열거형의 variant 이름을 만듭니다.
+pub fn change_variant_ident(f_name: &Ident) -> Ident {
+    format_ident!("{}", f_name.to_string().to_upper_camel_case(), span = f_name.span())
+}
+
+/// `{ModelName}Change` 열거형의 variant 정의를 생성합니다.
+/// 배열 필드는 인덱스를, 유닛 타입 필드는 값 없이 variant 만 가집니다.
+pub fn generate_change_variant(f_name: &Ident, docs: &[&Attribute], value_ty: &TokenStream, is_array: bool, is_unit: bool) -> TokenStream {
+    let variant = change_variant_ident(f_name);
+
+    match (is_array, is_unit) {
+        (false, false) => quote! { #(#docs)* #variant(#value_ty) },
+        (true, false) => quote! { #(#docs)* #variant(usize, #value_ty) },
+        (false, true) => quote! { #(#docs)* #variant },
+        (true, true) => quote! { #(#docs)* #variant(usize) },
+    }
+}
+
+/// 필드의 변경 스트림(`source`)을 `{ModelName}Change` 이벤트 스트림으로 변환하는 코드를 생성합니다.
+/// 배열 필드는 `source` 안에서 `index` 를 사용할 수 있습니다.
+pub fn generate_change_stream(
+    change_name: &Ident,
+    f_name: &Ident,
+    items: TokenStream,
+    source: TokenStream,
+    is_array: bool,
+    is_unit: bool,
+) -> TokenStream {
+    let variant = change_variant_ident(f_name);
+
+    let map_fn = match (is_array, is_unit) {
+        (false, false) => quote! { #change_name::#variant },
+        (true, false) => quote! { move |value| #change_name::#variant(index, value) },
+        (false, true) => quote! { |_| #change_name::#variant },
+        (true, true) => quote! { move |_| #change_name::#variant(index) },
+    };
+
+    if is_array {
+        quote! {
+            for (index, item) in #items.iter().enumerate() {
+                streams.push(std::boxed::Box::pin(frand_property::StreamExt::map(#source, #map_fn)));
+            }
+        }
+    } else {
+        quote! {
+            {
+                let item = &#items;
+                streams.push(std::boxed::Box::pin(frand_property::StreamExt::map(#source, #map_fn)));
+            }
+        }
+    }
+}
+
+/// `computed` 필드의 소스 필드 정보입니다.
+pub struct ComputedSource {
+    pub name: Ident,
+    /// 계산 클로저의 인자 타입. 배열 필드는 `Vec<T>` 입니다.
+    pub arg_ty: TokenStream,
+    pub is_array: bool,
+    /// 계산 클로저가 캡처하여 값을 읽을 핸들 (`Receiver`/`Sender` 또는 그 목록).
+    pub capture: TokenStream,
+    /// 변경을 감시할 `&Sender` 들의 반복자.
+    pub senders: TokenStream,
+}
+
+/// `computed` 필드의 계산 클로저와, 클로저 인자로 선언된 소스 필드 이름을 추출합니다.
+pub fn parse_computed_closure<'a>(f_name: &Ident, ty: &Type, init: Option<&'a Expr>) -> (&'a syn::ExprClosure, Vec<Ident>) {
+    if split_array_type(ty).0 {
+        proc_macro_error::abort!(f_name, "`computed` fields cannot be arrays.");
+    }
+
+    let closure = match init {
+        Some(Expr::Closure(closure)) => closure,
+        Some(expr) => proc_macro_error::abort!(expr, "`computed` field `{}` must be initialized with a closure over its source fields, e.g. `|a, b| a + b`", f_name),
+        None => proc_macro_error::abort!(f_name, "`computed` field `{}` requires a closure over its source fields, e.g. `computed {}: T = |a, b| a + b`", f_name, f_name),
+    };
+
+    let sources = closure.inputs.iter().map(|input| {
+        let pat = match input {
+            syn::Pat::Type(pat_type) => pat_type.pat.as_ref(),
+            pat => pat,
+        };
+        match pat {
+            syn::Pat::Ident(pat_ident) => pat_ident.ident.clone(),
+            pat => proc_macro_error::abort!(pat, "`computed` closure arguments must be source field names"),
+        }
+    }).collect();
+
+    (closure, sources)
+}
+
+/// 소스 값으로 결과를 계산하는 클로저(`{field}_compute`)를 생성합니다.
+pub fn generate_computed_fn(f_name: &Ident, closure: &syn::ExprClosure, sources: &[ComputedSource], ret_ty: &TokenStream) -> TokenStream {
+    let f_compute = format_ident!("{}_compute", f_name);
+    let arg_tys = sources.iter().map(|s| &s.arg_ty);
+    let captures = sources.iter().map(|s| {
+        let name = &s.name;
+        let capture = &s.capture;
+        quote! { let #name = #capture; }
+    });
+    let args = sources.iter().map(|s| {
+        let name = &s.name;
+        if s.is_array {
+            quote! { #name.iter().map(|item| item.value()).collect() }
+        } else {
+            quote! { #name.value() }
+        }
+    });
 
+    quote! {
+        let #f_compute = {
+            let compute: fn(#(#arg_tys),*) -> #ret_ty = #closure;
+            #(#captures)*
+            move || compute(#(#args),*)
+        };
+    }
+}
+
+/// 계산 클로저를 `{field}_prop` 의 `Sender` 에 연결하고, 소스가 바뀔 때마다 다시 계산하도록 등록합니다.
+pub fn generate_computed_watch(f_name: &Ident, sources: &[ComputedSource]) -> TokenStream {
+    let f_prop = format_ident!("{}_prop", f_name);
+    let f_compute = format_ident!("{}_compute", f_name);
+    let senders = sources.iter().map(|s| &s.senders);
+
+    quote! {
+        {
+            let computed = frand_property::Computed::new(#f_prop.sender().clone(), #f_compute);
+            #(
+                for sender in #senders {
+                    computed.watch(sender);
+                }
+            )*
+        }
+    }
+}
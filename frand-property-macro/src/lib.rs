@@ -3,6 +3,7 @@ use proc_macro_error::proc_macro_error;
 use syn::parse_macro_input;
 
 mod slint_model;
+mod slint_type;
 mod model;
 mod common;
 
@@ -22,6 +23,28 @@ pub fn slint_model(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn slint_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as frand_property_build::parser::SlintEnum);
+    let slint_doc = frand_property_build::generator::generate_slint_enum_doc(&input);
+    let expanded = slint_type::codegen_rust::generate_enum(&input, quote::quote! {
+        #[doc = #slint_doc]
+    });
+    TokenStream::from(expanded)
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn slint_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as frand_property_build::parser::SlintStruct);
+    let slint_doc = frand_property_build::generator::generate_slint_struct_doc(&input);
+    let expanded = slint_type::codegen_rust::generate_struct(&input, quote::quote! {
+        #[doc = #slint_doc]
+    });
+    TokenStream::from(expanded)
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn model(input: TokenStream) -> TokenStream {
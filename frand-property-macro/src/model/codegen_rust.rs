@@ -1,27 +1,184 @@
 use proc_macro2::TokenStream;
 use quote::{quote, format_ident};
 use syn::Type;
-use frand_property_build::parser::Model;
-use crate::common::resolve_type;
+use frand_property_build::parser::{doc_attrs, Direction, Model, ModelField};
+use crate::common::{resolve_type, is_unit_ty, split_array_type, generate_init_value, generate_init_values, generate_change_variant, generate_change_stream, ComputedSource, parse_computed_closure, generate_computed_fn, generate_computed_watch};
+
+/// 필드가 모델 또는 Owner 에 노출되는 형태입니다.
+#[derive(Clone, Copy, PartialEq)]
+enum Handle {
+    Property,
+    Sender,
+    Receiver,
+}
+
+/// 공개 모델에 노출되는 핸들. 중첩 모델은 `None` 입니다.
+/// `in`/`callback` 은 쓰기만, `out`/`computed` 는 읽기만 가능합니다.
+fn public_handle(f: &ModelField) -> Option<Handle> {
+    match f.direction {
+        // `action` 은 `slint_model!` 전용이므로 `model!` 필드에는 나타나지 않습니다.
+        Direction::Model | Direction::Action => None,
+        Direction::In | Direction::Callback => Some(Handle::Sender),
+        Direction::Out | Direction::Computed => Some(Handle::Receiver),
+        Direction::InOut => Some(Handle::Property),
+    }
+}
+
+/// Owner 에 노출되는 핸들. 공개 모델과 반대쪽 권한을 가집니다.
+fn owner_handle(f: &ModelField) -> Option<Handle> {
+    match f.direction {
+        Direction::Model | Direction::Action => None,
+        Direction::In | Direction::Callback | Direction::Computed => Some(Handle::Receiver),
+        Direction::Out => Some(Handle::Sender),
+        Direction::InOut => Some(Handle::Property),
+    }
+}
+
+/// 공개 모델에서 쓸 수 있는 필드 (`{ModelName}Sender` 에 포함)
+fn is_writable(f: &ModelField) -> bool {
+    f.is_model() || matches!(public_handle(f), Some(Handle::Property | Handle::Sender))
+}
+
+/// 공개 모델에서 읽을 수 있는 필드 (`{ModelName}Receiver`, `{ModelName}Values` 에 포함)
+fn is_readable(f: &ModelField) -> bool {
+    f.is_model() || matches!(public_handle(f), Some(Handle::Property | Handle::Receiver))
+}
+
+/// 방향 키워드(`in`/`out`/`callback`)를 사용하는 필드가 있으면 Owner 를 생성합니다.
+fn has_owner(input: &Model) -> bool {
+    input.fields.iter().any(|f| matches!(f.direction, Direction::In | Direction::Out | Direction::Callback))
+}
+
+fn handle_ty(handle: Handle, resolved_ty: &TokenStream, is_array: bool) -> TokenStream {
+    let ty = match handle {
+        Handle::Property => quote! { frand_property::Property<#resolved_ty> },
+        Handle::Sender => quote! { frand_property::Sender<#resolved_ty> },
+        Handle::Receiver => quote! { frand_property::Receiver<#resolved_ty> },
+    };
+
+    if is_array {
+        quote! { std::sync::Arc<[#ty]> }
+    } else {
+        ty
+    }
+}
+
+/// `from` 핸들(또는 그 배열)인 `expr` 로부터 `to` 핸들을 복제하는 식을 생성합니다.
+fn convert_handle(from: Handle, to: Handle, expr: TokenStream, is_array: bool) -> TokenStream {
+    let method = match (from, to) {
+        (from, to) if from == to => return quote! { #expr.clone() },
+        (Handle::Property, Handle::Sender) => quote! { sender },
+        (Handle::Property, Handle::Receiver) => quote! { receiver },
+        _ => unreachable!("only a Property can be split into a Sender or a Receiver"),
+    };
+
+    if is_array {
+        quote! { #expr.iter().map(|p| p.#method().clone()).collect::<std::vec::Vec<_>>().into() }
+    } else {
+        quote! { #expr.#method().clone() }
+    }
+}
+
+/// 생성 중에 지역 변수로 만들어지는 필드의 핸들. computed 필드는 이미 Receiver 입니다.
+fn local_handle(f: &ModelField) -> Handle {
+    if f.is_computed() {
+        Handle::Receiver
+    } else {
+        Handle::Property
+    }
+}
 
 pub fn generate(input: &Model) -> TokenStream {
     let vis = &input.vis;
     let model_name = &input.model_name;
+    let model_attrs = &input.attrs;
 
-    let field_defs = generate_field_defs(input);
-    let init_fields = generate_init_fields(input);
-    let init_logic = quote! { #(#init_fields),* };
+    if let Some(f) = input.fields.iter().find(|f| f.options.rename.is_some()) {
+        proc_macro_error::abort!(f.options.rename, "`rename` is only supported in `slint_model!` fields.");
+    }
+    if let Some((_, span)) = input.fields.iter().find_map(|f| f.options.overflow) {
+        proc_macro_error::abort!(span, "`overflow` is only supported in `slint_model!` and `slint_struct!` fields.");
+    }
 
     let sender_name = format_ident!("{}Sender", model_name);
     let receiver_name = format_ident!("{}Receiver", model_name);
+    let owner_name = format_ident!("{}Owner", model_name);
+    let values_name = format_ident!("{}Values", model_name);
+    let change_name = format_ident!("{}Change", model_name);
     let instance_ident = format_ident!("{}_INSTANCE", model_name.to_string().to_uppercase());
 
+    let has_owner = has_owner(input);
+
+    let field_defs = generate_field_defs(input, public_handle);
+    let init_fields = generate_init_fields(input);
+    let public_inits = generate_handle_inits(input, public_handle);
+
+    let (owner_field_def, owner_field_init, owner_init, owner_defs) = if has_owner {
+        let owner_field_defs = generate_field_defs(input, owner_handle);
+        let owner_inits = generate_handle_inits(input, owner_handle);
+        let owner_change_streams = generate_change_streams(input.fields.iter(), &change_name, owner_handle);
+
+        (
+            quote! { _owner: #owner_name },
+            quote! { _owner },
+            quote! {
+                let _owner = #owner_name {
+                    #(#owner_inits),*
+                };
+            },
+            quote! {
+                /// 모델의 권한이 있는 쪽(`in` 필드의 `Receiver`, `out` 필드의 `Sender`)을 가지는 핸들입니다.
+                /// 모델을 선언한 모듈 안에서만 `clone_owner()` 로 얻을 수 있습니다.
+                #[derive(Debug, Clone)]
+                #vis struct #owner_name {
+                    #(#owner_field_defs),*
+                }
+
+                impl #owner_name {
+                    /// 모든 필드의 변경 이벤트를 전달하는 스트림을 반환합니다.
+                    pub fn changes(&self) -> frand_property::ChangeStream<#change_name> {
+                        let mut streams: std::vec::Vec<frand_property::ChangeStream<#change_name>> = std::vec::Vec::new();
+                        #(#owner_change_streams)*
+                        frand_property::merge_changes(streams)
+                    }
+                }
+
+                impl #model_name {
+                    #[allow(dead_code)]
+                    fn clone_owner(&self) -> #owner_name {
+                        self._owner.clone()
+                    }
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {}, quote! {})
+    };
+
+    let init_logic = quote! {
+        {
+            #(#init_fields)*
+            #owner_init
+            Self {
+                #(#public_inits,)*
+                #owner_field_init
+            }
+        }
+    };
+
     let sender_field_defs = generate_sender_field_defs(input);
     let receiver_field_defs = generate_receiver_field_defs(input);
     
     let clone_sender_logic = generate_clone_sender_logic(input);
     let clone_receiver_logic = generate_clone_receiver_logic(input);
 
+    let values_field_defs = generate_values_field_defs(input);
+    let receiver_group_impl = generate_receiver_group_impl(input, &receiver_name, &values_name);
+    let sender_group_impl = generate_sender_group_impl(input, &sender_name, &values_name);
+
+    let change_variants = generate_change_variants(input);
+    let change_streams = generate_change_streams(input.fields.iter().filter(|f| is_readable(f)), &change_name, public_handle);
+
     let (new_ret_ty, static_ret_ty, new_body, init_method) = if let Some(len) = &input.len {
         (
             quote! { std::sync::Arc<[Self]> },
@@ -30,9 +187,7 @@ pub fn generate(input: &Model) -> TokenStream {
                 let weak = ();
                 let mut models = std::vec::Vec::with_capacity(#len);
                 for _ in 0..#len {
-                    models.push(Self {
-                        #init_logic
-                    });
+                    models.push(#init_logic);
                 }
                 models.into()
             },
@@ -51,9 +206,7 @@ pub fn generate(input: &Model) -> TokenStream {
             quote! { std::sync::Arc<#model_name> },
             quote! {
                 let weak = ();
-                std::sync::Arc::new(Self {
-                    #init_logic
-                })
+                std::sync::Arc::new(#init_logic)
             },
             quote! {
                 pub fn init_singleton(init: impl FnOnce(&Self)) -> Self {
@@ -71,8 +224,10 @@ pub fn generate(input: &Model) -> TokenStream {
         static #instance_ident: std::sync::OnceLock<#static_ret_ty> = std::sync::OnceLock::new();
 
         #[derive(Debug, Clone)]
+        #(#model_attrs)*
         #vis struct #model_name {
-            #(#field_defs),*
+            #(#field_defs,)*
+            #owner_field_def
         }
         
         #[derive(Debug, Clone)]
@@ -85,6 +240,22 @@ pub fn generate(input: &Model) -> TokenStream {
             #(#receiver_field_defs),*
         }
 
+        #[derive(Debug, Clone, PartialEq)]
+        #vis struct #values_name {
+            #(#values_field_defs),*
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        #vis enum #change_name {
+            #(#change_variants),*
+        }
+
+        #owner_defs
+
+        #receiver_group_impl
+
+        #sender_group_impl
+
         impl #model_name {
             pub fn clone_singleton() -> #new_ret_ty {
                 #instance_ident.get_or_init(|| {
@@ -105,6 +276,12 @@ pub fn generate(input: &Model) -> TokenStream {
                     #(#clone_receiver_logic),*
                 }
             }
+
+            pub fn changes(&self) -> frand_property::ChangeStream<#change_name> {
+                let mut streams: std::vec::Vec<frand_property::ChangeStream<#change_name>> = std::vec::Vec::new();
+                #(#change_streams)*
+                frand_property::merge_changes(streams)
+            }
         }
 
         impl frand_property::Model for #model_name {
@@ -119,177 +296,327 @@ pub fn generate(input: &Model) -> TokenStream {
                 self.clone_receiver()
             }
         }
+
+        impl frand_property::ModelChanges for #model_name {
+            type Change = #change_name;
+
+            fn changes(&self) -> frand_property::ChangeStream<Self::Change> {
+                self.changes()
+            }
+        }
     }
 }
 
 fn generate_sender_field_defs(input: &Model) -> Vec<TokenStream> {
-    input.fields.iter().map(|f| {
+    input.fields.iter().filter(|f| is_writable(f)).map(|f| {
         let f_name = &f.name;
-        let f_ty = &f.ty;
         let f_vis = &f.vis;
-
-        let (is_array, elem_ty) = if let Type::Array(arr) = f_ty {
-            (true, arr.elem.as_ref())
-        } else if let Type::Slice(slice) = f_ty {
-            (true, slice.elem.as_ref())
-        } else {
-            (false, f_ty)
-        };
-        
+        let f_attrs = &f.attrs;
+        let (is_array, elem_ty) = split_array_type(&f.ty);
         let resolved_ty = resolve_type(elem_ty);
 
-        if f.is_model {
+        if f.is_model() {
              if is_array {
-                quote! { #f_vis #f_name: std::sync::Arc<[<#resolved_ty as frand_property::Model>::Sender]> }
+                quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<[<#resolved_ty as frand_property::Model>::Sender]> }
              } else {
-                 quote! { #f_vis #f_name: <#resolved_ty as frand_property::Model>::Sender }
+                 quote! { #(#f_attrs)* #f_vis #f_name: <#resolved_ty as frand_property::Model>::Sender }
              }
         } else {
-            if is_array {
-                quote! { #f_vis #f_name: std::sync::Arc<[frand_property::Sender<#resolved_ty>]> }
-            } else {
-                quote! { #f_vis #f_name: frand_property::Sender<#resolved_ty> }
-            }
+            let ty = handle_ty(Handle::Sender, &resolved_ty, is_array);
+            quote! { #(#f_attrs)* #f_vis #f_name: #ty }
         }
     }).collect()
 }
 
 fn generate_receiver_field_defs(input: &Model) -> Vec<TokenStream> {
-    input.fields.iter().map(|f| {
+    input.fields.iter().filter(|f| is_readable(f)).map(|f| {
         let f_name = &f.name;
-        let f_ty = &f.ty;
         let f_vis = &f.vis;
-
-        let (is_array, elem_ty) = if let Type::Array(arr) = f_ty {
-            (true, arr.elem.as_ref())
-        } else if let Type::Slice(slice) = f_ty {
-            (true, slice.elem.as_ref())
-        } else {
-            (false, f_ty)
-        };
-
+        let f_attrs = &f.attrs;
+        let (is_array, elem_ty) = split_array_type(&f.ty);
         let resolved_ty = resolve_type(elem_ty);
 
-        if f.is_model {
+        if f.is_model() {
              if is_array {
-                quote! { #f_vis #f_name: std::sync::Arc<[<#resolved_ty as frand_property::Model>::Receiver]> }
+                quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<[<#resolved_ty as frand_property::Model>::Receiver]> }
              } else {
-                 quote! { #f_vis #f_name: <#resolved_ty as frand_property::Model>::Receiver }
+                 quote! { #(#f_attrs)* #f_vis #f_name: <#resolved_ty as frand_property::Model>::Receiver }
              }
         } else {
-            if is_array {
-                quote! { #f_vis #f_name: std::sync::Arc<[frand_property::Receiver<#resolved_ty>]> }
-            } else {
-                quote! { #f_vis #f_name: frand_property::Receiver<#resolved_ty> }
-            }
+            let ty = handle_ty(Handle::Receiver, &resolved_ty, is_array);
+            quote! { #(#f_attrs)* #f_vis #f_name: #ty }
         }
     }).collect()
 }
 
-fn generate_clone_sender_logic(input: &Model) -> Vec<TokenStream> {
-    input.fields.iter().map(|f| {
+fn generate_values_field_defs(input: &Model) -> Vec<TokenStream> {
+    input.fields.iter().filter(|f| is_readable(f)).map(|f| {
         let f_name = &f.name;
-        let f_ty = &f.ty;
-        
-        let is_array = matches!(f_ty, Type::Array(_) | Type::Slice(_));
+        let f_vis = &f.vis;
+        let f_docs = doc_attrs(&f.attrs);
+        let (is_array, elem_ty) = split_array_type(&f.ty);
+        let resolved_ty = resolve_type(elem_ty);
 
-        if f.is_model {
-            if is_array {
-                quote! {
-                    #f_name: self.#f_name.iter().map(|p| p.clone_sender()).collect::<std::vec::Vec<_>>().into()
-                }
-            } else {
-                quote! {
-                    #f_name: self.#f_name.clone_sender()
+        let value_ty = if f.is_model() {
+            quote! { <<#resolved_ty as frand_property::Model>::Receiver as frand_property::ReceiverGroup>::Item }
+        } else {
+            quote! { #resolved_ty }
+        };
+
+        if is_array {
+            quote! { #(#f_docs)* #f_vis #f_name: std::vec::Vec<#value_ty> }
+        } else {
+            quote! { #(#f_docs)* #f_vis #f_name: #value_ty }
+        }
+    }).collect()
+}
+
+fn generate_receiver_group_impl(input: &Model, receiver_name: &syn::Ident, values_name: &syn::Ident) -> TokenStream {
+    let value_fields: Vec<_> = input.fields.iter().filter(|f| is_readable(f)).map(|f| {
+        let f_name = &f.name;
+        let (is_array, _) = split_array_type(&f.ty);
+
+        if is_array {
+            quote! { #f_name: self.#f_name.iter().map(frand_property::ReceiverGroup::value).collect() }
+        } else {
+            quote! { #f_name: frand_property::ReceiverGroup::value(&self.#f_name) }
+        }
+    }).collect();
+
+    // Arc<[..]> 로 공유되는 배열 필드는 복제한 뒤 대기하고, 알림 상태가 갱신된 복제본으로 교체합니다.
+    let array_names: Vec<_> = input.fields.iter()
+        .filter(|f| is_readable(f))
+        .filter(|f| split_array_type(&f.ty).0)
+        .map(|f| &f.name)
+        .collect();
+
+    let notified_futures: Vec<_> = input.fields.iter().filter(|f| is_readable(f)).map(|f| {
+        let f_name = &f.name;
+        let (is_array, _) = split_array_type(&f.ty);
+
+        if is_array {
+            quote! {
+                for receiver in #f_name.iter_mut() {
+                    futures.push(std::boxed::Box::pin(async move {
+                        frand_property::ReceiverGroup::notified(receiver).await;
+                    }));
                 }
             }
         } else {
-            if is_array {
-                quote! {
-                    #f_name: self.#f_name.iter().map(|p| p.sender().clone()).collect::<std::vec::Vec<_>>().into()
+            quote! {
+                let receiver = &mut self.#f_name;
+                futures.push(std::boxed::Box::pin(async move {
+                    frand_property::ReceiverGroup::notified(receiver).await;
+                }));
+            }
+        }
+    }).collect();
+
+    quote! {
+        #[frand_property::async_trait]
+        impl frand_property::ReceiverGroup for #receiver_name {
+            type Item = #values_name;
+
+            fn value(&self) -> Self::Item {
+                #values_name {
+                    #(#value_fields),*
                 }
-            } else {
-                quote! {
-                    #f_name: self.#f_name.sender().clone()
+            }
+
+            async fn notified(&mut self) -> Self::Item {
+                #(let mut #array_names = self.#array_names.to_vec();)*
+                {
+                    let mut futures: std::vec::Vec<std::pin::Pin<std::boxed::Box<dyn std::future::Future<Output = ()> + Send + '_>>> = std::vec::Vec::new();
+                    #(#notified_futures)*
+                    frand_property::notified_any(futures).await;
                 }
+                #(self.#array_names = #array_names.into();)*
+
+                frand_property::ReceiverGroup::value(self)
             }
         }
-    }).collect()
+    }
 }
 
-fn generate_clone_receiver_logic(input: &Model) -> Vec<TokenStream> {
-    input.fields.iter().map(|f| {
+fn generate_sender_group_impl(input: &Model, sender_name: &syn::Ident, values_name: &syn::Ident) -> TokenStream {
+    // 읽고 쓸 수 있는 필드만 보냅니다. `{Model}Values` 에 없는 `in`/`callback` 필드는 보낼 값이 없고,
+    // `Sender` 에 없는 `out`/`computed` 필드의 값은 무시합니다.
+    let send_fields: Vec<_> = input.fields.iter().filter(|f| is_writable(f) && is_readable(f)).map(|f| {
         let f_name = &f.name;
-        let f_ty = &f.ty;
-        
-        let is_array = matches!(f_ty, Type::Array(_) | Type::Slice(_));
+        let (is_array, _) = split_array_type(&f.ty);
 
-        if f.is_model {
-            if is_array {
-                quote! {
-                    #f_name: self.#f_name.iter().map(|p| p.clone_receiver()).collect::<std::vec::Vec<_>>().into()
-                }
-            } else {
-                quote! {
-                    #f_name: self.#f_name.clone_receiver()
+        if is_array {
+            let message = format!("`{}` 의 길이가 Sender 배열의 길이와 다릅니다", f_name);
+            quote! {
+                assert_eq!(self.#f_name.len(), values.#f_name.len(), #message);
+                for (sender, value) in self.#f_name.iter().zip(values.#f_name) {
+                    frand_property::SenderGroup::send_all(sender, value);
                 }
             }
         } else {
-            if is_array {
-                quote! {
-                    #f_name: self.#f_name.iter().map(|p| p.receiver().clone()).collect::<std::vec::Vec<_>>().into()
-                }
-            } else {
-                quote! {
-                    #f_name: self.#f_name.receiver().clone()
-                }
+            quote! {
+                frand_property::SenderGroup::send_all(&self.#f_name, values.#f_name);
             }
         }
+    }).collect();
+
+    quote! {
+        impl frand_property::SenderGroup for #sender_name {
+            type Item = #values_name;
+
+            #[allow(unused_variables)]
+            fn send_all(&self, values: Self::Item) {
+                #(#send_fields)*
+            }
+        }
+
+        impl #sender_name {
+            /// 읽고 쓸 수 있는 필드(`inout` 과 중첩 모델)에만 씁니다. `in`/`callback` 필드는 각 `Sender` 로 직접 보내세요.
+            pub fn send_all(&self, values: #values_name) {
+                frand_property::SenderGroup::send_all(self, values)
+            }
+        }
+    }
+}
+
+fn generate_change_variants(input: &Model) -> Vec<TokenStream> {
+    input.fields.iter().map(|f| {
+        let (is_array, elem_ty) = split_array_type(&f.ty);
+        let resolved_ty = resolve_type(elem_ty);
+
+        let value_ty = if f.is_model() {
+            quote! { <#resolved_ty as frand_property::ModelChanges>::Change }
+        } else {
+            quote! { #resolved_ty }
+        };
+
+        generate_change_variant(&f.name, &doc_attrs(&f.attrs), &value_ty, is_array, !f.is_model() && is_unit_ty(elem_ty))
     }).collect()
 }
 
-fn generate_field_defs(input: &Model) -> Vec<TokenStream> {
+/// `fields` 의 변경 스트림을 모으는 코드를 생성합니다. 각 필드는 `handle_of` 가 반환하는 핸들로 접근합니다.
+fn generate_change_streams<'a>(
+    fields: impl Iterator<Item = &'a ModelField>,
+    change_name: &syn::Ident,
+    handle_of: fn(&ModelField) -> Option<Handle>,
+) -> Vec<TokenStream> {
+    fields.map(|f| {
+        let f_name = &f.name;
+        let (is_array, elem_ty) = split_array_type(&f.ty);
+
+        let source = match handle_of(f) {
+            None => quote! { frand_property::ModelChanges::changes(item) },
+            Some(Handle::Property) => quote! { item.receiver().changes() },
+            Some(Handle::Sender | Handle::Receiver) => quote! { item.changes() },
+        };
+
+        generate_change_stream(
+            change_name,
+            f_name,
+            quote! { self.#f_name },
+            source,
+            is_array,
+            !f.is_model() && is_unit_ty(elem_ty),
+        )
+    }).collect()
+}
+
+fn generate_clone_sender_logic(input: &Model) -> Vec<TokenStream> {
+    input.fields.iter().filter(|f| is_writable(f)).map(|f| {
+        let f_name = &f.name;
+        let (is_array, _) = split_array_type(&f.ty);
+
+        match public_handle(f) {
+            None if is_array => quote! {
+                #f_name: self.#f_name.iter().map(|p| p.clone_sender()).collect::<std::vec::Vec<_>>().into()
+            },
+            None => quote! {
+                #f_name: self.#f_name.clone_sender()
+            },
+            Some(handle) => {
+                let value = convert_handle(handle, Handle::Sender, quote! { self.#f_name }, is_array);
+                quote! { #f_name: #value }
+            }
+        }
+    }).collect()
+}
+
+fn generate_clone_receiver_logic(input: &Model) -> Vec<TokenStream> {
+    input.fields.iter().filter(|f| is_readable(f)).map(|f| {
+        let f_name = &f.name;
+        let (is_array, _) = split_array_type(&f.ty);
+
+        match public_handle(f) {
+            None if is_array => quote! {
+                #f_name: self.#f_name.iter().map(|p| p.clone_receiver()).collect::<std::vec::Vec<_>>().into()
+            },
+            None => quote! {
+                #f_name: self.#f_name.clone_receiver()
+            },
+            Some(handle) => {
+                let value = convert_handle(handle, Handle::Receiver, quote! { self.#f_name }, is_array);
+                quote! { #f_name: #value }
+            }
+        }
+    }).collect()
+}
+
+/// 모델 또는 Owner 구조체의 필드 정의를 생성합니다.
+fn generate_field_defs(input: &Model, handle_of: fn(&ModelField) -> Option<Handle>) -> Vec<TokenStream> {
     input.fields.iter().map(|f| {
         let f_name = &f.name;
         let f_ty = &f.ty;
         let f_vis = &f.vis;
-
-        let (is_array, elem_ty) = if let Type::Array(arr) = f_ty {
-            (true, arr.elem.as_ref())
-        } else if let Type::Slice(slice) = f_ty {
-            (true, slice.elem.as_ref())
-        } else {
-            (false, f_ty)
-        };
-        
+        let f_attrs = &f.attrs;
+        let (is_array, elem_ty) = split_array_type(f_ty);
         let resolved_ty = resolve_type(elem_ty);
 
-        if f.is_model {
-             if is_array {
-                if let Type::Array(_) = f_ty {
-                      proc_macro_error::abort!(f_name, "Model fields must use implicit length syntax `[]`. Explicit length `[N]` is not allowed for models.");
+        match handle_of(f) {
+            None => {
+                if is_array {
+                    if let Type::Array(_) = f_ty {
+                        proc_macro_error::abort!(f_name, "Model fields must use implicit length syntax `[]`. Explicit length `[N]` is not allowed for models.");
+                    }
+                    quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<[#resolved_ty]> }
+                } else {
+                    quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<#resolved_ty> }
                 }
-                quote! { #f_vis #f_name: std::sync::Arc<[#resolved_ty]> }
-             } else {
-                 quote! { #f_vis #f_name: std::sync::Arc<#resolved_ty> }
-             }
-        } else {
-            if is_array {
+            }
+            Some(handle) => {
                 if let Type::Slice(_) = f_ty {
-                     proc_macro_error::abort!(f_name, "Value fields must use explicit length syntax `[N]`. Implicit length `[]` is not allowed.");
+                    proc_macro_error::abort!(f_name, "Value fields must use explicit length syntax `[N]`. Implicit length `[]` is not allowed.");
                 }
-                quote! { #f_vis #f_name: std::sync::Arc<[frand_property::Property<#resolved_ty>]> }
-            } else {
-                quote! { #f_vis #f_name: frand_property::Property<#resolved_ty> }
+                let ty = handle_ty(handle, &resolved_ty, is_array);
+                quote! { #(#f_attrs)* #f_vis #f_name: #ty }
             }
         }
     }).collect()
 }
 
-fn generate_init_fields(input: &Model) -> Vec<TokenStream> {
+/// 생성 중의 지역 변수로부터 모델 또는 Owner 구조체의 필드를 초기화하는 코드를 생성합니다.
+fn generate_handle_inits(input: &Model, handle_of: fn(&ModelField) -> Option<Handle>) -> Vec<TokenStream> {
     input.fields.iter().map(|f| {
+        let f_name = &f.name;
+        let (is_array, _) = split_array_type(&f.ty);
+
+        match handle_of(f) {
+            None => quote! { #f_name: #f_name.clone() },
+            Some(handle) => {
+                let value = convert_handle(local_handle(f), handle, quote! { #f_name }, is_array);
+                quote! { #f_name: #value }
+            }
+        }
+    }).collect()
+}
+
+fn generate_init_fields(input: &Model) -> Vec<TokenStream> {
+    input.fields.iter().enumerate().map(|(index, f)| {
         let f_name = &f.name;
         let f_ty = &f.ty;
+
+        if f.is_computed() {
+            return generate_computed_init(input, index);
+        }
         
         let (is_array, elem_ty, array_len) = if let Type::Array(arr) = f_ty {
              (true, arr.elem.as_ref(), Some(&arr.len))
@@ -301,54 +628,120 @@ fn generate_init_fields(input: &Model) -> Vec<TokenStream> {
         
         let resolved_ty = resolve_type(elem_ty);
 
-        if f.is_model {
+        if f.is_model() {
+             if let Some(init) = &f.init {
+                 proc_macro_error::abort!(init, "`model` fields cannot have an initial value.");
+             }
+
              if is_array {
                 if let Some(len) = array_len {
                     quote! {
-                        #f_name: {
+                        let #f_name: std::sync::Arc<[#resolved_ty]> = {
                             let mut models = std::vec::Vec::with_capacity(#len);
                             let singleton = #resolved_ty::clone_singleton();
                             for _ in 0..#len {
                                 models.push((*singleton).clone());
                             }
                             models.into()
-                        }
+                        };
                     }
                 } else {
                     quote! {
-                        #f_name: #resolved_ty::clone_singleton()
+                        let #f_name = #resolved_ty::clone_singleton();
                     }
                 }
              } else {
                  quote! {
-                     #f_name: #resolved_ty::clone_singleton()
+                     let #f_name = #resolved_ty::clone_singleton();
                  }
              }
         } else {
             if is_array {
                 let len = array_len.unwrap();
+                let init_values = generate_init_values(f_name, len, elem_ty, f.init.as_ref());
                 quote! {
-                    #f_name: {
+                    let #f_name: std::sync::Arc<[frand_property::Property<#resolved_ty>]> = {
                         let mut props = std::vec::Vec::with_capacity(#len);
-                        for _ in 0..#len {
+                        for value in #init_values {
                             props.push(frand_property::Property::<#resolved_ty>::new(
                                 weak.clone(),
-                                Default::default(),
+                                value,
                                 |_, _| {}
                             ));
                         }
                         props.into()
-                    }
+                    };
                 }
             } else {
+                let init_value = generate_init_value(elem_ty, f.init.as_ref());
                 quote! {
-                    #f_name: frand_property::Property::<#resolved_ty>::new(
+                    let #f_name = frand_property::Property::<#resolved_ty>::new(
                         weak.clone(),
-                        Default::default(),
+                        #init_value,
                         |_, _| {}
-                    )
+                    );
                 }
             }
         }
     }).collect()
 }
+
+fn generate_computed_init(input: &Model, index: usize) -> TokenStream {
+    let f = &input.fields[index];
+    let f_name = &f.name;
+    let f_prop = format_ident!("{}_prop", f_name);
+    let resolved_ty = resolve_type(&f.ty);
+
+    let (closure, source_names) = parse_computed_closure(f_name, &f.ty, f.init.as_ref());
+
+    let sources: Vec<_> = source_names.into_iter().map(|name| {
+        // 소스 필드는 computed 필드보다 먼저 선언되어 있어야 합니다.
+        let Some(source) = input.fields.iter().take(index).find(|s| s.name == name) else {
+            proc_macro_error::abort!(name, "`{}` is not a field declared before computed field `{}`", name, f_name);
+        };
+        if source.is_model() {
+            proc_macro_error::abort!(name, "`model` field `{}` cannot be a source of a computed field", name);
+        }
+
+        let (is_array, elem_ty) = split_array_type(&source.ty);
+        let elem_ty = resolve_type(elem_ty);
+
+        if source.is_computed() {
+            let source_prop = format_ident!("{}_prop", name);
+            ComputedSource {
+                arg_ty: quote! { #elem_ty },
+                is_array,
+                capture: quote! { #name.clone() },
+                senders: quote! { std::iter::once(#source_prop.sender()) },
+                name,
+            }
+        } else if is_array {
+            ComputedSource {
+                arg_ty: quote! { std::vec::Vec<#elem_ty> },
+                is_array,
+                capture: quote! { #name.iter().map(|p| p.receiver().clone()).collect::<std::vec::Vec<_>>() },
+                senders: quote! { #name.iter().map(|p| p.sender()) },
+                name,
+            }
+        } else {
+            ComputedSource {
+                arg_ty: quote! { #elem_ty },
+                is_array,
+                capture: quote! { #name.receiver().clone() },
+                senders: quote! { std::iter::once(#name.sender()) },
+                name,
+            }
+        }
+    }).collect();
+
+    let f_compute = format_ident!("{}_compute", f_name);
+    let compute_fn = generate_computed_fn(f_name, closure, &sources, &resolved_ty);
+    let watch = generate_computed_watch(f_name, &sources);
+
+    quote! {
+        #compute_fn
+        let #f_prop = frand_property::Property::<#resolved_ty>::new(weak.clone(), #f_compute(), |_, _| {});
+        #watch
+        let #f_name = #f_prop.receiver().clone();
+    }
+}
@@ -1,24 +1,32 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::Type;
-use frand_property_build::parser::{Direction, SlintModel, SlintModelField};
-use crate::common::{resolve_type, is_special_string_type, is_unit_ty, generate_vec_init_tokens};
+use frand_property_build::parser::{doc_attrs, Direction, SlintModel, SlintModelField};
+use frand_property_build::types::option_inner;
+use crate::common::{resolve_type, is_unit_ty, to_slint_value, from_slint_value, IntPolicy, is_converted_slint_type, split_array_type, generate_init_value, generate_init_values, generate_change_variant, generate_change_stream, ComputedSource, parse_computed_closure, generate_computed_fn, generate_computed_watch};
 
 pub fn generate(input: &SlintModel, doc_comment: TokenStream) -> TokenStream {
     let vis = &input.vis;
     let model_name = &input.model_name;
+    let model_attrs = &input.attrs;
     let type_name = &input.type_name;
     let global_type_name = type_name;
-    let instances_ident = format_ident!("{}_INSTANCES", model_name.to_string().to_uppercase());
+    let change_name = format_ident!("{}Change", model_name);
+    let mock_name = format_ident!("{}Mock", model_name);
 
     let field_defs = generate_field_defs(input);
+    let mock_field_defs = generate_mock_field_defs(input);
+    let change_variants = generate_change_variants(input);
+    let change_streams = generate_change_streams(input, &change_name);
     
-    let (array_len_tokens, ret_ty, downcast_ty, return_stmt, init_method) = if let Some(len) = &input.len {
+    let (array_len_tokens, ret_ty, return_stmt, mock_ret_ty, mock_return_stmt, model_rows, init_method) = if let Some(len) = &input.len {
         (
             quote! { #len },
             quote! { std::sync::Arc<[Self]> },
-            quote! { std::sync::Arc<[Self]> },
             quote! { rust_models.into() },
+            quote! { std::sync::Arc<[#mock_name]> },
+            quote! { mock_models.into() },
+            quote! { &**models },
             quote! {
                 pub fn init_singleton(index: usize, init: impl FnOnce(&Self)) -> Self where C: frand_property::slint::SlintSingleton, for<'a> #global_type_name<'a>: slint::Global<'a, C> {
                     let models = Self::clone_singleton();
@@ -32,8 +40,10 @@ pub fn generate(input: &SlintModel, doc_comment: TokenStream) -> TokenStream {
         (
             quote! { 1 },
             quote! { std::sync::Arc<Self> },
-            quote! { std::sync::Arc<Self> },
             quote! { std::sync::Arc::new(rust_models.pop().expect("Should have created at least one model")) },
+            quote! { #mock_name },
+            quote! { mock_models.pop().expect("Should have created at least one model") },
+            quote! { std::slice::from_ref(&**models) },
             quote! {
                 pub fn init_singleton(init: impl FnOnce(&Self)) -> Self where C: frand_property::slint::SlintSingleton, for<'a> #global_type_name<'a>: slint::Global<'a, C> {
                     let model_arc = Self::clone_singleton();
@@ -46,7 +56,7 @@ pub fn generate(input: &SlintModel, doc_comment: TokenStream) -> TokenStream {
     };
     
     // 배열 로직 (길이 = LEN 혹은 1)
-    let body_logic_array = generate_logic_impl(array_len_tokens.clone(), global_type_name, input);
+    let (body_logic_array, install_logic) = generate_logic_impl(array_len_tokens.clone(), global_type_name, input);
 
     let field_names_for_clone: Vec<_> = input.fields.iter().map(|f| {
         let name = &f.name;
@@ -58,20 +68,32 @@ pub fn generate(input: &SlintModel, doc_comment: TokenStream) -> TokenStream {
         quote! { .field(stringify!(#name), &self.#name) }
     }).collect();
 
-    // 통합 싱글톤 패턴
-    quote! {
-        static #instances_ident: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<std::any::TypeId, Box<dyn std::any::Any + Send + Sync>>>> = std::sync::OnceLock::new();
+    let mock_field_names: Vec<_> = mock_field_names(input);
+    let mock_field_names_for_clone = mock_field_names.iter().map(|name| quote! { #name: self.#name.clone() });
+    let mock_field_names_for_debug = mock_field_names.iter().map(|name| quote! { .field(stringify!(#name), &self.#name) });
+    let mock_doc = format!(" UI 대신 [`{model_name}`] 을 다루는 모의 객체입니다. `{model_name}::mocked` 가 만듭니다.");
 
+    // 컴포넌트 인스턴스마다 모델을 만들고, 싱글톤은 `init_singleton` 으로 등록한 인스턴스에 바인딩합니다.
+    quote! {
+        #(#model_attrs)*
         #doc_comment
         #vis struct #model_name<C: slint::ComponentHandle> {
-            _handle: slint::Weak<C>,
+            _binding: frand_property::slint::SlintBinding<C>,
+            /// 이 행의 UI 쪽 (`in` 필드와 콜백의 `Sender` 등). 컴포넌트에 연결할 때 사용합니다.
+            _ui: #mock_name,
             #(#field_defs),*
         }
 
+        #[derive(Debug, Clone, PartialEq)]
+        #vis enum #change_name {
+            #(#change_variants),*
+        }
+
         impl<C: slint::ComponentHandle> Clone for #model_name<C> {
             fn clone(&self) -> Self {
                 Self {
-                    _handle: self._handle.clone(),
+                    _binding: self._binding.clone(),
+                    _ui: self._ui.clone(),
                     #(#field_names_for_clone),*
                 }
             }
@@ -85,36 +107,161 @@ pub fn generate(input: &SlintModel, doc_comment: TokenStream) -> TokenStream {
             }
         }
 
+        #[doc = #mock_doc]
+        ///
+        /// `in` 필드와 콜백은 UI 처럼 값을 보내거나 호출하고, `out`/`computed` 필드는 UI 에 보일 값을 읽습니다.
+        /// `action` 필드는 요청 큐와 함께 마지막 상태(`{name}_state`)를 가집니다.
+        #vis struct #mock_name {
+            #(#mock_field_defs),*
+        }
+
+        impl Clone for #mock_name {
+            fn clone(&self) -> Self {
+                Self {
+                    #(#mock_field_names_for_clone),*
+                }
+            }
+        }
+
+        impl std::fmt::Debug for #mock_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!(#mock_name))
+                    #(#mock_field_names_for_debug)*
+                    .finish()
+            }
+        }
+
         impl<C: slint::ComponentHandle + 'static> #model_name<C> {
             pub fn clone_singleton() -> #ret_ty where C: frand_property::slint::SlintSingleton, for<'a> #global_type_name<'a>: slint::Global<'a, C> {
-                    let map = #instances_ident.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()));
-                    let mut map = map.lock().unwrap();
-                    let type_id = std::any::TypeId::of::<C>();
+                // 이미 바인딩했으면 창을 꺼내지 않으므로 UI 스레드가 아닌 곳에서도 가져올 수 있습니다.
+                // 싱글톤 창이 해제된 뒤 다시 만들어졌으면 이전 모델을 새 창에 다시 연결합니다.
+                frand_property::slint::singleton_model::<C, _>(|component| Self::bind_to(component), |models, component| Self::attach(models, component))
+            }
 
-                    if let Some(any_val) = map.get(&type_id) {
-                        return any_val.downcast_ref::<#downcast_ty>().expect("Type mismatch in singleton store").clone();
-                    }
+            /// `component` 인스턴스의 Global 에 바인딩된 모델을 반환합니다. 처음 호출하면 모델을 만듭니다.
+            /// 같은 컴포넌트 타입의 창을 여러 개 열면 창마다 다른 모델을 가지며, 창이 해제되면 모델도 버려집니다.
+            pub fn bind_to(component: &C) -> #ret_ty where for<'a> #global_type_name<'a>: slint::Global<'a, C> {
+                frand_property::slint::bind_model(component, || {
+                    let (models, _) = Self::build(frand_property::slint::SlintHandle::new(component.as_weak()));
+                    Self::install(&models, component);
+                    models
+                })
+            }
+
+            /// 컴포넌트 없이 모델과, UI 대신 모델을 다루는 모의 객체를 만듭니다.
+            /// 창을 만들지 않고 `start()` 같은 로직을 시험할 때 사용합니다. 나중에 `attach` 로 컴포넌트에 연결할 수도 있습니다.
+            /// `model` 필드는 연결된 모델과 같이 `clone_singleton` 으로 가져옵니다.
+            /// Rust 필드만 만들므로 `C` 는 Global 을 사용하지 않는 컴포넌트여도 됩니다.
+            pub fn mocked() -> (#ret_ty, #mock_ret_ty) {
+                Self::build(frand_property::slint::SlintHandle::new(slint::Weak::default()))
+            }
 
-                    use slint::Model as _;
-                    let weak = C::clone_singleton();
-                    let component = weak.upgrade().expect("Failed to upgrade singleton instance");
+            fn build(handle: frand_property::slint::SlintHandle<C>) -> (#ret_ty, #mock_ret_ty) {
+                let binding = frand_property::slint::SlintBinding::new(handle.clone());
 
-                    let mut rust_models = {
-                        #body_logic_array
-                    };
+                let (mut rust_models, mut mock_models) = {
+                    #body_logic_array
+                };
+
+                (#return_stmt, #mock_return_stmt)
+            }
+
+            /// `models` 를 `component` 인스턴스에 다시 연결합니다. 창을 다시 만든 뒤 호출합니다.
+            /// Global 에 데이터 모델과 콜백을 다시 설치하고 현재 값으로 UI 를 채우며, `Sender`/`Receiver` 는 그대로 사용합니다.
+            /// 이후 `bind_to(component)` 는 `models` 를 반환합니다.
+            pub fn attach(models: &#ret_ty, component: &C) where for<'a> #global_type_name<'a>: slint::Global<'a, C> {
+                Self::install(models, component);
+                frand_property::slint::register_model(component, models.clone());
+            }
 
-                    let result: #ret_ty = #return_stmt;
-                    map.insert(type_id, Box::new(result.clone()));
-                    result
+            /// `models` 를 `component` 에 연결합니다. 처음 연결할 때 Global 에 데이터 모델을 설치하는 연결 함수를 만듭니다.
+            fn install(models: &#ret_ty, component: &C) where for<'a> #global_type_name<'a>: slint::Global<'a, C> {
+                let rows: &[Self] = #model_rows;
+                if let Some(model) = rows.first() {
+                    model._binding.attach(component, || {
+                        #install_logic
+                    });
+                }
             }
 
             #init_method
+
+            pub fn changes(&self) -> frand_property::ChangeStream<#change_name> {
+                frand_property::ModelChanges::changes(self)
+            }
+        }
+
+        // 필드마다 `Sender`/`Receiver` 그룹을 만들지 않으므로 모델 자신을 그룹으로 사용합니다.
+        impl<C: slint::ComponentHandle> frand_property::Model for #model_name<C> {
+            type Sender = Self;
+            type Receiver = Self;
+
+            fn clone_sender(&self) -> Self::Sender {
+                self.clone()
+            }
+
+            fn clone_receiver(&self) -> Self::Receiver {
+                self.clone()
+            }
+        }
+
+        impl<C: slint::ComponentHandle> frand_property::ModelChanges for #model_name<C> {
+            type Change = #change_name;
+
+            fn changes(&self) -> frand_property::ChangeStream<Self::Change> {
+                let mut streams: std::vec::Vec<frand_property::ChangeStream<#change_name>> = std::vec::Vec::new();
+                #(#change_streams)*
+                frand_property::merge_changes(streams)
+            }
         }
     }
 }
 
+fn has_change(f: &SlintModelField) -> bool {
+    f.ret.is_none() && f.direction != Direction::Action
+}
+
+fn generate_change_variants(input: &SlintModel) -> Vec<TokenStream> {
+    // 반환값이 있는 콜백과 `action` 은 값의 흐름이 아니라 요청-응답이므로 변경 이벤트가 없습니다.
+    input.fields.iter().filter(|f| has_change(f)).map(|f| {
+        let (is_array, elem_ty) = split_array_type(&f.ty);
+        let resolved_ty = resolve_type(elem_ty);
+
+        let value_ty = if f.direction == Direction::Model {
+            quote! { <#resolved_ty as frand_property::ModelChanges>::Change }
+        } else {
+            quote! { #resolved_ty }
+        };
+
+        generate_change_variant(&f.name, &doc_attrs(&f.attrs), &value_ty, is_array, f.direction != Direction::Model && is_unit_ty(elem_ty))
+    }).collect()
+}
+
+fn generate_change_streams(input: &SlintModel, change_name: &syn::Ident) -> Vec<TokenStream> {
+    input.fields.iter().filter(|f| has_change(f)).map(|f| {
+        let f_name = &f.name;
+        let (is_array, elem_ty) = split_array_type(&f.ty);
+
+        let source = if f.direction == Direction::Model {
+            quote! { frand_property::ModelChanges::changes(item) }
+        } else {
+            quote! { item.changes() }
+        };
+
+        generate_change_stream(
+            change_name,
+            f_name,
+            quote! { self.#f_name },
+            source,
+            is_array,
+            f.direction != Direction::Model && is_unit_ty(elem_ty),
+        )
+    }).collect()
+}
+
 fn generate_field_defs(input: &SlintModel) -> Vec<TokenStream> {
     input.fields.iter().map(|f| {
+        let f_attrs = &f.attrs;
         let f_vis = &f.vis;
         let f_name = &f.name;
         let f_ty = &f.ty;
@@ -129,40 +276,100 @@ fn generate_field_defs(input: &SlintModel) -> Vec<TokenStream> {
             (false, f_ty)
         };
 
-        if *direction == Direction::Model {
+        if *direction == Direction::Action {
+             // 비동기 요청: Rust 가 요청을 받아 처리하는 큐로 노출
+             let resolved_ty = resolve_type(f_ty);
+             let resolved_ret = action_ret_type(f);
+             quote! { #(#f_attrs)* #f_vis #f_name: frand_property::slint::SlintAction<#resolved_ty, #resolved_ret> }
+        } else if let Some(ret) = &f.ret {
+             // 반환값이 있는 콜백: Rust 핸들러를 등록하는 슬롯으로 노출
+             let resolved_ty = resolve_type(f_ty);
+             let resolved_ret = resolve_type(ret);
+             quote! { #(#f_attrs)* #f_vis #f_name: frand_property::slint::SlintCallback<#resolved_ty, #resolved_ret> }
+        } else if *direction == Direction::Computed {
+             // 계산 필드: 읽기 전용 Receiver 로 노출
+             let resolved_ty = resolve_type(f_ty);
+             quote! { #(#f_attrs)* #f_vis #f_name: frand_property::Receiver<#resolved_ty> }
+        } else if *direction == Direction::Model {
              // 중첩 모델: 단순 Rust 타입으로 포함
              let resolved_ty = resolve_type(elem_ty);
              if is_array {
-                 quote! { #f_vis #f_name: std::sync::Arc<[#resolved_ty]> }
+                 quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<[#resolved_ty]> }
              } else {
-                 quote! { #f_vis #f_name: std::sync::Arc<#resolved_ty> }
+                 quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<#resolved_ty> }
              }
         } else if is_array {
             let resolved_elem_ty = resolve_type(elem_ty);
             if *direction == Direction::Out {
-                quote! { #f_vis #f_name: std::sync::Arc<[frand_property::Sender<#resolved_elem_ty, slint::Weak<C>>]> }
+                quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<[frand_property::Sender<#resolved_elem_ty, frand_property::slint::SlintHandle<C>>]> }
             } else {
-                 quote! { #f_vis #f_name: std::sync::Arc<[frand_property::Receiver<#resolved_elem_ty>]> }
+                 quote! { #(#f_attrs)* #f_vis #f_name: std::sync::Arc<[frand_property::Receiver<#resolved_elem_ty>]> }
             }
         } else {
             if is_unit {
                 if *direction == Direction::Out {
-                     quote! { #f_vis #f_name: frand_property::Sender<(), slint::Weak<C>> }
+                     quote! { #(#f_attrs)* #f_vis #f_name: frand_property::Sender<(), frand_property::slint::SlintHandle<C>> }
                 } else {
-                     quote! { #f_vis #f_name: frand_property::Receiver<()> }
+                     quote! { #(#f_attrs)* #f_vis #f_name: frand_property::Receiver<()> }
                 }
             } else {
                 let resolved_ty = resolve_type(f_ty);
                 if *direction == Direction::Out {
-                    quote! { #f_vis #f_name: frand_property::Sender<#resolved_ty, slint::Weak<C>> }
+                    quote! { #(#f_attrs)* #f_vis #f_name: frand_property::Sender<#resolved_ty, frand_property::slint::SlintHandle<C>> }
                 } else {
-                    quote! { #f_vis #f_name: frand_property::Receiver<#resolved_ty> }
+                    quote! { #(#f_attrs)* #f_vis #f_name: frand_property::Receiver<#resolved_ty> }
                 }
             }
         }
     }).collect()
 }
 
+/// `{Model}Mock` 의 필드. `model` 필드는 없고, `action` 필드는 상태 필드(`{name}_state`)가 함께 있습니다.
+fn generate_mock_field_defs(input: &SlintModel) -> Vec<TokenStream> {
+    input.fields.iter().filter(|f| f.direction != Direction::Model).flat_map(|f| {
+        let f_attrs = &f.attrs;
+        let f_vis = &f.vis;
+        let f_name = &f.name;
+        let (is_array, elem_ty) = split_array_type(&f.ty);
+        let resolved_elem_ty = resolve_type(elem_ty);
+
+        let ty = match f.direction {
+            Direction::Action => {
+                let resolved_ty = resolve_type(&f.ty);
+                let resolved_ret = action_ret_type(f);
+                let f_state = format_ident!("{}_state", f_name);
+                return vec![
+                    quote! { #(#f_attrs)* #f_vis #f_name: frand_property::slint::SlintAction<#resolved_ty, #resolved_ret> },
+                    quote! { #f_vis #f_state: frand_property::Receiver<Option<frand_property::slint::ActionState<#resolved_ret>>> },
+                ];
+            }
+            Direction::Callback => match &f.ret {
+                Some(ret) => {
+                    let resolved_ret = resolve_type(ret);
+                    quote! { frand_property::slint::SlintCallback<#resolved_elem_ty, #resolved_ret> }
+                }
+                None => quote! { frand_property::Sender<#resolved_elem_ty> },
+            },
+            // UI 가 보내는 값
+            Direction::In if is_array => quote! { std::sync::Arc<[frand_property::Sender<#resolved_elem_ty>]> },
+            Direction::In => quote! { frand_property::Sender<#resolved_elem_ty> },
+            // UI 에 보일 값
+            _ if is_array => quote! { std::sync::Arc<[frand_property::Receiver<#resolved_elem_ty>]> },
+            _ => quote! { frand_property::Receiver<#resolved_elem_ty> },
+        };
+        vec![quote! { #(#f_attrs)* #f_vis #f_name: #ty }]
+    }).collect()
+}
+
+fn mock_field_names(input: &SlintModel) -> Vec<syn::Ident> {
+    input.fields.iter().filter(|f| f.direction != Direction::Model).flat_map(|f| {
+        match f.direction {
+            Direction::Action => vec![f.name.clone(), format_ident!("{}_state", f.name)],
+            _ => vec![f.name.clone()],
+        }
+    }).collect()
+}
+
 fn generate_struct_init_fields(input: &SlintModel) -> Vec<TokenStream> {
     input.fields.iter().map(|f| {
         let f_name = &f.name;
@@ -170,221 +377,382 @@ fn generate_struct_init_fields(input: &SlintModel) -> Vec<TokenStream> {
     }).collect()
 }
 
+/// 필드 하나의 생성 코드입니다.
+/// 모델을 만들 때 한 번 실행하는 코드(`setup`, `row`, `mock`)와, 컴포넌트에 처음 연결할 때 연결 함수를 만드는 코드(`install_setup`, `apply`),
+/// 연결할 때마다 실행하는 코드(`install`, `install_row`)로 나뉩니다. 모델을 만드는 코드는 Slint Global 과 데이터 구조체를 사용하지 않습니다.
+#[derive(Default)]
+struct FieldCode {
+    /// 행 루프 앞에서 실행하는 코드
+    setup: TokenStream,
+    /// 행마다 Rust 필드 값을 만드는 코드
+    row: TokenStream,
+    /// 행마다 `{Model}Mock` 의 필드를 초기화하는 코드 (`name: value,`)
+    mock: TokenStream,
+    /// 연결 함수를 만들 때 모델의 행들(`rows`)에서 연결에 쓸 값을 모으는 코드
+    install_setup: TokenStream,
+    /// `SlintRowWriter` 로 모은 이 필드의 값을 데이터 행(`data`)에 쓰는 `match` 갈래
+    apply: TokenStream,
+    /// 연결할 때 실행하는 코드 (Slint 콜백 등록)
+    install: TokenStream,
+    /// 연결할 때 행마다 Slint 데이터 행(`slint_row_data`)에 현재 값을 쓰는 코드
+    install_row: TokenStream,
+}
+
+/// 모델의 행들(`rows`)에서 필드 값을 행 순서대로 모으는 코드입니다. `ui` 이면 `{Model}Mock` 쪽 필드를 모읍니다.
+fn collect_rows(name: &syn::Ident, f_name: &syn::Ident, ui: bool) -> TokenStream {
+    if ui {
+        quote! { let #name: Vec<_> = rows.iter().map(|model| model._ui.#f_name.clone()).collect(); }
+    } else {
+        quote! { let #name: Vec<_> = rows.iter().map(|model| model.#f_name.clone()).collect(); }
+    }
+}
+
+/// 모델을 만드는 코드와, 컴포넌트에 처음 연결할 때 연결 함수를 만드는 코드를 생성합니다.
 fn generate_logic_impl(
     array_len_tokens: TokenStream,
     global_type_name: &syn::Ident,
     input: &SlintModel
-) -> TokenStream {
+) -> (TokenStream, TokenStream) {
     let fields: Vec<_> = input.fields.iter().collect();
-    let struct_data_type = format_ident!("{}Data", global_type_name);
-    let struct_data_type_path = quote! { crate::#struct_data_type };
     let (data_fields, signal_fields): (Vec<_>, Vec<_>) = fields.into_iter()
-        .partition(|f| !is_unit_ty(&f.ty) && f.direction != Direction::Callback);
+        .partition(|f| !is_unit_ty(&f.ty) && !matches!(f.direction, Direction::Callback | Direction::Action));
 
     let struct_init_ids = generate_struct_init_fields(input);
-    let mut loop_body = Vec::new();
-
-    let mut slint_data_assignments = Vec::new();
-    let mut rust_struct_fields_init = Vec::new();
-
-    let mut signal_init_block = Vec::new();
+    let field_index = |f: &SlintModelField| input.fields.iter().position(|field| field.name == f.name).expect("field must be in the model");
 
+    let mut codes = Vec::new();
     for f in &signal_fields {
-        let (init, body, struct_id) = process_signal_field(f, &array_len_tokens, global_type_name);
-        signal_init_block.push(init);
-        loop_body.push(body);
-        rust_struct_fields_init.push(struct_id);
+        codes.push(process_signal_field(f, field_index(f), &array_len_tokens, global_type_name));
     }
-
     for f in &data_fields {
-        let (body, struct_id, assign) = process_data_field(f, global_type_name);
-        loop_body.push(body);
-        rust_struct_fields_init.push(struct_id);
-        slint_data_assignments.push(assign);
+        codes.push(if f.direction == Direction::Computed {
+            process_computed_field(f, field_index(f), input)
+        } else {
+            process_data_field(f, field_index(f))
+        });
     }
+    let setups = codes.iter().map(|code| &code.setup);
+    let rows = codes.iter().map(|code| &code.row);
+    let mocks = codes.iter().map(|code| &code.mock);
+    let install_setups = codes.iter().map(|code| &code.install_setup);
+    let applies: Vec<_> = codes.iter().map(|code| &code.apply).filter(|code| !code.is_empty()).collect();
+    let installs = codes.iter().map(|code| &code.install);
+    let install_rows: Vec<_> = codes.iter().map(|code| &code.install_row).filter(|code| !code.is_empty()).collect();
+    let mock_name = format_ident!("{}Mock", input.model_name);
 
     // 스칼라 IN 속성 처리 (Outer Model 변경 감지)
-    let mut scalar_in_senders_collect = Vec::new();
     let mut scalar_diff_checks = Vec::new();
-    
+    let mut scalar_vectors_clone = Vec::new();
+
     for f in &data_fields {
         if let Type::Array(_) = f.ty { continue; }
         if f.direction == Direction::In {
-            let f_name = &f.name;
-            let f_prop = format_ident!("{}_prop", f_name);
-            let vec_name = format_ident!("{}_senders", f_name);
-            scalar_in_senders_collect.push(quote! {
-                #vec_name.push(#f_prop.sender().clone());
-            });
-            
+            let data_name = f.slint_ident();
+            let vec_name = format_ident!("{}_senders", f.name);
+            scalar_vectors_clone.push(quote! { let #vec_name = #vec_name.clone(); });
+
             if crate::common::is_array_string_type(&f.ty) {
                 let resolved_ty = resolve_type(&f.ty);
                 scalar_diff_checks.push(quote! {
-                    if new_data.#f_name != old_data.#f_name {
+                    if new_data.#data_name != old_data.#data_name {
                         if let Some(sender) = #vec_name.get(idx) {
-                             if let Ok(val) = <#resolved_ty>::try_from_str(new_data.#f_name.as_str()) {
+                             if let Ok(val) = <#resolved_ty>::try_from_str(new_data.#data_name.as_str()) {
                                  sender.send(val);
                              }
                         }
                     }
                 });
-            } else if crate::common::is_std_string_type(&f.ty) {
-                 scalar_diff_checks.push(quote! {
-                    if new_data.#f_name != old_data.#f_name {
-                        if let Some(sender) = #vec_name.get(idx) {
-                             sender.send(new_data.#f_name.to_string());
-                        }
-                    }
-                });
             } else {
+                let changed = slint_field_changed(f, quote! { new_data }, quote! { old_data });
+                let value = read_slint_field(f, quote! { new_data });
                 scalar_diff_checks.push(quote! {
-                    if new_data.#f_name != old_data.#f_name {
+                    if #changed {
                         if let Some(sender) = #vec_name.get(idx) {
-                            sender.send(new_data.#f_name.clone());
+                            if let Some(value) = #value {
+                                sender.send(value);
+                            }
                         }
                     }
                 });
             }
         }
     }
-    
-    let mut scalar_vectors_init = Vec::new();
-    let mut scalar_vectors_clone = Vec::new();
-    for f in &data_fields {
-         if let Type::Array(_) = f.ty { continue; }
-         if f.direction == Direction::In {
-             let f_name = &f.name;
-             let vec_name = format_ident!("{}_senders", f_name);
-             scalar_vectors_init.push(quote! { let mut #vec_name = Vec::with_capacity(#array_len_tokens); });
-             scalar_vectors_clone.push(quote! { let #vec_name = #vec_name.clone(); });
-         }
-    }
 
-    quote! {
+    // 데이터 구조체(`{Global}Data`)는 이름 대신 Global 의 `data` 타입으로 정하므로, 어느 모듈에 선언되어도 됩니다.
+    let slint_data = if install_rows.is_empty() {
+        quote! { vec![default_row(&component.global::<#global_type_name>().get_data()); #array_len_tokens] }
+    } else {
+        quote! {
+            {
+                let template_data = default_row(&component.global::<#global_type_name>().get_data());
+                let mut slint_data = Vec::with_capacity(#array_len_tokens);
+                for i in 0..#array_len_tokens {
+                    let mut slint_row_data = template_data.clone();
+                    #(#install_rows)*
+                    slint_data.push(slint_row_data);
+                }
+                slint_data
+            }
+        }
+    };
+
+    let build = quote! {
         let mut rust_models = Vec::with_capacity(#array_len_tokens);
-        let mut slint_data = Vec::with_capacity(#array_len_tokens);
-        #(#scalar_vectors_init)*
-        
-        // 시그널 필드 설정 (배열)
-        #(#signal_init_block)*
+        let mut mock_models = Vec::with_capacity(#array_len_tokens);
 
-        let template_data = <#struct_data_type_path as Default>::default();
+        // Rust 에서 보낸 값은 행마다 모았다가 이벤트 루프에서 한 번에 씁니다.
+        #[allow(unused_variables)]
+        let writer = binding.writer().clone();
+
+        // 시그널 필드와 행 목록 설정 (배열)
+        #(#setups)*
 
         for i in 0..#array_len_tokens {
-            #(#loop_body)*
-            
-            #(#scalar_in_senders_collect)*
+            #(#rows)*
+
+            let ui = #mock_name {
+                #(#mocks)*
+            };
+            mock_models.push(ui.clone());
 
             rust_models.push(Self {
-                _handle: weak.clone(),
+                _binding: binding.clone(),
+                _ui: ui,
                 #(#struct_init_ids),*
             });
+        }
 
-            #[allow(unused_mut)]
-            let mut slint_row_data = template_data.clone();
-            #(#slint_data_assignments)*
-            slint_data.push(slint_row_data);
+        (rust_models, mock_models)
+    };
+
+    // 처음 연결할 때 한 번 실행합니다. 데이터 행의 타입을 정하고, 연결할 때마다 실행할 연결 함수를 반환합니다.
+    let apply = if applies.is_empty() {
+        quote! { |_, _, _| false }
+    } else {
+        quote! {
+            |data, field, value| {
+                use slint::Model as _;
+                match field {
+                    #(#applies)*
+                    _ => false,
+                }
+            }
         }
+    };
 
-        let inner_model = std::rc::Rc::new(slint::VecModel::from(slint_data.clone()));
-        
-        let old_data_vec = std::rc::Rc::new(std::cell::RefCell::new(slint_data));
-        let old_data_vec_clone = old_data_vec.clone();
-        
-        #(#scalar_vectors_clone)*
-        
-        let notify_model = frand_property::slint::SlintNotifyModel::new(inner_model, move |idx, new_data| {
-             let mut old_data_guard = old_data_vec_clone.borrow_mut();
-             if idx < old_data_guard.len() {
-                 let old_data = &mut old_data_guard[idx];
-                 #(#scalar_diff_checks)*
-                 *old_data = new_data;
-             }
-        });
+    let install = quote! {
+        fn default_row<D: Default>(_: &slint::ModelRc<D>) -> D {
+            D::default()
+        }
+
+        model._binding.writer().set_target(|c: &C| c.global::<#global_type_name>().get_data(), #apply);
+
+        #(#install_setups)*
+
+        // 연결할 때마다 모델의 현재 값으로 Slint 데이터를 만들고 콜백을 등록합니다.
+        move |component: &C| {
+            use slint::Model as _;
+
+            let slint_data = #slint_data;
+            let inner_model = std::rc::Rc::new(slint::VecModel::from(slint_data.clone()));
+            let old_data_vec = std::cell::RefCell::new(slint_data);
+
+            #(#scalar_vectors_clone)*
 
-        component.global::<#global_type_name>().set_data(
-             slint::ModelRc::new(std::rc::Rc::new(notify_model))
-        );
+            let notify_model = frand_property::slint::SlintNotifyModel::new(inner_model, move |idx, new_data| {
+                 let mut old_data_guard = old_data_vec.borrow_mut();
+                 if idx < old_data_guard.len() {
+                     let old_data = &mut old_data_guard[idx];
+                     #(#scalar_diff_checks)*
+                     *old_data = new_data;
+                 }
+            });
+
+            component.global::<#global_type_name>().set_data(
+                 slint::ModelRc::new(std::rc::Rc::new(notify_model))
+            );
 
-        rust_models
+            #(#installs)*
+        }
+    };
+
+    (build, install)
+}
+
+/// `SlintRowWriter` 로 모은 `field` 번 필드의 값(`#ty`)을 꺼내 `body` 로 쓰는 `match` 갈래입니다.
+fn apply_arm(field: usize, ty: TokenStream, pattern: TokenStream, body: TokenStream) -> TokenStream {
+    quote! {
+        #field => {
+            let #pattern: #ty = *value.downcast().expect("Type mismatch in SlintRowWriter");
+            #body
+        }
     }
 }
 
-fn generate_out_property(global_type_name: &syn::Ident, setter_block: TokenStream, resolved_ty: TokenStream) -> TokenStream {
+/// `out` 속성을 만듭니다. 보낸 값은 `writer` 로 모았다가 이벤트 루프에서 `i` 행의 `field` 번 필드에 한 번에 씁니다.
+fn generate_out_property(field: usize, resolved_ty: TokenStream, init_value: TokenStream) -> TokenStream {
     quote! {
-        frand_property::Property::<#resolved_ty, slint::Weak<C>>::new(
-             weak.clone(),
-             <#resolved_ty as Default>::default(),
-             move |c, v| {
-                 c.upgrade_in_event_loop(move |c| {
-                     let global = c.global::<#global_type_name>();
-                     let model = global.get_data();
-                     #setter_block
-                 }).unwrap();
+        frand_property::Property::<#resolved_ty, frand_property::slint::SlintHandle<C>>::new(
+             handle.clone(),
+             #init_value,
+             {
+                 let writer = writer.clone();
+                 move |_, v| writer.write(i, #field, v)
              }
          )
     }
 }
 
+/// Slint 데이터 행(`data`)의 필드에 Rust 값을 씁니다. `Option<T>` 는 값과 `has-*` 플래그를 함께 씁니다.
+fn assign_slint_field(f: &SlintModelField, data: TokenStream, value: TokenStream) -> TokenStream {
+    let data_name = f.slint_ident();
+    let policy = IntPolicy::new(&f.options, &f.name);
+    match option_inner(&f.ty) {
+        Some(inner) => {
+            let has_name = format_ident!("has_{}", data_name);
+            let converted = to_slint_value(inner, quote! { value }, &policy);
+            quote! {
+                match #value {
+                    Some(value) => {
+                        if let Some(value) = #converted {
+                            #data.#has_name = true;
+                            #data.#data_name = value;
+                        }
+                    }
+                    None => {
+                        #data.#has_name = false;
+                        #data.#data_name = Default::default();
+                    }
+                }
+            }
+        }
+        None => {
+            let converted = to_slint_value(&f.ty, value, &policy);
+            quote! {
+                if let Some(value) = #converted {
+                    #data.#data_name = value;
+                }
+            }
+        }
+    }
+}
+
+/// Slint 데이터 행(`data`)의 필드를 Rust 값으로 읽습니다. 값이 범위를 벗어나 버려지면 `None` 입니다.
+fn read_slint_field(f: &SlintModelField, data: TokenStream) -> TokenStream {
+    let data_name = f.slint_ident();
+    let policy = IntPolicy::new(&f.options, &f.name);
+    match option_inner(&f.ty) {
+        Some(inner) => {
+            let has_name = format_ident!("has_{}", data_name);
+            let converted = from_slint_value(inner, quote! { #data.#data_name.clone() }, &policy);
+            quote! { if #data.#has_name { #converted.map(Some) } else { Some(None) } }
+        }
+        None => from_slint_value(&f.ty, quote! { #data.#data_name.clone() }, &policy),
+    }
+}
+
+/// 두 Slint 데이터 행에서 필드 값이 달라졌는지 비교하는 식을 생성합니다.
+fn slint_field_changed(f: &SlintModelField, new_data: TokenStream, old_data: TokenStream) -> TokenStream {
+    let data_name = f.slint_ident();
+    if option_inner(&f.ty).is_some() {
+        let has_name = format_ident!("has_{}", data_name);
+        quote! { #new_data.#data_name != #old_data.#data_name || #new_data.#has_name != #old_data.#has_name }
+    } else {
+        quote! { #new_data.#data_name != #old_data.#data_name }
+    }
+}
+
 fn generate_in_array_setup(
-    f_name: &syn::Ident,
+    f: &SlintModelField,
     len: &syn::Expr,
-    resolved_elem_ty: &TokenStream
-) -> (TokenStream, TokenStream) {
+    elem_ty: &Type,
+) -> FieldCode {
+    let f_name = &f.name;
+    let data_name = f.slint_ident();
     let f_senders = format_ident!("{}_senders", f_name);
     let f_receivers = format_ident!("{}_receivers", f_name);
+    let f_values = format_ident!("{}_values", f_name);
+    let f_rows = format_ident!("{}_rows", f_name);
+    let f_sender_rows = format_ident!("{}_sender_rows", f_name);
+    let resolved_elem_ty = resolve_type(elem_ty);
 
-    let vec_init = generate_vec_init_tokens(len, resolved_elem_ty);
+    if is_converted_slint_type(elem_ty) {
+        proc_macro_error::abort!(elem_ty, "`in` array fields must use types that Slint shares as-is. `Duration`, `PixelImage`, `Length`, `PhysicalLength`, `Option`, `f64` and integers other than `i32` are only supported on scalar fields and `out` arrays.");
+    }
 
-    let setup = quote! {
-        let mut #f_senders: Vec<frand_property::Sender<#resolved_elem_ty, slint::Weak<C>>> = Vec::with_capacity(#len);
+    let init_values = generate_init_values(f_name, len, elem_ty, f.init.as_ref());
+
+    let row = quote! {
+        let #f_values = #init_values;
+        let mut #f_senders: Vec<frand_property::Sender<#resolved_elem_ty>> = Vec::with_capacity(#len);
         let mut #f_receivers: Vec<frand_property::Receiver<#resolved_elem_ty>> = Vec::with_capacity(#len);
 
-        for _ in 0..#len {
-            let prop = frand_property::Property::<#resolved_elem_ty, slint::Weak<C>>::new(weak.clone(), <#resolved_elem_ty as Default>::default(), |_, _| {});
+        for value in #f_values.iter().cloned() {
+            let prop = frand_property::Property::<#resolved_elem_ty>::new((), value, |_, _| {});
             #f_senders.push(prop.sender().clone());
             #f_receivers.push(prop.receiver().clone());
         }
         let #f_name: std::sync::Arc<[frand_property::Receiver<#resolved_elem_ty>]> = #f_receivers.into();
+    };
+
+    let mock = quote! { #f_name: #f_senders.into(), };
+
+    let install_setup = [collect_rows(&f_rows, f_name, false), collect_rows(&f_sender_rows, f_name, true)]
+        .into_iter().collect();
 
+    let install_row = quote! {
         let inner_vec_model: std::rc::Rc<slint::VecModel<#resolved_elem_ty>> = std::rc::Rc::new(slint::VecModel::from(
-             #vec_init
+             #f_rows[i].iter().map(|receiver| receiver.value()).collect::<Vec<_>>()
         ));
-        let senders_clone = #f_senders.clone();
+        let senders_clone = #f_sender_rows[i].clone();
         
         let notify_model = frand_property::slint::SlintNotifyModel::new(inner_vec_model, move |idx, val| {
             if let Some(sender) = senders_clone.get(idx) {
                  sender.send(val);
             }
         });
+        slint_row_data.#data_name = slint::ModelRc::<#resolved_elem_ty>::new(std::rc::Rc::new(notify_model));
     };
 
-    let init = quote! {
-        slint_row_data.#f_name = slint::ModelRc::<#resolved_elem_ty>::new(std::rc::Rc::new(notify_model));
-    };
-
-    (setup, init)
+    FieldCode { row, mock, install_setup, install_row, ..Default::default() }
 }
 
 fn process_signal_field(
     f: &SlintModelField,
+    field: usize,
     array_len_tokens: &TokenStream,
     global_type_name: &syn::Ident,
-) -> (TokenStream, TokenStream, TokenStream) {
+) -> FieldCode {
     let f_name = &f.name;
 
+    if f.direction == Direction::Action {
+        return process_action_field(f, field, array_len_tokens, global_type_name);
+    }
+
     if f.direction == Direction::Out {
          proc_macro_error::abort!(f_name, "`()` type cannot be used with `out` direction");
     }
 
+    if f.direction == Direction::Computed {
+         proc_macro_error::abort!(f_name, "`()` type cannot be used with `computed` fields");
+    }
+
     let f_senders = format_ident!("{}_senders", f_name);
     let f_receivers = format_ident!("{}_receivers", f_name);
-    let on_ident = format_ident!("on_{}", f_name);
+    let on_ident = format_ident!("on_{}", f.slint_ident());
 
     if f.direction == Direction::In {
         proc_macro_error::abort!(f_name, "`()` (unit type) cannot be used with `in` direction. Use `callback` instead.");
     }
 
+    if let Some(init) = &f.init {
+        proc_macro_error::abort!(init, "`callback` fields cannot have an initial value.");
+    }
+
+    if let Some(ret) = &f.ret {
+        return process_return_callback_field(f, ret, array_len_tokens, global_type_name);
+    }
+
     let resolved_ty = resolve_type(&f.ty);
     let is_unit = is_unit_ty(&f.ty);
 
@@ -397,44 +765,235 @@ fn process_signal_field(
             });
         }
     } else {
+        let value = from_slint_value(&f.ty, quote! { val }, &IntPolicy::new(&f.options, f_name));
         quote! {
             component.global::<#global_type_name>().#on_ident(move |idx, val| {
                 if let Some(s) = senders_clone.get(idx as usize) {
-                    s.notify_with(val.into());
+                    if let Some(value) = #value {
+                        s.notify_with(value);
+                    }
                 }
             });
         }
     };
 
-    let signal_init = quote! {
-        let mut #f_senders: Vec<frand_property::Sender<#resolved_ty, slint::Weak<C>>> = Vec::with_capacity(#array_len_tokens);
+    let setup = quote! {
+        let mut #f_senders: Vec<frand_property::Sender<#resolved_ty>> = Vec::with_capacity(#array_len_tokens);
         let mut #f_receivers: Vec<frand_property::Receiver<#resolved_ty>> = Vec::with_capacity(#array_len_tokens);
         for _ in 0..#array_len_tokens {
-             let prop = frand_property::Property::<#resolved_ty, slint::Weak<C>>::new(weak.clone(), <#resolved_ty as Default>::default(), |_,_| {});
+             let prop = frand_property::Property::<#resolved_ty>::new((), <#resolved_ty as Default>::default(), |_,_| {});
              #f_senders.push(prop.sender().clone());
              #f_receivers.push(prop.receiver().clone());
         }
+    };
 
+    let row = quote! {
+        let #f_name = #f_receivers[i].clone();
+    };
+
+    let install = quote! {
         let senders_clone = #f_senders.clone();
         #callback_registration
     };
 
-    let loop_body = quote! {
-        let #f_name = #f_receivers[i].clone();
+    let mock = quote! { #f_name: #f_senders[i].clone(), };
+
+    let install_setup = collect_rows(&f_senders, f_name, true);
+
+    FieldCode { setup, row, mock, install_setup, install, ..Default::default() }
+}
+
+/// 반환값이 있는 콜백: 행마다 `SlintCallback` 을 만들고, Slint 의 호출을 해당 행의 핸들러로 전달합니다.
+fn process_return_callback_field(
+    f: &SlintModelField,
+    ret: &Type,
+    array_len_tokens: &TokenStream,
+    global_type_name: &syn::Ident,
+) -> FieldCode {
+    let f_name = &f.name;
+    let f_callbacks = format_ident!("{}_callbacks", f_name);
+    let on_ident = format_ident!("on_{}", f.slint_ident());
+    let resolved_ty = resolve_type(&f.ty);
+    let resolved_ret = resolve_type(ret);
+
+    if is_unit_ty(ret) {
+        proc_macro_error::abort!(ret, "the return type of a `callback` field cannot be `()`. Omit `-> ()` instead.");
+    }
+
+    let policy = IntPolicy::new(&f.options, f_name);
+    let (params, args) = callback_args(f, &policy);
+    let result = to_slint_value(ret, quote! { result }, &policy);
+
+    let setup = quote! {
+        let #f_callbacks: Vec<frand_property::slint::SlintCallback<#resolved_ty, #resolved_ret>> =
+            (0..#array_len_tokens).map(|_| frand_property::slint::SlintCallback::new()).collect();
     };
 
-    let struct_init = quote! { #f_name };
+    let install = quote! {
+        let callbacks_clone = #f_callbacks.clone();
+        component.global::<#global_type_name>().#on_ident(move |idx, #(#params),*| {
+            // 인자가 범위를 벗어나 버려지면 핸들러를 호출하지 않고 기본값을 반환합니다.
+            let result: #resolved_ret = match (callbacks_clone.get(idx as usize), #args) {
+                (Some(callback), Some(args)) => callback.call(args),
+                _ => <#resolved_ret as Default>::default(),
+            };
+            #result.unwrap_or_default()
+        });
+    };
+
+    let row = quote! {
+        let #f_name = #f_callbacks[i].clone();
+    };
 
-    (signal_init, loop_body, struct_init)
+    let mock = quote! { #f_name: #f_callbacks[i].clone(), };
+
+    let install_setup = collect_rows(&f_callbacks, f_name, false);
+
+    FieldCode { setup, row, mock, install_setup, install, ..Default::default() }
 }
 
-fn process_data_field(
+/// Slint 콜백의 인자 이름들과, 인자를 필드 타입의 값(`Option<_>`)으로 모으는 식을 생성합니다.
+/// Slint 는 튜플 인자를 개별 인자로 전달합니다.
+fn callback_args(f: &SlintModelField, policy: &IntPolicy) -> (Vec<syn::Ident>, TokenStream) {
+    let arg_tys: Vec<&Type> = match &f.ty {
+        Type::Tuple(tuple) => tuple.elems.iter().collect(),
+        ty => vec![ty],
+    };
+    let params: Vec<_> = (0..arg_tys.len()).map(|n| format_ident!("a{}", n)).collect();
+    let converted: Vec<_> = arg_tys.iter().zip(&params).map(|(ty, param)| from_slint_value(ty, quote! { #param }, policy)).collect();
+
+    let args = match &f.ty {
+        Type::Tuple(tuple) if tuple.elems.is_empty() => quote! { Some(()) },
+        Type::Tuple(_) => quote! {
+            match (#(#converted,)*) {
+                (#(Some(#params),)*) => Some((#(#params,)*)),
+                _ => None,
+            }
+        },
+        _ => quote! { #(#converted)* },
+    };
+
+    (params, args)
+}
+
+/// `action` 필드의 결과 타입. `-> Ret` 이 없으면 `()` 입니다.
+fn action_ret_type(f: &SlintModelField) -> TokenStream {
+    match &f.ret {
+        Some(ret) => resolve_type(ret),
+        None => quote! { () },
+    }
+}
+
+/// `action` 필드: 행마다 `SlintAction` 을 만들고, Slint 의 요청을 해당 행의 큐로 전달합니다.
+/// 처리 상태는 행 데이터의 `-pending`, `-error`, `-result` 필드에 반영됩니다.
+fn process_action_field(
     f: &SlintModelField,
+    field: usize,
+    array_len_tokens: &TokenStream,
     global_type_name: &syn::Ident,
-) -> (TokenStream, TokenStream, TokenStream) {
+) -> FieldCode {
+    let f_name = &f.name;
+    let f_actions = format_ident!("{}_actions", f_name);
+    let f_states = format_ident!("{}_states", f_name);
+    let f_state = format_ident!("{}_state", f_name);
+    let on_ident = format_ident!("on_{}", f.slint_ident());
+    let pending_ident = format_ident!("{}_pending", f.slint_ident());
+    let error_ident = format_ident!("{}_error", f.slint_ident());
+    let resolved_ty = resolve_type(&f.ty);
+    let resolved_ret = action_ret_type(f);
+
+    if let Some(init) = &f.init {
+        proc_macro_error::abort!(init, "`action` fields cannot have an initial value.");
+    }
+
+    let policy = IntPolicy::new(&f.options, f_name);
+
+    // 결과 타입이 `()` 이면 `-result` 필드가 없습니다.
+    let set_result = match f.ret.as_ref().filter(|ret| !is_unit_ty(ret)) {
+        Some(ret) => {
+            let result_ident = format_ident!("{}_result", f.slint_ident());
+            let value = to_slint_value(ret, quote! { value }, &policy);
+            quote! {
+                if let Some(value) = #value {
+                    data.#result_ident = value;
+                }
+            }
+        }
+        None => quote! { let _ = value; },
+    };
+
+    let (params, args) = callback_args(f, &policy);
+
+    let setup = quote! {
+        let #f_states: Vec<frand_property::Property<Option<frand_property::slint::ActionState<#resolved_ret>>>> =
+            (0..#array_len_tokens).map(|_| frand_property::Property::new((), None, |_, _| {})).collect();
+        let #f_actions: Vec<frand_property::slint::SlintAction<#resolved_ty, #resolved_ret>> = (0..#array_len_tokens).map(|i| {
+            let writer = writer.clone();
+            let state_sender = #f_states[i].sender().clone();
+            frand_property::slint::SlintAction::new(move |state: &frand_property::slint::ActionState<#resolved_ret>| {
+                state_sender.notify_with(Some(state.clone()));
+                // 창이 닫힌 뒤에 끝난 요청은 반영할 곳이 없으므로 무시합니다.
+                writer.write(i, #field, state.clone());
+            })
+        }).collect();
+    };
+
+    let apply = apply_arm(field, quote! { frand_property::slint::ActionState<#resolved_ret> }, quote! { state }, quote! {
+        match state {
+            frand_property::slint::ActionState::Pending => {
+                data.#pending_ident = true;
+                data.#error_ident = Default::default();
+            }
+            frand_property::slint::ActionState::Done(value) => {
+                data.#pending_ident = false;
+                #set_result
+            }
+            frand_property::slint::ActionState::Failed(error) => {
+                data.#pending_ident = false;
+                data.#error_ident = error.into();
+            }
+        }
+        true
+    });
+
+    let install = quote! {
+        let actions_clone = #f_actions.clone();
+        component.global::<#global_type_name>().#on_ident(move |idx, #(#params),*| {
+            // 처리 중인 요청이 있거나 인자가 범위를 벗어나 버려지면 요청하지 않습니다.
+            if let (Some(action), Some(args)) = (actions_clone.get(idx as usize), #args) {
+                action.invoke(args);
+            }
+        });
+    };
+
+    let row = quote! {
+        let #f_name = #f_actions[i].clone();
+    };
+
+    // 다시 연결한 창에도 처리 중인 요청을 표시합니다.
+    let install_row = quote! {
+        slint_row_data.#pending_ident = #f_actions[i].is_pending();
+    };
+
+    let mock = quote! {
+        #f_name: #f_actions[i].clone(),
+        #f_state: #f_states[i].receiver().clone(),
+    };
+
+    let install_setup = collect_rows(&f_actions, f_name, false);
+
+    FieldCode { setup, row, mock, install_setup, apply, install, install_row }
+}
+
+fn process_data_field(
+    f: &SlintModelField,
+    field: usize,
+) -> FieldCode {
     let f_name = &f.name;
+    let data_name = f.slint_ident();
     let f_ty = &f.ty;
     let f_prop = format_ident!("{}_prop", f_name);
+    let f_rows = format_ident!("{}_rows", f_name);
 
     let (is_array, elem_ty, array_len) = if let Type::Array(arr) = f_ty {
          (true, arr.elem.as_ref(), Some(&arr.len))
@@ -446,22 +1005,24 @@ fn process_data_field(
 
     let resolved_elem_ty = resolve_type(elem_ty);
 
+    // 연결할 때 현재 값을 읽을 수 있도록 행마다 필드 값을 모읍니다.
+    let install_setup = collect_rows(&f_rows, f_name, false);
+
     if is_array {
         if f.direction == Direction::In {
             // 배열 IN: 각 요소에 대해 Property 생성
             let len = array_len.expect("Array length required for 'in' property fields");
-            let (setup, init) = generate_in_array_setup(f_name, len, &resolved_elem_ty);
-            (setup, quote! { #f_name }, init)
+            generate_in_array_setup(f, len, elem_ty)
         } else if f.direction == Direction::Model {
              // 모델은 반드시 [] (Type::Slice) 여야 함. Type::Array(길이 명시)는 허용하지 않음.
              if let Some(_len) = array_len {
                  proc_macro_error::abort!(f_name, "Model fields must use implicit length syntax `[]`. Explicit length `[N]` is not allowed for models.");
              }
              
-             let loop_body = quote! {
+             let row = quote! {
                  let #f_name = #resolved_elem_ty::clone_singleton();
              };
-             (loop_body, quote! { #f_name }, quote!{})
+             FieldCode { row, ..Default::default() }
         } else {
              // Out: 반드시 [N] (Type::Array) 여야 함. Type::Slice(길이 생략)는 허용하지 않음.
              // array_len이 None이면 Type::Slice라는 의미
@@ -471,71 +1032,161 @@ fn process_data_field(
 
              let len = array_len.expect("Array length required for 'out' property fields");
              let f_senders = format_ident!("{}_senders", f_name);
-             let vec_init = generate_vec_init_tokens(len, &resolved_elem_ty);
-             let loop_body = quote! {
+             let f_receivers = format_ident!("{}_receivers", f_name);
+             let f_values = format_ident!("{}_values", f_name);
+             let init_values = generate_init_values(f_name, len, elem_ty, f.init.as_ref());
+             let policy = IntPolicy::new(&f.options, f_name);
+             let elem_value = to_slint_value(elem_ty, quote! { v }, &policy);
+             let current_elem_value = to_slint_value(elem_ty, quote! { sender.value() }, &policy);
+             let row = quote! {
+                 let #f_values = #init_values;
                  let mut #f_senders = Vec::with_capacity(#len);
-                 for j in 0..#len {
-                    let prop = frand_property::Property::<#resolved_elem_ty, slint::Weak<C>>::new(
-                         weak.clone(),
-                         <#resolved_elem_ty as Default>::default(),
-                         move |c, v| {
-                             c.upgrade_in_event_loop(move |c| {
-                                 let global = c.global::<#global_type_name>();
-                                 let model = global.get_data();
-                                 if let Some(data) = model.row_data(i) {
-                                     data.#f_name.set_row_data(j, v.into());
-                                 }
-                             }).unwrap();
+                 let mut #f_receivers = Vec::with_capacity(#len);
+                 for (j, value) in #f_values.iter().cloned().enumerate() {
+                    let prop = frand_property::Property::<#resolved_elem_ty, frand_property::slint::SlintHandle<C>>::new(
+                         handle.clone(),
+                         value,
+                         {
+                             let writer = writer.clone();
+                             move |_, v| writer.write(i, #field, (j, v))
                          }
                     );
                     #f_senders.push(prop.sender().clone());
+                    #f_receivers.push(prop.receiver().clone());
                 }
-                let #f_name: std::sync::Arc<[frand_property::Sender<#resolved_elem_ty, slint::Weak<C>>]> = #f_senders.into();
+                let #f_name: std::sync::Arc<[frand_property::Sender<#resolved_elem_ty, frand_property::slint::SlintHandle<C>>]> = #f_senders.into();
             };
-            let struct_init = quote! { #f_name };
-            let slint_assignment = quote! {
-                slint_row_data.#f_name = slint::ModelRc::new(std::rc::Rc::new(slint::VecModel::from(
-                    #vec_init
+            // 요소는 행의 배열 모델에 쓰므로 행은 다시 쓰지 않습니다.
+            let apply = apply_arm(field, quote! { (usize, #resolved_elem_ty) }, quote! { (j, v) }, quote! {
+                if let Some(value) = #elem_value {
+                    data.#data_name.set_row_data(j, value);
+                }
+                false
+            });
+            let install_row = quote! {
+                slint_row_data.#data_name = slint::ModelRc::new(std::rc::Rc::new(slint::VecModel::from(
+                    #f_rows[i].iter().map(|sender| #current_elem_value.unwrap_or_default()).collect::<Vec<_>>()
                 )));
             };
-            (loop_body, struct_init, slint_assignment)
+            let mock = quote! { #f_name: #f_receivers.into(), };
+            FieldCode { row, mock, install_setup, apply, install_row, ..Default::default() }
         }
     } else {
         // 스칼라 로직
+        let f_init = format_ident!("{}_init", f_name);
+        let init_value = generate_init_value(f_ty, f.init.as_ref());
+        let install_row = assign_slint_field(f, quote! { slint_row_data }, quote! { #f_rows[i].value() });
+
         if f.direction == Direction::In {
-            let loop_body = quote! {
-                let #f_prop = frand_property::Property::new(weak.clone(), Default::default(), |_, _| {});
+            let f_senders = format_ident!("{}_senders", f_name);
+            let row = quote! {
+                let #f_init: #resolved_elem_ty = #init_value;
+                let #f_prop = frand_property::Property::new((), #f_init, |_, _| {});
                 let #f_name = #f_prop.receiver().clone();
             };
-            (loop_body, quote! { #f_name }, quote!{})
+            let mock = quote! { #f_name: #f_prop.sender().clone(), };
+            // UI 에서 바뀐 값은 `{name}_senders` 로 보냅니다.
+            let install_setup = [install_setup, collect_rows(&f_senders, f_name, true)].into_iter().collect();
+            FieldCode { row, mock, install_setup, install_row, ..Default::default() }
         } else if f.direction == Direction::Model {
-             let loop_body = quote! {
+             if let Some(init) = &f.init {
+                 proc_macro_error::abort!(init, "`model` fields cannot have an initial value.");
+             }
+
+             let row = quote! {
                  let #f_name = #resolved_elem_ty::clone_singleton();
              };
-             (loop_body, quote! { #f_name }, quote!{})
+             FieldCode { row, ..Default::default() }
         } else {
             // Out Scalar
-            let setter = if is_special_string_type(f_ty) {
-                 quote! {
-                     if let Some(mut data) = model.row_data(i) {
-                          data.#f_name = v.to_string().into();
-                          model.set_row_data(i, data);
-                     }
-                 }
-            } else {
-                 quote! {
-                     if let Some(mut data) = model.row_data(i) {
-                          data.#f_name = v.into();
-                          model.set_row_data(i, data);
-                     }
-                 }
+            let out_prop_logic = generate_out_property(field, resolved_elem_ty.clone(), quote! { #f_init });
+            let row = quote! {
+                let #f_init: #resolved_elem_ty = #init_value;
+                let #f_prop = #out_prop_logic;
+                let #f_name = #f_prop.sender().clone();
             };
+            let apply = apply_arm(field, resolved_elem_ty, quote! { v }, {
+                let assign = assign_slint_field(f, quote! { data }, quote! { v });
+                quote! { #assign true }
+            });
+            let mock = quote! { #f_name: #f_prop.receiver().clone(), };
+            FieldCode { row, mock, install_setup, apply, install_row, ..Default::default() }
+        }
+    }
+}
 
-            let out_prop_logic = generate_out_property(global_type_name, setter, resolved_elem_ty);
-            let loop_body = quote! {
-                let #f_name = #out_prop_logic.sender().clone();
-            };
-            (loop_body, quote! { #f_name }, quote!{})
+fn process_computed_field(
+    f: &SlintModelField,
+    field: usize,
+    input: &SlintModel,
+) -> FieldCode {
+    let f_name = &f.name;
+    let f_prop = format_ident!("{}_prop", f_name);
+    let f_rows = format_ident!("{}_rows", f_name);
+    let f_compute = format_ident!("{}_compute", f_name);
+    let resolved_ty = resolve_type(&f.ty);
+
+    let (closure, source_names) = parse_computed_closure(f_name, &f.ty, f.init.as_ref());
+
+    let index = input.fields.iter().position(|field| field.name == *f_name).expect("computed field must be in the model");
+    let sources: Vec<_> = source_names.into_iter().map(|name| {
+        // 소스 필드는 computed 필드보다 먼저 선언되어 있어야 합니다.
+        let Some(source) = input.fields.iter().take(index).find(|s| s.name == name) else {
+            proc_macro_error::abort!(name, "`{}` is not a field declared before computed field `{}`", name, f_name);
+        };
+        if matches!(source.direction, Direction::Model | Direction::Callback | Direction::Action) || is_unit_ty(&source.ty) {
+            proc_macro_error::abort!(name, "only `in`, `out` and `computed` fields can be sources of a computed field");
+        }
+
+        let (is_array, elem_ty) = split_array_type(&source.ty);
+        let elem_ty = resolve_type(elem_ty);
+        let arg_ty = if is_array {
+            quote! { std::vec::Vec<#elem_ty> }
+        } else {
+            quote! { #elem_ty }
+        };
+
+        let senders = match (&source.direction, is_array) {
+            (Direction::In, true) => {
+                let source_senders = format_ident!("{}_senders", name);
+                quote! { #source_senders.iter() }
+            }
+            (Direction::Out, true) => quote! { #name.iter() },
+            (Direction::Out, false) => quote! { std::iter::once(&#name) },
+            _ => {
+                let source_prop = format_ident!("{}_prop", name);
+                quote! { std::iter::once(#source_prop.sender()) }
+            }
+        };
+
+        ComputedSource {
+            arg_ty,
+            is_array,
+            capture: quote! { #name.clone() },
+            senders,
+            name,
         }
+    }).collect();
+
+    let compute_fn = generate_computed_fn(f_name, closure, &sources, &resolved_ty);
+    let watch = generate_computed_watch(f_name, &sources);
+
+    let out_prop_logic = generate_out_property(field, resolved_ty.clone(), quote! { #f_compute() });
+    let assign = assign_slint_field(f, quote! { data }, quote! { v });
+
+    let row = quote! {
+        #compute_fn
+        let #f_prop = #out_prop_logic;
+        #watch
+        let #f_name = #f_prop.receiver().clone();
+    };
+
+    FieldCode {
+        row,
+        mock: quote! { #f_name: #f_prop.receiver().clone(), },
+        install_setup: collect_rows(&f_rows, f_name, false),
+        apply: apply_arm(field, resolved_ty, quote! { v }, quote! { #assign true }),
+        install_row: assign_slint_field(f, quote! { slint_row_data }, quote! { #f_rows[i].value() }),
+        ..Default::default()
     }
 }
@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use frand_property_build::parser::{Overflow, SlintEnum, SlintStruct};
+use crate::common::{resolve_type, split_array_type, to_slint_value, from_slint_value, IntPolicy};
+
+/// `slint_enum!` 의 Rust 열거형과, Slint 가 생성한 같은 이름의 열거형(`crate::Name`)과의 변환을 생성합니다.
+pub fn generate_enum(input: &SlintEnum, doc_comment: TokenStream) -> TokenStream {
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let name = &input.name;
+
+    if input.variants.is_empty() {
+        proc_macro_error::abort!(name, "`slint_enum!` requires at least one variant");
+    }
+
+    // Slint 열거형의 기본값은 첫 번째 값입니다.
+    let variant_defs = input.variants.iter().enumerate().map(|(index, variant)| {
+        let variant_attrs = &variant.attrs;
+        let variant_name = &variant.name;
+        let default_attr = if index == 0 { quote! { #[default] } } else { quote! {} };
+        quote! { #(#variant_attrs)* #default_attr #variant_name }
+    });
+    let variant_names: Vec<_> = input.variants.iter().map(|variant| &variant.name).collect();
+
+    quote! {
+        #(#attrs)*
+        #doc_comment
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #vis enum #name {
+            #(#variant_defs),*
+        }
+
+        impl From<#name> for crate::#name {
+            fn from(value: #name) -> Self {
+                match value {
+                    #(#name::#variant_names => crate::#name::#variant_names),*
+                }
+            }
+        }
+
+        impl From<crate::#name> for #name {
+            fn from(value: crate::#name) -> Self {
+                match value {
+                    #(crate::#name::#variant_names => #name::#variant_names),*
+                }
+            }
+        }
+    }
+}
+
+/// `slint_struct!` 의 Rust 구조체와, Slint 가 생성한 같은 이름의 구조체(`crate::Name`)와의 변환을 생성합니다.
+pub fn generate_struct(input: &SlintStruct, doc_comment: TokenStream) -> TokenStream {
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let name = &input.name;
+
+    let field_defs = input.fields.iter().map(|f| {
+        let f_attrs = &f.attrs;
+        let f_vis = &f.vis;
+        let f_name = &f.name;
+        if split_array_type(&f.ty).0 {
+            proc_macro_error::abort!(f.ty, "`slint_struct!` fields cannot be arrays");
+        }
+        // `From` 변환은 값을 버릴 수 없으므로 `reject` 를 지원하지 않습니다.
+        if let Some((Overflow::Reject, span)) = f.options.overflow {
+            proc_macro_error::abort!(span, "`overflow = \"reject\"` is not supported on `slint_struct!` fields. Use \"saturate\" or \"wrap\".");
+        }
+        let resolved_ty = resolve_type(&f.ty);
+        quote! { #(#f_attrs)* #f_vis #f_name: #resolved_ty }
+    });
+
+    let to_slint = input.fields.iter().map(|f| {
+        let f_name = &f.name;
+        let slint_ident = f.slint_ident();
+        let value = to_slint_value(&f.ty, quote! { value.#f_name }, &IntPolicy::new(&f.options, f_name));
+        quote! { #slint_ident: #value.unwrap_or_default() }
+    });
+
+    let from_slint = input.fields.iter().map(|f| {
+        let f_name = &f.name;
+        let slint_ident = f.slint_ident();
+        let value = from_slint_value(&f.ty, quote! { value.#slint_ident }, &IntPolicy::new(&f.options, f_name));
+        quote! { #f_name: #value.unwrap_or_default() }
+    });
+
+    quote! {
+        #(#attrs)*
+        #doc_comment
+        #[derive(Debug, Clone, PartialEq, Default)]
+        #vis struct #name {
+            #(#field_defs),*
+        }
+
+        impl From<#name> for crate::#name {
+            fn from(value: #name) -> Self {
+                Self {
+                    #(#to_slint),*
+                }
+            }
+        }
+
+        impl From<crate::#name> for #name {
+            fn from(value: crate::#name) -> Self {
+                Self {
+                    #(#from_slint),*
+                }
+            }
+        }
+    }
+}
@@ -3,29 +3,36 @@ use crate::{AdderGlobal, MainWindow};
 
 slint_model! {
     export to "components/adder.slint";
+    /// 두 정수를 더하는 예제 모델입니다.
     pub AdderModel: AdderGlobal {
+        /// 첫 번째 피연산자
         in x: i32,
+        /// 두 번째 피연산자
         in y: i32,
-        out sum: i32,
+        /// `x + y`의 결과
+        computed sum: i32 = |x, y| x + y,
+        /// 합계를 화면에 표시할 문자열로 변환합니다.
+        callback format_sum: (i32) -> String,
+        /// 합계를 저장합니다. 저장이 끝나면 저장된 값을 돌려줍니다.
+        action save: (i32) -> i32,
     }
 }
 
 impl AdderModel<MainWindow> {
     pub fn start(&self) {
-        let mut x = self.x.clone();
-        let mut y = self.y.clone();
-        let sum = self.sum.clone();
+        self.format_sum.set_handler(|sum| format!("Sum: {sum}"));
 
+        let save = self.save.clone();
         crate::spawn(async move {
-            loop {
-                tokio::select! {
-                    x = x.modified() => {
-                        sum.send(x + y.value());
-                    }
-                    y = y.modified() => {
-                        sum.send(x.value() + y);
-                    }
-                }
+            while let Some(request) = save.next().await {
+                let sum = request.args;
+                let result = if sum < 0 {
+                    Err("음수는 저장할 수 없습니다")
+                } else {
+                    log::info!("saved sum: {sum}");
+                    Ok(sum)
+                };
+                request.complete(result);
             }
         });
     }
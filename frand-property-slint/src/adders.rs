@@ -1,8 +1,5 @@
 use frand_property::slint_model;
-use crate::{AddersGlobal, MainWindow};
-use futures::stream::FuturesUnordered;
-use futures::StreamExt;
-use futures::future::BoxFuture;
+use crate::AddersGlobal;
 
 const MODEL_LEN: usize = 2;
 const PROP_LEN: usize = 3;
@@ -11,40 +8,6 @@ slint_model! {
     export to "components/adders.slint";
     pub AddersModel[MODEL_LEN]: AddersGlobal {
         in values: i32[PROP_LEN],
-        out sum: i32,
-    }
-}
-
-impl AddersModel<MainWindow> {
-    pub fn start(&self) {
-        let values = self.values.clone();
-        let sum = self.sum.clone();
-
-        crate::spawn(async move {
-            let mut futures: FuturesUnordered<BoxFuture<'static, (usize, i32)>> = FuturesUnordered::new();
-            
-            // 초기 퓨처 등록
-            for (idx, rx) in values.iter().enumerate() {
-                let mut rx = rx.clone();
-                futures.push(Box::pin(async move {
-                    let val = rx.modified().await;
-                    (idx, val)
-                }));
-            }
-
-            // 변경 감지 루프
-            while let Some((idx, _val)) = futures.next().await {
-                // 변경된 인덱스의 퓨처 재등록
-                let mut rx = values[idx].clone();
-                futures.push(Box::pin(async move {
-                    let val = rx.modified().await;
-                    (idx, val)
-                }));
-
-                // 합계 재계산 및 전송
-                let current_sum: i32 = values.iter().map(|v| v.value()).sum();
-                sum.send(current_sum);
-            }
-        });
+        computed sum: i32 = |values| values.iter().sum(),
     }
 }
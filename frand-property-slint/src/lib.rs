@@ -36,13 +36,10 @@ pub fn run() -> Result<(), slint::PlatformError> {
     let screen_model = ScreenModel::<MainWindow>::clone_singleton();
     screen_model.start();
 
+    // 합계는 computed 필드로 자동 계산됩니다. start() 는 합계 표시 형식만 등록합니다.
     let adder_model = AdderModel::<MainWindow>::clone_singleton();
     adder_model.start();
-
-    let adder_vec_models = AddersModel::<MainWindow>::clone_singleton();
-    for model in adder_vec_models.iter() {
-        model.start();
-    }
+    AddersModel::<MainWindow>::clone_singleton();
 
     let repeater_model = repeater::RepeaterModel::<MainWindow>::clone_singleton();
     repeater_model.start();
@@ -1,4 +1,6 @@
 use frand_property::slint_model;
+use frand_property::slint::Length;
+use slint::Color;
 use crate::{MainWindow, RepeaterGlobal};
 use arraystring::ArrayString;
 use arraystring::typenum::{U20, U41};
@@ -8,18 +10,33 @@ slint_model! {
     pub RepeaterModel: RepeaterGlobal {
         in text: ArrayString<U20>,
         out repeated: ArrayString<U41>,
+        /// 입력 글자 수. 입력이 비어 있으면 `None`
+        out length: Option<usize>,
+        /// 결과 글자 크기
+        out font_size: Length = Length(16.0),
+        /// 결과 글자 색상. 입력이 길이 제한에 가까워지면 바뀝니다.
+        out color: Color = NORMAL_COLOR,
     }
 }
 
+const NORMAL_COLOR: Color = Color::from_rgb_u8(0x33, 0x99, 0x33);
+const WARNING_COLOR: Color = Color::from_rgb_u8(0xcc, 0x33, 0x33);
+
 impl RepeaterModel<MainWindow> {
     pub fn start(&self) {
         let mut text = self.text.clone();
         let repeated = self.repeated.clone();
+        let length = self.length.clone();
+        let color = self.color.clone();
 
         crate::spawn(async move {
             loop {
                 let val = text.modified().await;
                 
+                let count = val.chars().count();
+                length.send((count > 0).then_some(count));
+                color.send(if count > 15 { WARNING_COLOR } else { NORMAL_COLOR });
+
                 let s = format!("{} {}", val, val);
 
                 if let Ok(res) = ArrayString::<U41>::try_from_str(&s) {
@@ -1,6 +1,14 @@
-use frand_property::slint_model;
+use frand_property::{slint_enum, slint_model};
 use crate::{MainWindow, ScreenGlobal};
-use crate::ScreenVariant;
+
+slint_enum! {
+    export to "screen/screen.slint";
+    /// 현재 표시 중인 화면
+    pub enum ScreenVariant {
+        Start,
+        Pay,
+    }
+}
 
 slint_model! {
     export to "screen/screen.slint";
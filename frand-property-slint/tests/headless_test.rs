@@ -0,0 +1,351 @@
+use arraystring::ArrayString;
+use arraystring::typenum::U8;
+use frand_property::slint::testing::{process_events, run};
+use frand_property::slint::SlintSingleton;
+use frand_property::slint_model;
+use slint::{ComponentHandle, Model};
+
+const ROWS: usize = 2;
+
+slint_model! {
+    pub FixtureModel: FixtureGlobal {
+        in x: i32,
+        out doubled: i32,
+        in values: i32[3],
+        out flags: bool[2],
+        in name: ArrayString<U8>,
+        out greeting: String,
+        callback ping: (),
+        callback describe: (i32) -> String,
+    }
+}
+
+slint_model! {
+    pub RowsModel[ROWS]: RowsGlobal {
+        in value: i32,
+        out total: i32,
+    }
+}
+
+slint_model! {
+    pub OrderModel: OrderGlobal {
+        in price: i32,
+        in qty: i32,
+        computed total: i32 = |price, qty| price * qty,
+        action save: (i32) -> i32,
+    }
+}
+
+slint_model! {
+    pub CounterModel: CounterGlobal {
+        in step: i32,
+        out count: i32,
+    }
+}
+
+// `frand-property-gen print` 으로 만든 선언에, UI 처럼 값을 쓰고 읽는 창을 붙였습니다.
+slint::slint! {
+    export struct FixtureGlobalData {
+        x: int,
+        doubled: int,
+        values: [int],
+        flags: [bool],
+        name: string,
+        greeting: string,
+    }
+
+    export global FixtureGlobal {
+        in-out property <[FixtureGlobalData]> data: [{}];
+        callback ping(int);
+        pure callback describe(int, int) -> string;
+    }
+
+    export struct RowsGlobalData {
+        value: int,
+        total: int,
+    }
+
+    export global RowsGlobal {
+        in-out property <[RowsGlobalData]> data: [{}];
+    }
+
+    export struct OrderGlobalData {
+        price: int,
+        qty: int,
+        total: int,
+        save-pending: bool,
+        save-error: string,
+        save-result: int,
+    }
+
+    export global OrderGlobal {
+        in-out property <[OrderGlobalData]> data: [{}];
+        callback save(int, int);
+    }
+
+    export component OrderWindow inherits Window {
+        out property <int> total: OrderGlobal.data[0].total;
+        out property <bool> save-pending: OrderGlobal.data[0].save-pending;
+        out property <string> save-error: OrderGlobal.data[0].save-error;
+        out property <int> save-result: OrderGlobal.data[0].save-result;
+
+        public function set-price(value: int) { OrderGlobal.data[0].price = value; }
+        public function set-qty(value: int) { OrderGlobal.data[0].qty = value; }
+        public function save(value: int) { OrderGlobal.save(0, value); }
+    }
+
+    export struct CounterGlobalData {
+        step: int,
+        count: int,
+    }
+
+    export global CounterGlobal {
+        in-out property <[CounterGlobalData]> data: [{}];
+    }
+
+    export component CounterWindow inherits Window {
+        out property <int> count: CounterGlobal.data[0].count;
+
+        public function set-step(value: int) { CounterGlobal.data[0].step = value; }
+    }
+
+    export component FixtureWindow inherits Window {
+        out property <int> doubled: FixtureGlobal.data[0].doubled;
+        out property <bool> second-flag: FixtureGlobal.data[0].flags[1];
+        out property <string> greeting: FixtureGlobal.data[0].greeting;
+        out property <int> second-total: RowsGlobal.data[1].total;
+
+        public function set-x(value: int) { FixtureGlobal.data[0].x = value; }
+        public function set-value(index: int, value: int) { FixtureGlobal.data[0].values[index] = value; }
+        public function set-name(value: string) { FixtureGlobal.data[0].name = value; }
+        public function set-row-value(row: int, value: int) { RowsGlobal.data[row].value = value; }
+        public function ping() { FixtureGlobal.ping(0); }
+        public function describe(value: int) -> string { return FixtureGlobal.describe(0, value); }
+    }
+}
+
+#[test]
+fn test_headless_scalar_fields() {
+    run(|| {
+        let window = FixtureWindow::new().unwrap();
+        let model = FixtureModel::bind_to(&window);
+
+        // UI 에서 쓴 `in` 값은 바로 Receiver 에 전달됩니다.
+        window.invoke_set_x(21);
+        assert_eq!(model.x.value(), 21);
+
+        // Rust 에서 보낸 `out` 값은 이벤트 루프를 돌린 뒤에 UI 에 반영됩니다.
+        model.doubled.send(42);
+        assert_eq!(window.get_doubled(), 0);
+        process_events();
+        assert_eq!(window.get_doubled(), 42);
+        // Rust 에서 쓴 행은 `in` 값을 다시 보내지 않습니다.
+        assert_eq!(model.x.value(), 21);
+    });
+}
+
+#[test]
+fn test_headless_array_fields() {
+    run(|| {
+        let window = FixtureWindow::new().unwrap();
+        let model = FixtureModel::bind_to(&window);
+
+        window.invoke_set_value(1, 7);
+        assert_eq!(model.values.iter().map(|value| value.value()).collect::<Vec<_>>(), [0, 7, 0]);
+
+        model.flags[1].send(true);
+        process_events();
+        assert!(window.get_second_flag());
+        let row = window.global::<FixtureGlobal>().get_data().row_data(0).unwrap();
+        assert_eq!(row.flags.iter().collect::<Vec<_>>(), [false, true]);
+    });
+}
+
+#[test]
+fn test_headless_string_fields() {
+    run(|| {
+        let window = FixtureWindow::new().unwrap();
+        let model = FixtureModel::bind_to(&window);
+
+        window.invoke_set_name("frand".into());
+        assert_eq!(model.name.value().as_str(), "frand");
+        // 길이 제한을 넘는 입력은 버려집니다.
+        window.invoke_set_name("too long name".into());
+        assert_eq!(model.name.value().as_str(), "frand");
+
+        model.greeting.send("hello frand".to_string());
+        process_events();
+        assert_eq!(window.get_greeting(), "hello frand");
+    });
+}
+
+#[test]
+fn test_headless_callbacks() {
+    run(|| {
+        let window = FixtureWindow::new().unwrap();
+        let model = FixtureModel::bind_to(&window);
+
+        assert!(!model.ping.has_notified());
+        window.invoke_ping();
+        assert!(model.ping.has_notified());
+
+        assert_eq!(window.invoke_describe(3), "");
+        model.describe.set_handler(|value| format!("value: {value}"));
+        assert_eq!(window.invoke_describe(3), "value: 3");
+    });
+}
+
+#[test]
+fn test_headless_model_array() {
+    run(|| {
+        let window = FixtureWindow::new().unwrap();
+        let rows = RowsModel::bind_to(&window);
+        assert_eq!(rows.len(), ROWS);
+
+        window.invoke_set_row_value(1, 5);
+        assert_eq!(rows[0].value.value(), 0);
+        assert_eq!(rows[1].value.value(), 5);
+
+        // 같은 이벤트 루프 호출에서 여러 행에 쓴 값이 모두 반영됩니다.
+        rows[0].total.send(1);
+        rows[1].total.send(10);
+        rows[1].total.send(11);
+        process_events();
+        let data = window.global::<RowsGlobal>().get_data();
+        assert_eq!(data.iter().map(|row| row.total).collect::<Vec<_>>(), [1, 11]);
+        assert_eq!(window.get_second_total(), 11);
+    });
+}
+
+#[test]
+fn test_headless_attach_mocked_model() {
+    run(|| {
+        let (model, ui) = FixtureModel::mocked();
+        ui.x.send(4);
+        model.doubled.send(8);
+        assert_eq!(ui.doubled.value(), 8);
+
+        // 창 없이 만든 모델도 나중에 창에 연결하면 현재 값으로 UI 를 채웁니다.
+        let window = FixtureWindow::new().unwrap();
+        FixtureModel::attach(&model, &window);
+        assert_eq!(window.get_doubled(), 8);
+        assert!(std::sync::Arc::ptr_eq(&FixtureModel::bind_to(&window), &model));
+
+        window.invoke_set_x(5);
+        assert_eq!(model.x.value(), 5);
+    });
+}
+
+/// UI 스레드에서 다음 요청을 기다립니다. 요청이 이미 들어와 있으면 바로 반환합니다.
+fn next_request<A, R>(action: &frand_property::slint::SlintAction<A, R>) -> frand_property::slint::ActionRequest<A, R> {
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(action.next()).unwrap()
+}
+
+#[test]
+fn test_headless_computed_fields() {
+    run(|| {
+        let window = OrderWindow::new().unwrap();
+        let model = OrderModel::bind_to(&window);
+
+        // UI 에서 쓴 소스 값으로 바로 다시 계산하고, 결과는 이벤트 루프를 돌린 뒤에 UI 에 반영됩니다.
+        window.invoke_set_price(3);
+        window.invoke_set_qty(4);
+        assert_eq!(model.total.value(), 12);
+        process_events();
+        assert_eq!(window.get_total(), 12);
+
+        window.invoke_set_qty(5);
+        process_events();
+        assert_eq!(window.get_total(), 15);
+    });
+}
+
+#[test]
+fn test_headless_action() {
+    run(|| {
+        let window = OrderWindow::new().unwrap();
+        let model = OrderModel::bind_to(&window);
+
+        window.invoke_save(7);
+        process_events();
+        assert!(window.get_save_pending());
+        // 처리 중인 동안의 요청은 거부됩니다.
+        window.invoke_save(8);
+
+        let request = next_request(&model.save);
+        assert_eq!(request.args, 7);
+        request.complete(Ok::<_, String>(70));
+        process_events();
+        assert!(!window.get_save_pending());
+        assert_eq!(window.get_save_result(), 70);
+        assert_eq!(window.get_save_error(), "");
+
+        window.invoke_save(-1);
+        let request = next_request(&model.save);
+        assert_eq!(request.args, -1);
+        request.complete(Err("negative"));
+        process_events();
+        assert!(!window.get_save_pending());
+        assert_eq!(window.get_save_error(), "negative");
+        // 실패해도 마지막으로 성공한 결과는 남습니다.
+        assert_eq!(window.get_save_result(), 70);
+    });
+}
+
+#[test]
+fn test_headless_window_recreation() {
+    run(|| {
+        let first = FixtureWindow::new().unwrap();
+        let model = FixtureModel::bind_to(&first);
+        model.describe.set_handler(|value| format!("value: {value}"));
+        first.invoke_set_x(4);
+        model.doubled.send(8);
+        process_events();
+        drop(first);
+
+        // 새 창에 다시 연결하면 마지막 `in` 값과 `out` 값으로 UI 를 채우고 콜백을 다시 설치합니다.
+        let second = FixtureWindow::new().unwrap();
+        FixtureModel::attach(&model, &second);
+        assert_eq!(second.get_doubled(), 8);
+        assert_eq!(second.global::<FixtureGlobal>().get_data().row_data(0).unwrap().x, 4);
+        assert_eq!(second.invoke_describe(3), "value: 3");
+        assert!(std::sync::Arc::ptr_eq(&FixtureModel::bind_to(&second), &model));
+
+        second.invoke_set_x(6);
+        assert_eq!(model.x.value(), 6);
+        model.doubled.send(12);
+        process_events();
+        assert_eq!(second.get_doubled(), 12);
+    });
+}
+
+#[test]
+fn test_headless_clone_singleton_off_ui_thread() {
+    run(|| {
+        let window = CounterWindow::new().unwrap();
+        window.init_singleton();
+        let model = CounterModel::<CounterWindow>::clone_singleton();
+
+        // 바인딩된 싱글톤 모델은 창을 꺼낼 수 없는 다른 스레드에서도 가져옵니다.
+        let from_thread = std::thread::spawn(CounterModel::<CounterWindow>::clone_singleton).join().unwrap();
+        assert!(std::sync::Arc::ptr_eq(&from_thread, &model));
+
+        window.invoke_set_step(2);
+        assert_eq!(from_thread.step.value(), 2);
+        from_thread.count.send(3);
+        process_events();
+        assert_eq!(window.get_count(), 3);
+
+        // 창을 다시 만들면 UI 스레드의 첫 호출이 이전 모델을 새 창에 연결하고, 그 뒤로는 다시 어느 스레드에서나 가져옵니다.
+        drop(window);
+        let window = CounterWindow::new().unwrap();
+        window.init_singleton();
+        assert!(std::sync::Arc::ptr_eq(&CounterModel::<CounterWindow>::clone_singleton(), &model));
+        assert_eq!(window.get_count(), 3);
+        let from_thread = std::thread::spawn(CounterModel::<CounterWindow>::clone_singleton).join().unwrap();
+        assert!(std::sync::Arc::ptr_eq(&from_thread, &model));
+        window.invoke_set_step(5);
+        assert_eq!(model.step.value(), 5);
+    });
+}
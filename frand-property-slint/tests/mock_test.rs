@@ -0,0 +1,160 @@
+use std::time::Duration;
+use frand_property::slint::ActionState;
+use frand_property::{slint_model, ChangeStream, ModelChanges, StreamExt};
+
+slint_model! {
+    pub CounterModel: CounterGlobal {
+        in step: i32 = 1,
+        out count: i32,
+        out history: i32[2],
+        computed doubled: i32 = |count| count * 2,
+        callback increment: (),
+        callback label: (i32) -> String,
+        action reset: (i32) -> i32,
+    }
+}
+
+// 창은 만들지 않습니다. 모델의 타입 인자로만 사용합니다.
+// 모의 객체는 Rust 필드만 사용하므로, Slint 선언은 크레이트 루트가 아닌 모듈에 있어도 됩니다.
+mod ui {
+    slint::slint! {
+        export struct CounterGlobalData {
+            step: int,
+            count: int,
+            history: [int],
+            doubled: int,
+            reset-pending: bool,
+            reset-error: string,
+            reset-result: int,
+        }
+
+        export global CounterGlobal {
+            in-out property <[CounterGlobalData]> data: [{ step: 1 }];
+            callback increment(int);
+            pure callback label(int, int) -> string;
+            callback reset(int, int);
+        }
+
+        export component CounterWindow inherits Window {
+            out property <int> count: CounterGlobal.data[0].count;
+        }
+    }
+}
+
+use ui::{CounterGlobal, CounterWindow};
+
+impl CounterModel<CounterWindow> {
+    fn start(&self) {
+        self.label.set_handler(|count| format!("#{count}"));
+
+        let model = self.clone();
+        let mut increment = self.increment.clone();
+        tokio::spawn(async move {
+            loop {
+                increment.notified().await;
+                let previous = model.count.value();
+                model.count.send(previous + model.step.value());
+                model.history[0].send(previous);
+            }
+        });
+
+        let reset = self.reset.clone();
+        let count = self.count.clone();
+        tokio::spawn(async move {
+            while let Some(request) = reset.next().await {
+                let value = request.args;
+                if value < 0 {
+                    request.complete(Err::<i32, _>("negative"));
+                } else {
+                    count.send(value);
+                    request.complete(Ok::<_, String>(value));
+                }
+            }
+        });
+    }
+}
+
+async fn wait_for<T: Clone + PartialEq>(receiver: &frand_property::Receiver<T>, expected: T) {
+    let mut receiver = receiver.clone();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while receiver.value() != expected {
+            receiver.notified().await;
+        }
+    }).await.expect("timed out waiting for the value");
+}
+
+#[tokio::test]
+async fn test_mocked_model_runs_logic_without_ui() {
+    let (model, ui) = CounterModel::<CounterWindow>::mocked();
+    model.start();
+
+    // UI 처럼 `in` 값을 보내고 콜백을 호출합니다.
+    ui.step.send(3);
+    assert_eq!(model.step.value(), 3);
+    ui.increment.notify();
+    wait_for(&ui.count, 3).await;
+    assert_eq!(ui.doubled.value(), 6);
+    assert_eq!(ui.history.iter().map(|value| value.value()).collect::<Vec<_>>(), [0, 0]);
+
+    ui.increment.notify();
+    wait_for(&ui.count, 6).await;
+    wait_for(&ui.history[0], 3).await;
+
+    assert_eq!(ui.label.call(7), "#7");
+}
+
+#[tokio::test]
+async fn test_mocked_model_actions() {
+    let (model, ui) = CounterModel::<CounterWindow>::mocked();
+    model.start();
+
+    assert!(ui.reset.invoke(10));
+    wait_for(&ui.reset_state, Some(ActionState::Done(10))).await;
+    assert_eq!(ui.count.value(), 10);
+
+    assert!(ui.reset.invoke(-1));
+    wait_for(&ui.reset_state, Some(ActionState::Failed("negative".to_string()))).await;
+    assert!(!ui.reset.is_pending());
+}
+
+/// 모의 객체의 타입은 컴포넌트 타입과 관계없습니다.
+fn press_increment(ui: &CounterModelMock) {
+    ui.increment.notify();
+}
+
+#[tokio::test]
+async fn test_mock_type_without_component() {
+    let (model, ui) = CounterModel::<CounterWindow>::mocked();
+    model.start();
+
+    press_increment(&ui);
+    wait_for(&ui.count, 1).await;
+    assert_eq!(model.count.value(), 1);
+}
+
+#[test]
+fn test_mocked_models_are_independent() {
+    let (first, first_ui) = CounterModel::<CounterWindow>::mocked();
+    let (second, _) = CounterModel::<CounterWindow>::mocked();
+
+    first_ui.step.send(5);
+    assert_eq!(first.step.value(), 5);
+    assert_eq!(second.step.value(), 1);
+    // UI 가 없으면 `out` 값은 Rust 쪽에만 남습니다.
+    second.count.send(2);
+    assert_eq!(second.count.value(), 2);
+    assert_eq!(first_ui.count.value(), 0);
+}
+
+/// 제네릭 코드는 `ModelChanges` 트레이트로 변경 스트림을 받습니다.
+fn changes_of<M: ModelChanges>(model: &M) -> ChangeStream<M::Change> {
+    model.changes()
+}
+
+#[tokio::test]
+async fn test_changes_through_trait() {
+    let (model, ui) = CounterModel::<CounterWindow>::mocked();
+    let mut changes = changes_of(&model);
+    ui.step.send(4);
+    assert_eq!(changes.next().await, Some(CounterModelChange::Step(4)));
+}
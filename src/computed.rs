@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex, PoisonError};
+use crate::Sender;
+
+type ComputeFn<T> = Arc<dyn Fn() -> T + Send + Sync>;
+
+/// 소스 `Sender` 로 값이 전송될 때마다 계산 결과를 `target` 으로 다시 보내는 계산 필드 바인딩입니다.
+/// `model!`/`slint_model!` 의 `computed` 필드가 사용합니다.
+pub struct Computed<T, C = ()> {
+    target: Sender<T, C>,
+    compute: ComputeFn<T>,
+    lock: Arc<Mutex<()>>,
+}
+
+impl<T, C: Clone> Clone for Computed<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            target: self.target.clone(),
+            compute: self.compute.clone(),
+            lock: self.lock.clone(),
+        }
+    }
+}
+
+impl<T, C> Computed<T, C> {
+    pub fn new(target: Sender<T, C>, compute: impl Fn() -> T + Send + Sync + 'static) -> Self {
+        Self {
+            target,
+            compute: Arc::new(compute),
+            lock: Arc::default(),
+        }
+    }
+
+    /// 현재 소스 값으로 다시 계산하여 `target` 으로 보냅니다.
+    pub fn recompute(&self) where T: Clone + PartialEq {
+        // 여러 스레드에서 동시에 소스가 바뀌어도 마지막으로 계산한 쪽이 최신 소스 값을 읽도록 계산과 전송을 직렬화합니다.
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        self.target.send((self.compute)());
+    }
+
+    /// `source` 로 값이 전송될 때마다 다시 계산합니다.
+    pub fn watch<S, SC>(&self, source: &Sender<S, SC>)
+    where
+        T: Clone + PartialEq + Send + Sync + 'static,
+        C: Clone + Send + Sync + 'static,
+    {
+        let computed = self.clone();
+        source.on_send(move |_| computed.recompute());
+    }
+}
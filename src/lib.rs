@@ -1,5 +1,5 @@
 #[cfg(feature = "slint")]
-pub use frand_property_macro::slint_model;
+pub use frand_property_macro::{slint_model, slint_enum, slint_struct};
 
 pub use frand_property_macro::model;
 
@@ -11,10 +11,17 @@ pub mod slint;
 
 mod stream;
 mod receiver_group;
+mod sender_group;
+mod computed;
 
 pub use self::{
     property::*,
     model::*,
     stream::*,
     receiver_group::*,
+    sender_group::*,
+    computed::*,
 };
+
+#[doc(hidden)]
+pub use async_trait::async_trait;
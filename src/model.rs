@@ -1,3 +1,5 @@
+use crate::ChangeStream;
+
 pub trait Model: Clone {
     type Sender;
     type Receiver;
@@ -6,6 +8,14 @@ pub trait Model: Clone {
     fn clone_receiver(&self) -> Self::Receiver;
 }
 
+/// 필드 변경을 이벤트로 전달하는 모델입니다. `model!`, `slint_model!` 매크로가 구현합니다.
+pub trait ModelChanges: Model {
+    type Change;
+
+    /// 모델의 모든 필드 변경을 `Self::Change` 이벤트로 전달하는 스트림을 반환합니다.
+    fn changes(&self) -> ChangeStream<Self::Change>;
+}
+
 pub trait ModelList {
     type Sender;
     type Receiver;
@@ -39,3 +49,11 @@ impl<T: Model> Model for std::sync::Arc<T> {
         (**self).clone_receiver()
     }
 }
+
+impl<T: ModelChanges> ModelChanges for std::sync::Arc<T> {
+    type Change = T::Change;
+
+    fn changes(&self) -> ChangeStream<Self::Change> {
+        (**self).changes()
+    }
+}
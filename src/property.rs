@@ -1,7 +1,7 @@
 use std::fmt;
 use std::fmt::Debug;
 use std::future::Future;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tokio_stream::wrappers::WatchStream;
@@ -26,11 +26,15 @@ impl<T: Default + Clone + Send + Sync> Default for Property<T> {
     }
 }
 
+type SetFn<T, C> = Arc<dyn Fn(&C, T) + Send + Sync>;
+type Observers<T> = Arc<Mutex<Vec<Arc<dyn Fn(&T) + Send + Sync>>>>;
+
 pub struct Sender<T, C = ()> {
     component: C,
     sender: watch::Sender<T>,
     receiver: watch::Receiver<T>,
-    set: Arc<dyn Fn(&C, T) + Send + Sync>,
+    set: SetFn<T, C>,
+    observers: Observers<T>,
 }
 
 impl<T, C> Clone for Sender<T, C> where C: Clone {
@@ -40,6 +44,7 @@ impl<T, C> Clone for Sender<T, C> where C: Clone {
             sender: self.sender.clone(),
             receiver: self.receiver.clone(),
             set: self.set.clone(),
+            observers: self.observers.clone(),
         }
     }
 }
@@ -78,6 +83,7 @@ impl<T, C> Property<T, C> {
                 sender: channel.0.clone(),
                 receiver: channel.1.clone(),
                 set: Arc::new(set),
+                observers: Arc::default(),
             },
             receiver: Receiver {
                 receiver: channel.1,
@@ -159,6 +165,16 @@ impl<T> Receiver<T> {
         WatchStream::new(self.receiver.clone())
     }
 
+    /// 현재 값은 건너뛰고, 이후의 변경 사항만 전달하는 스트림을 반환합니다.
+    pub fn changes(&self) -> WatchStream<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let mut receiver = self.receiver.clone();
+        receiver.mark_unchanged();
+        WatchStream::from_changes(receiver)
+    }
+
     pub fn spawn<F, Fut>(&self, handler: F) -> JoinHandle<()>
     where
         T: Clone + Send + Sync + 'static,
@@ -198,11 +214,13 @@ impl<T, C> Sender<T, C> {
 
         (self.set)(&self.component, value.clone());
 
-        self.sender.send(value)
+        self.sender.send(value.clone())
             .unwrap_or_else(|_|
                 // self 가 sender 와 receiver 를 모두 소유하기 때문에 receiver 는 언제나 존재합니다.
                 unreachable!("Receiver is already dropped.")
             );
+
+        self.notify_observers(&value);
     }
 
     pub fn notify(&self) where T: Clone {
@@ -214,15 +232,46 @@ impl<T, C> Sender<T, C> {
     pub fn notify_with(&self, value: T) where T: Clone {
         (self.set)(&self.component, value.clone());
 
-        self.sender.send(value)
+        self.sender.send(value.clone())
             .unwrap_or_else(|_|
                 // self 가 sender 와 receiver 를 모두 소유하기 때문에 receiver 는 언제나 존재합니다.
                 unreachable!("Receiver is already dropped.")
             );
+
+        self.notify_observers(&value);
     }
     pub fn borrow(&self) -> watch::Ref<'_, T> {
         self.receiver.borrow()
     }
+
+    /// 현재 값은 건너뛰고, 이후의 변경 사항만 전달하는 스트림을 반환합니다.
+    pub fn changes(&self) -> WatchStream<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let mut receiver = self.receiver.clone();
+        receiver.mark_unchanged();
+        WatchStream::from_changes(receiver)
+    }
+
+    /// 이 `Sender`(및 복제본)로 값이 전송될 때마다 전송한 스레드에서 동기적으로 호출될 관찰자를 등록합니다.
+    /// 비동기 런타임 없이 값의 변경에 반응해야 할 때 사용합니다.
+    pub fn on_send(&self, observer: impl Fn(&T) + Send + Sync + 'static) {
+        self.observers.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(observer));
+    }
+
+    fn notify_observers(&self, value: &T) {
+        // 관찰자 안에서 다시 관찰자를 등록할 수 있도록 잠금을 해제한 뒤 호출합니다.
+        let observers = self.observers.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        for observer in observers {
+            observer(value);
+        }
+    }
 }
 
 pub trait PropertyList<T, C> {
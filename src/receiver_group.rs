@@ -1,7 +1,10 @@
 
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
 use async_trait::async_trait;
 use tokio::task::JoinHandle;
-use crate::{Receiver, Sender};
+use crate::{Receiver, SenderGroup};
 
 #[async_trait]
 pub trait ReceiverGroup: Clone + Send + 'static {
@@ -11,24 +14,39 @@ pub trait ReceiverGroup: Clone + Send + 'static {
 
     async fn notified(&mut self) -> Self::Item;
 
-    /// 현재 그룹의 변경 사항을 지정된 `Sender`로 바인딩합니다.
-    /// 값이 변경될 때마다 `Sender`로 새로운 값을 보냅니다.
-    fn spawn_bind<C>(&self, sender: Sender<Self::Item, C>) -> JoinHandle<()>
+    /// 현재 그룹의 변경 사항을 지정된 `SenderGroup`(`Sender` 또는 모델의 `{ModelName}Sender`)으로 바인딩합니다.
+    /// 값이 변경될 때마다 `SenderGroup::notify_all`로 새로운 값을 보냅니다.
+    /// 모델끼리 바인딩하면 양쪽에서 읽고 쓸 수 있는 필드(`inout`과 중첩 모델)만 전달되며, `in`/`out` 필드는 그대로 남습니다.
+    fn spawn_bind<S>(&self, senders: S) -> JoinHandle<()>
     where
-        Self::Item: PartialEq,
-        C: Send + Sync + Clone + 'static,
+        S: SenderGroup,
+        Self::Item: Into<S::Item>,
     {
         let mut group = self.clone();
 
         tokio::spawn(async move {
             loop {
                 group.notified().await;
-                sender.notify_with(group.value());
+                senders.notify_all(group.value().into());
             }
         })
     }
 }
 
+/// 주어진 `Future` 중 하나가 완료될 때까지 대기합니다.
+/// `model!` 매크로가 생성하는 `ReceiverGroup` 구현에서 사용됩니다.
+#[doc(hidden)]
+pub async fn notified_any(mut futures: Vec<Pin<Box<dyn Future<Output = ()> + Send + '_>>>) {
+    std::future::poll_fn(|cx| {
+        for future in futures.iter_mut() {
+            if future.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+        }
+        Poll::Pending
+    }).await
+}
+
 #[async_trait]
 impl<T: Clone + Send + Sync + 'static> ReceiverGroup for Receiver<T> {
     type Item = T;
@@ -0,0 +1,30 @@
+use crate::Sender;
+
+pub trait SenderGroup: Clone + Send + Sync + 'static {
+    type Item;
+
+    /// 그룹에 속한 모든 `Sender`로 값을 보냅니다.
+    fn send_all(&self, values: Self::Item);
+
+    /// 값이 같더라도 알림이 필요한 `Sender`에는 알림을 보내며 모든 값을 보냅니다.
+    /// `ReceiverGroup::spawn_bind`에서 사용되며, 기본 구현은 `send_all`과 같습니다.
+    fn notify_all(&self, values: Self::Item) {
+        self.send_all(values);
+    }
+}
+
+impl<T, C> SenderGroup for Sender<T, C>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+    C: Send + Sync + Clone + 'static,
+{
+    type Item = T;
+
+    fn send_all(&self, values: Self::Item) {
+        self.send(values);
+    }
+
+    fn notify_all(&self, values: Self::Item) {
+        self.notify_with(values);
+    }
+}
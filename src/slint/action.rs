@@ -0,0 +1,152 @@
+use std::fmt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+use tokio_stream::Stream;
+
+/// `SlintAction` 의 진행 상태입니다. 상태가 바뀔 때마다 관찰자에게 전달됩니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionState<R> {
+    /// 요청이 접수되어 핸들러가 처리 중입니다.
+    Pending,
+    /// 핸들러가 결과를 반환했습니다.
+    Done(R),
+    /// 핸들러가 실패했거나 요청이 완료되지 않고 버려졌습니다.
+    Failed(String),
+}
+
+type Observer<R> = Arc<dyn Fn(&ActionState<R>) + Send + Sync>;
+
+struct ActionShared<R> {
+    pending: AtomicBool,
+    observer: Observer<R>,
+}
+
+impl<R> ActionShared<R> {
+    /// 결과를 알린 뒤에 처리 중 상태를 해제합니다.
+    /// 순서가 반대이면 해제 직후 접수된 다음 요청의 `Pending` 이 이 결과보다 먼저 전달될 수 있습니다.
+    fn finish(&self, state: ActionState<R>) {
+        (self.observer)(&state);
+        self.pending.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Slint 에서 시작하는 비동기 작업의 요청 큐입니다.
+/// `slint_model!` 의 `action name: (Args) -> Ret` 필드가 사용합니다.
+///
+/// 요청이 처리 중인 동안에는 새로운 요청을 거부합니다.
+/// Rust 는 `next()` 또는 `Stream` 으로 요청을 받아 처리하고 `ActionRequest::complete` 로 결과를 알립니다.
+/// 요청은 하나의 태스크에서 처리해야 합니다.
+pub struct SlintAction<A, R> {
+    shared: Arc<ActionShared<R>>,
+    sender: mpsc::UnboundedSender<ActionRequest<A, R>>,
+    requests: Arc<Mutex<mpsc::UnboundedReceiver<ActionRequest<A, R>>>>,
+}
+
+impl<A, R> Clone for SlintAction<A, R> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            sender: self.sender.clone(),
+            requests: self.requests.clone(),
+        }
+    }
+}
+
+impl<A, R> fmt::Debug for SlintAction<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlintAction")
+            .field("pending", &self.is_pending())
+            .finish()
+    }
+}
+
+impl<A, R> SlintAction<A, R> {
+    /// 상태가 바뀔 때마다 `observer` 를 호출하는 요청 큐를 만듭니다.
+    pub fn new(observer: impl Fn(&ActionState<R>) + Send + Sync + 'static) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        Self {
+            shared: Arc::new(ActionShared {
+                pending: AtomicBool::new(false),
+                observer: Arc::new(observer),
+            }),
+            sender,
+            requests: Arc::new(Mutex::new(receiver)),
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.shared.pending.load(Ordering::SeqCst)
+    }
+
+    /// 요청을 큐에 넣습니다. 이전 요청이 처리 중이면 거부하고 `false` 를 반환합니다.
+    pub fn invoke(&self, args: A) -> bool {
+        if self.shared.pending.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+
+        (self.shared.observer)(&ActionState::Pending);
+
+        // 수신자는 `self` 가 함께 가지고 있으므로 전송은 실패하지 않습니다.
+        let _ = self.sender.send(ActionRequest {
+            args,
+            shared: Some(self.shared.clone()),
+        });
+        true
+    }
+
+    /// 다음 요청을 기다립니다.
+    pub async fn next(&self) -> Option<ActionRequest<A, R>> {
+        std::future::poll_fn(|cx| self.poll_request(cx)).await
+    }
+
+    fn poll_request(&self, cx: &mut Context<'_>) -> Poll<Option<ActionRequest<A, R>>> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner).poll_recv(cx)
+    }
+}
+
+impl<A, R> Stream for SlintAction<A, R> {
+    type Item = ActionRequest<A, R>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_request(cx)
+    }
+}
+
+/// `SlintAction` 에 접수된 요청입니다.
+/// `complete` 를 호출하지 않고 버리면 요청은 실패로 처리됩니다.
+pub struct ActionRequest<A, R> {
+    pub args: A,
+    shared: Option<Arc<ActionShared<R>>>,
+}
+
+impl<A: fmt::Debug, R> fmt::Debug for ActionRequest<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionRequest")
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl<A, R> ActionRequest<A, R> {
+    /// 처리 결과를 알리고 처리 중 상태를 해제합니다.
+    pub fn complete<E: fmt::Display>(mut self, result: Result<R, E>) {
+        if let Some(shared) = self.shared.take() {
+            shared.finish(match result {
+                Ok(value) => ActionState::Done(value),
+                Err(err) => ActionState::Failed(err.to_string()),
+            });
+        }
+    }
+}
+
+impl<A, R> Drop for ActionRequest<A, R> {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.take() {
+            shared.finish(ActionState::Failed("action request was dropped before completion".to_string()));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+
+type Handler<A, R> = Arc<dyn Fn(A) -> R + Send + Sync>;
+
+/// Slint 에서 호출하고 결과를 돌려받는 콜백의 Rust 핸들러 슬롯입니다.
+/// `slint_model!` 의 `callback name: (Args) -> Ret` 필드가 사용합니다.
+///
+/// 핸들러는 Slint 이벤트 루프에서 동기적으로 호출됩니다.
+pub struct SlintCallback<A, R> {
+    handler: Arc<Mutex<Option<Handler<A, R>>>>,
+}
+
+impl<A, R> Clone for SlintCallback<A, R> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl<A, R> Default for SlintCallback<A, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, R> fmt::Debug for SlintCallback<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlintCallback")
+            .field("has_handler", &self.has_handler())
+            .finish()
+    }
+}
+
+impl<A, R> SlintCallback<A, R> {
+    pub fn new() -> Self {
+        Self {
+            handler: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// 핸들러를 등록합니다. 이전에 등록된 핸들러는 교체됩니다.
+    pub fn set_handler(&self, handler: impl Fn(A) -> R + Send + Sync + 'static) {
+        *self.handler.lock().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(handler));
+    }
+
+    pub fn clear_handler(&self) {
+        *self.handler.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    pub fn has_handler(&self) -> bool {
+        self.handler.lock().unwrap_or_else(PoisonError::into_inner).is_some()
+    }
+
+    /// 등록된 핸들러를 호출합니다. 핸들러가 없으면 `R::default()`를 반환합니다.
+    pub fn call(&self, args: A) -> R where R: Default {
+        // 핸들러 안에서 핸들러를 교체할 수 있도록 잠금을 해제한 뒤 호출합니다.
+        let handler = self.handler.lock().unwrap_or_else(PoisonError::into_inner).clone();
+
+        match handler {
+            Some(handler) => handler(args),
+            None => R::default(),
+        }
+    }
+}
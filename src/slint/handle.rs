@@ -0,0 +1,113 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use slint;
+use crate::slint::SlintRowWriter;
+
+/// 바꿔 끼울 수 있는 `slint::Weak` 입니다.
+/// `slint_model!` 의 `Sender` 가 이 핸들로 UI 를 갱신하므로, 창을 다시 만들어도 같은 `Sender` 를 계속 사용할 수 있습니다.
+pub struct SlintHandle<C: slint::ComponentHandle> {
+    weak: Arc<Mutex<slint::Weak<C>>>,
+}
+
+impl<C: slint::ComponentHandle> Clone for SlintHandle<C> {
+    fn clone(&self) -> Self {
+        Self {
+            weak: self.weak.clone(),
+        }
+    }
+}
+
+impl<C: slint::ComponentHandle> fmt::Debug for SlintHandle<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlintHandle").finish_non_exhaustive()
+    }
+}
+
+impl<C: slint::ComponentHandle> SlintHandle<C> {
+    pub fn new(weak: slint::Weak<C>) -> Self {
+        Self {
+            weak: Arc::new(Mutex::new(weak)),
+        }
+    }
+
+    /// 현재 연결된 컴포넌트의 `slint::Weak` 입니다.
+    pub fn weak(&self) -> slint::Weak<C> {
+        self.weak.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// 이 핸들(및 복제본)이 가리키는 컴포넌트를 바꿉니다.
+    pub fn set(&self, weak: slint::Weak<C>) {
+        *self.weak.lock().unwrap_or_else(PoisonError::into_inner) = weak;
+    }
+
+    pub fn upgrade(&self) -> Option<C> {
+        self.weak().upgrade()
+    }
+
+    /// 현재 연결된 컴포넌트로 `slint::Weak::upgrade_in_event_loop` 를 호출합니다.
+    /// 컴포넌트가 해제되었으면 `func` 는 호출되지 않습니다.
+    pub fn upgrade_in_event_loop(&self, func: impl FnOnce(C) + Send + 'static) -> Result<(), slint::EventLoopError>
+    where
+        C: 'static,
+    {
+        self.weak().upgrade_in_event_loop(func)
+    }
+}
+
+type InstallFn<C> = Box<dyn Fn(&C) + Send + Sync>;
+
+/// `slint_model!` 모델과 컴포넌트 인스턴스의 연결입니다. 배열 모델의 모든 행이 공유합니다.
+///
+/// 연결 함수는 Global 에 데이터 모델을 설치하고 콜백을 등록합니다.
+/// `attach` 로 새 인스턴스에 다시 연결하면, 모델의 현재 값으로 UI 를 채웁니다.
+pub struct SlintBinding<C: slint::ComponentHandle> {
+    handle: SlintHandle<C>,
+    writer: SlintRowWriter<C>,
+    install: Arc<OnceLock<InstallFn<C>>>,
+}
+
+impl<C: slint::ComponentHandle> Clone for SlintBinding<C> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+            writer: self.writer.clone(),
+            install: self.install.clone(),
+        }
+    }
+}
+
+impl<C: slint::ComponentHandle> fmt::Debug for SlintBinding<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlintBinding").finish_non_exhaustive()
+    }
+}
+
+impl<C: slint::ComponentHandle + 'static> SlintBinding<C> {
+    pub fn new(handle: SlintHandle<C>) -> Self {
+        Self {
+            writer: SlintRowWriter::new(handle.clone()),
+            handle,
+            install: Arc::default(),
+        }
+    }
+
+    pub fn handle(&self) -> &SlintHandle<C> {
+        &self.handle
+    }
+
+    /// Rust 에서 보낸 값을 데이터 행에 쓰는 `SlintRowWriter` 입니다.
+    pub fn writer(&self) -> &SlintRowWriter<C> {
+        &self.writer
+    }
+
+    /// `component` 에 연결합니다. 핸들을 `component` 로 바꾸고 연결 함수를 실행합니다.
+    /// 연결 함수는 처음 연결할 때 `install` 로 만들고, 이후에는 다시 사용합니다.
+    pub fn attach<F>(&self, component: &C, install: impl FnOnce() -> F)
+    where
+        F: Fn(&C) + Send + Sync + 'static,
+    {
+        self.handle.set(component.as_weak());
+        let install = self.install.get_or_init(|| Box::new(install()));
+        install(component);
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+/// Rust 정수와 Slint `int`(`i32`), `Duration` 과 Slint `duration`(밀리초, `i64`) 사이의 변환에서 범위를 벗어난 값을 처리하는 방식입니다.
+/// `slint_model!` 필드의 `#[frand(overflow = "...")]` 로 지정하며, 기본값은 `Saturate` 입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// 대상 타입의 최솟값/최댓값으로 고정합니다.
+    #[default]
+    Saturate,
+    /// 비트를 잘라 감쌉니다. (`as` 변환)
+    Wrap,
+    /// 값을 버리고 오류 핸들러에 알립니다. 대상 값은 바뀌지 않습니다.
+    Reject,
+}
+
+/// `Overflow::Reject` 로 버려진 값의 정보입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// 값을 변환하려던 필드 이름
+    pub field: &'static str,
+    /// 범위를 벗어난 값
+    pub value: String,
+    /// 변환 대상 타입
+    pub target: &'static str,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value {} of field `{}` is out of range for `{}`", self.value, self.field, self.target)
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+type OverflowHandler = Arc<dyn Fn(&OverflowError) + Send + Sync>;
+
+static OVERFLOW_HANDLER: RwLock<Option<OverflowHandler>> = RwLock::new(None);
+
+/// `Overflow::Reject` 로 값이 버려질 때 호출할 핸들러를 등록합니다. 이전 핸들러는 교체됩니다.
+pub fn set_overflow_handler(handler: impl Fn(&OverflowError) + Send + Sync + 'static) {
+    *OVERFLOW_HANDLER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(handler));
+}
+
+fn report_overflow(field: &'static str, value: impl fmt::Display, target: &'static str) {
+    let handler = OVERFLOW_HANDLER.read().unwrap_or_else(PoisonError::into_inner).clone();
+
+    if let Some(handler) = handler {
+        handler(&OverflowError { field, value: value.to_string(), target });
+    }
+}
+
+/// Slint `int` 와 변환할 수 있는 Rust 정수 타입입니다.
+pub trait SlintInt: Copy + fmt::Display + Sized {
+    fn to_i32(self, overflow: Overflow) -> Option<i32>;
+    fn from_i32(value: i32, overflow: Overflow) -> Option<Self>;
+}
+
+macro_rules! impl_slint_int {
+    ($($ty:ty),*) => {
+        $(
+            impl SlintInt for $ty {
+                fn to_i32(self, overflow: Overflow) -> Option<i32> {
+                    match i32::try_from(self) {
+                        Ok(value) => Some(value),
+                        Err(_) => match overflow {
+                            Overflow::Saturate => Some(if (self as i128) < 0 { i32::MIN } else { i32::MAX }),
+                            Overflow::Wrap => Some(self as i32),
+                            Overflow::Reject => None,
+                        },
+                    }
+                }
+
+                fn from_i32(value: i32, overflow: Overflow) -> Option<Self> {
+                    match <$ty>::try_from(value) {
+                        Ok(value) => Some(value),
+                        Err(_) => match overflow {
+                            Overflow::Saturate => Some(if value < 0 { <$ty>::MIN } else { <$ty>::MAX }),
+                            Overflow::Wrap => Some(value as $ty),
+                            Overflow::Reject => None,
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_slint_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Rust 정수를 Slint `int` 로 변환합니다. `Overflow::Reject` 로 버려지면 `None` 을 반환합니다.
+pub fn to_slint_int<T: SlintInt>(value: T, overflow: Overflow, field: &'static str) -> Option<i32> {
+    let converted = value.to_i32(overflow);
+    if converted.is_none() {
+        report_overflow(field, value, "int");
+    }
+    converted
+}
+
+/// Slint `int` 를 Rust 정수로 변환합니다. `Overflow::Reject` 로 버려지면 `None` 을 반환합니다.
+pub fn from_slint_int<T: SlintInt>(value: i32, overflow: Overflow, field: &'static str) -> Option<T> {
+    let converted = T::from_i32(value, overflow);
+    if converted.is_none() {
+        report_overflow(field, value, std::any::type_name::<T>());
+    }
+    converted
+}
+
+/// `Duration` 을 Slint `duration`(밀리초, `i64`)으로 변환합니다. `Overflow::Reject` 로 버려지면 `None` 을 반환합니다.
+pub fn to_slint_duration(value: Duration, overflow: Overflow, field: &'static str) -> Option<i64> {
+    let millis = value.as_millis();
+    match i64::try_from(millis) {
+        Ok(millis) => Some(millis),
+        Err(_) => match overflow {
+            Overflow::Saturate => Some(i64::MAX),
+            Overflow::Wrap => Some(millis as i64),
+            Overflow::Reject => {
+                report_overflow(field, millis, "duration");
+                None
+            }
+        },
+    }
+}
+
+/// Slint `duration`(밀리초)을 `Duration` 으로 변환합니다. 음수는 `Duration` 의 범위를 벗어난 값으로 처리합니다.
+pub fn from_slint_duration(value: i64, overflow: Overflow, field: &'static str) -> Option<Duration> {
+    match u64::try_from(value) {
+        Ok(millis) => Some(Duration::from_millis(millis)),
+        Err(_) => match overflow {
+            Overflow::Saturate => Some(Duration::ZERO),
+            Overflow::Wrap => Some(Duration::from_millis(value as u64)),
+            Overflow::Reject => {
+                report_overflow(field, value, std::any::type_name::<Duration>());
+                None
+            }
+        },
+    }
+}
@@ -1,7 +1,24 @@
 mod notify_model;
 mod singleton;
+mod registry;
+mod handle;
+mod row_writer;
+mod callback;
+mod action;
+mod types;
+mod int;
+
+#[cfg(feature = "slint-testing")]
+pub mod testing;
 
 pub use self::{
     notify_model::*,
     singleton::*,
+    registry::*,
+    handle::*,
+    row_writer::*,
+    callback::*,
+    action::*,
+    types::*,
+    int::*,
 };
\ No newline at end of file
@@ -1,6 +1,10 @@
+use std::cmp::Ordering;
 use std::rc::Rc;
-use slint::{Model, ModelNotify, ModelTracker, VecModel};
+use slint::{FilterModel, Model, ModelNotify, ModelTracker, SortModel, VecModel};
 
+/// UI 에서 바뀐 행을 `on_change` 로 알리는 Slint 모델입니다.
+///
+/// 행의 추가, 삭제, 교체는 이 모델의 메서드로 해야 UI 에 알려집니다. (`inner` 를 직접 바꾸면 UI 가 알 수 없습니다.)
 pub struct SlintNotifyModel<T> {
     inner: Rc<VecModel<T>>,
     notify: ModelNotify,
@@ -17,6 +21,81 @@ impl<T> SlintNotifyModel<T> {
     }
 }
 
+impl<T: Clone + 'static> SlintNotifyModel<T> {
+    pub fn from_vec(values: Vec<T>, on_change: impl Fn(usize, T) + 'static) -> Self {
+        Self::new(Rc::new(VecModel::from(values)), on_change)
+    }
+
+    /// 끝에 행을 추가합니다.
+    pub fn push(&self, value: T) {
+        self.inner.push(value);
+        self.notify.row_added(self.inner.row_count() - 1, 1);
+    }
+
+    /// `index` 위치에 행을 끼워 넣습니다. `index` 가 행 수보다 크면 패닉합니다.
+    pub fn insert(&self, index: usize, value: T) {
+        self.inner.insert(index, value);
+        self.notify.row_added(index, 1);
+    }
+
+    /// `index` 위치의 행을 지우고 반환합니다. `index` 가 범위를 벗어나면 패닉합니다.
+    pub fn remove(&self, index: usize) -> T {
+        let value = self.inner.remove(index);
+        self.notify.row_removed(index, 1);
+        value
+    }
+
+    /// 모든 행을 `values` 로 바꿉니다.
+    pub fn set_vec(&self, values: impl Into<Vec<T>>) {
+        self.inner.set_vec(values);
+        self.notify.reset();
+    }
+
+    pub fn clear(&self) {
+        self.set_vec(Vec::new());
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> SlintNotifyModel<T> {
+    /// Rust 에서 바꾼 행을 씁니다. UI 에는 알리지만 `on_change` 는 호출하지 않습니다.
+    pub fn set_row_data_from_rust(&self, row: usize, data: T) {
+        if self.inner.row_data(row).is_none_or(|current| current == data) {
+            return;
+        }
+
+        self.inner.set_row_data(row, data);
+        self.notify.row_changed(row);
+    }
+
+    /// `compare` 순서로 정렬한 뷰입니다. 뷰에서 바뀐 행은 원래 인덱스로 `on_change` 에 전달됩니다.
+    /// 뷰의 행 인덱스는 `SortModel::unsorted_row` 로 원래 인덱스로 바꿀 수 있습니다.
+    pub fn sorted_by<F>(self: Rc<Self>, compare: F) -> SortModel<Rc<Self>, F>
+    where
+        F: FnMut(&T, &T) -> Ordering + 'static,
+    {
+        SortModel::new(self, compare)
+    }
+
+    /// `filter` 가 참인 행만 보여주는 뷰입니다. 뷰에서 바뀐 행은 원래 인덱스로 `on_change` 에 전달됩니다.
+    /// 뷰의 행 인덱스는 `FilterModel::unfiltered_row` 로 원래 인덱스로 바꿀 수 있습니다.
+    pub fn filtered<F>(self: Rc<Self>, filter: F) -> FilterModel<Rc<Self>, F>
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        FilterModel::new(self, filter)
+    }
+
+    /// 행마다 `map` 으로 바꾼 값을 보여주는 뷰입니다.
+    /// 뷰에서 바뀐 값은 `unmap(원래 행, 바뀐 값)` 으로 원래 행에 반영되어 같은 인덱스로 `on_change` 에 전달됩니다.
+    pub fn mapped<U, F, G>(self: Rc<Self>, map: F, unmap: G) -> SlintMapModel<Rc<Self>, F, G>
+    where
+        F: Fn(T) -> U + 'static,
+        G: Fn(T, U) -> T + 'static,
+    {
+        SlintMapModel::new(self, map, unmap)
+    }
+}
+
 impl<T: Clone + 'static + PartialEq> Model for SlintNotifyModel<T> {
     type Data = T;
 
@@ -49,3 +128,53 @@ impl<T: Clone + 'static + PartialEq> Model for SlintNotifyModel<T> {
         self
     }
 }
+
+/// 값을 양방향으로 바꾸는 모델 어댑터입니다. `slint::MapModel` 과 달리 뷰에서 쓴 값을 원래 모델에 반영합니다.
+/// 행 인덱스는 원래 모델과 같습니다.
+pub struct SlintMapModel<M, F, G> {
+    source: M,
+    map: F,
+    unmap: G,
+}
+
+impl<M, F, G> SlintMapModel<M, F, G> {
+    pub fn new(source: M, map: F, unmap: G) -> Self {
+        Self { source, map, unmap }
+    }
+
+    pub fn source_model(&self) -> &M {
+        &self.source
+    }
+}
+
+impl<M, F, G, U> Model for SlintMapModel<M, F, G>
+where
+    M: Model + 'static,
+    F: Fn(M::Data) -> U + 'static,
+    G: Fn(M::Data, U) -> M::Data + 'static,
+    U: 'static,
+{
+    type Data = U;
+
+    fn row_count(&self) -> usize {
+        self.source.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.source.row_data(row).map(&self.map)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if let Some(current) = self.source.row_data(row) {
+            self.source.set_row_data(row, (self.unmap)(current, data));
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.source.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
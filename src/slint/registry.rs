@@ -0,0 +1,201 @@
+use std::any::{Any, TypeId};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use slint;
+use super::singleton::{registered_singleton, SlintSingleton};
+
+/// 컴포넌트 인스턴스마다 만든 모델입니다.
+struct Binding {
+    model: TypeId,
+    instance: usize,
+    /// 인스턴스가 아직 살아 있는지 확인합니다.
+    alive: Box<dyn Fn() -> bool + Send + Sync>,
+    value: Box<dyn Any + Send + Sync>,
+}
+
+static BINDINGS: OnceLock<Mutex<Vec<Binding>>> = OnceLock::new();
+
+fn bindings() -> std::sync::MutexGuard<'static, Vec<Binding>> {
+    BINDINGS.get_or_init(|| Mutex::new(Vec::new())).lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// 컴포넌트 인스턴스의 식별자. 인스턴스가 살아 있는 동안 바뀌지 않는 `Window` 의 주소입니다.
+fn instance_of<C: slint::ComponentHandle>(component: &C) -> usize {
+    component.window() as *const slint::Window as usize
+}
+
+/// 닫힌(해제된) 컴포넌트의 모델을 버립니다.
+/// `slint::Weak` 은 컴포넌트를 만든 스레드에서만 살아 있는지 알 수 있으므로, UI 스레드에서 호출해야 합니다.
+fn prune(bindings: &mut Vec<Binding>) -> Vec<Binding> {
+    let (alive, dead) = std::mem::take(bindings).into_iter().partition(|binding| (binding.alive)());
+    *bindings = alive;
+    dead
+}
+
+/// `component` 에 바인딩된 `M` 을 반환합니다. 없으면 `build` 로 만들어 등록합니다.
+///
+/// 같은 컴포넌트 타입의 창을 여러 개 열어도 창마다 다른 모델을 가지며,
+/// 창이 해제되면 다음 바인딩이나 `release_models` 에서 모델을 버립니다.
+/// 해제된 창은 컴포넌트를 만든 스레드에서만 알 수 있으므로, `component` 를 가진 UI 스레드에서 호출해야 합니다.
+pub fn bind_model<C, M>(component: &C, build: impl FnOnce() -> M) -> M
+where
+    C: slint::ComponentHandle + 'static,
+    M: Clone + Send + Sync + 'static,
+{
+    let model = TypeId::of::<M>();
+    let instance = instance_of(component);
+
+    let dead = {
+        let mut bindings = bindings();
+        let dead = prune(&mut bindings);
+        if let Some(binding) = bindings.iter().find(|b| b.model == model && b.instance == instance) {
+            return binding.value.downcast_ref::<M>().expect("Type mismatch in model registry").clone();
+        }
+        dead
+    };
+    // 모델의 Drop 이 다른 모델을 다루더라도 잠금과 겹치지 않도록 잠금 밖에서 버립니다.
+    drop(dead);
+
+    // 모델을 만드는 동안 다른 모델을 바인딩할 수 있으므로 잠그지 않습니다.
+    let value = build();
+
+    let weak = component.as_weak();
+    let mut bindings = bindings();
+    if let Some(binding) = bindings.iter().find(|b| b.model == model && b.instance == instance) {
+        return binding.value.downcast_ref::<M>().expect("Type mismatch in model registry").clone();
+    }
+    bindings.push(Binding {
+        model,
+        instance,
+        alive: Box::new(move || weak.upgrade().is_some()),
+        value: Box::new(value.clone()),
+    });
+    value
+}
+
+/// `value` 를 `component` 에 바인딩된 `M` 으로 등록합니다. 이미 바인딩된 `M` 이 있으면 바꿉니다.
+/// 다른 인스턴스에서 만든 모델을 다시 연결한 뒤 호출합니다. `bind_model` 과 같이 UI 스레드에서 호출해야 합니다.
+pub fn register_model<C, M>(component: &C, value: M)
+where
+    C: slint::ComponentHandle + 'static,
+    M: Send + Sync + 'static,
+{
+    let model = TypeId::of::<M>();
+    let instance = instance_of(component);
+    let weak = component.as_weak();
+
+    let replaced = {
+        let mut bindings = bindings();
+        let mut replaced = prune(&mut bindings);
+        if let Some(index) = bindings.iter().position(|b| b.model == model && b.instance == instance) {
+            replaced.push(bindings.remove(index));
+        }
+        bindings.push(Binding {
+            model,
+            instance,
+            alive: Box::new(move || weak.upgrade().is_some()),
+            value: Box::new(value),
+        });
+        replaced
+    };
+    drop(replaced);
+}
+
+/// `init_singleton` 으로 등록한 컴포넌트의 모델입니다. 컴포넌트가 해제되어도 버리지 않습니다.
+struct Singleton {
+    model: TypeId,
+    instance: usize,
+    /// 모델을 바인딩한 컴포넌트가 `init_singleton` 으로 등록될 때 받은 번호입니다.
+    generation: Option<u64>,
+    alive: Box<dyn Fn() -> bool + Send + Sync>,
+    value: Box<dyn Any + Send + Sync>,
+}
+
+static SINGLETONS: OnceLock<Mutex<Vec<Singleton>>> = OnceLock::new();
+
+/// `init_singleton` 으로 등록한 `C` 의 `M` 을 반환합니다.
+///
+/// 등록한 창에 이미 바인딩된 모델이 있으면 컴포넌트를 꺼내지 않고 반환하므로, UI 스레드가 아닌 곳에서도 호출할 수 있습니다.
+/// 처음 호출하거나 `init_singleton` 으로 새 창을 등록한 뒤에는 `bind_singleton_model` 로 바인딩하므로 UI 스레드에서 호출해야 합니다.
+pub fn singleton_model<C, M>(bind: impl FnOnce(&C) -> M, attach: impl FnOnce(&M, &C)) -> M
+where
+    C: SlintSingleton,
+    M: Clone + Send + Sync + 'static,
+{
+    let (weak, generation) = registered_singleton::<C>().expect("Singleton not initialized. Call init_singleton() first.");
+    if let Some(singletons) = SINGLETONS.get() {
+        let singletons = singletons.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(singleton) = singletons.iter().find(|s| s.model == TypeId::of::<M>() && s.generation == Some(generation)) {
+            return singleton.value.downcast_ref::<M>().expect("Type mismatch in singleton store").clone();
+        }
+    }
+
+    let component = weak.upgrade().expect("Failed to upgrade singleton instance. Bind the singleton model on the UI thread first.");
+    bind_singleton_model(&component, || bind(&component), |models| attach(models, &component))
+}
+
+/// 싱글톤 컴포넌트 `component` 의 `M` 을 반환합니다. UI 스레드에서 호출해야 합니다.
+///
+/// 처음이면 `bind` 로 만듭니다. 이전 싱글톤 컴포넌트가 해제된 뒤 다시 만들어졌으면,
+/// 이전 모델을 `attach` 로 새 컴포넌트에 다시 연결하여 같은 `Sender`/`Receiver` 를 계속 사용합니다.
+pub fn bind_singleton_model<C, M>(component: &C, bind: impl FnOnce() -> M, attach: impl FnOnce(&M)) -> M
+where
+    C: slint::ComponentHandle + 'static,
+    M: Clone + Send + Sync + 'static,
+{
+    let model = TypeId::of::<M>();
+    let instance = instance_of(component);
+    let singletons = SINGLETONS.get_or_init(|| Mutex::new(Vec::new()));
+
+    let previous = {
+        let mut singletons = singletons.lock().unwrap_or_else(PoisonError::into_inner);
+        match singletons.iter().position(|s| s.model == model) {
+            Some(index) if singletons[index].instance == instance && (singletons[index].alive)() => {
+                return singletons[index].value.downcast_ref::<M>().expect("Type mismatch in singleton store").clone();
+            }
+            // 살아 있는 다른 창의 모델은 그 창에 남겨 두고 새로 만듭니다.
+            Some(index) if (singletons[index].alive)() => None,
+            Some(index) => singletons.remove(index).value.downcast::<M>().ok().map(|value| *value),
+            None => None,
+        }
+    };
+
+    // 모델을 만들거나 연결하는 동안에는 잠그지 않습니다.
+    let value = match previous {
+        Some(value) => {
+            attach(&value);
+            value
+        }
+        None => bind(),
+    };
+
+    // `component` 가 등록된 싱글톤이면 번호를 기록하여, 다음부터 `singleton_model` 이 컴포넌트 없이 찾게 합니다.
+    let generation = registered_singleton::<C>()
+        .filter(|(weak, _)| weak.upgrade().is_some_and(|registered| instance_of(&registered) == instance))
+        .map(|(_, generation)| generation);
+
+    let weak = component.as_weak();
+    let mut singletons = singletons.lock().unwrap_or_else(PoisonError::into_inner);
+    singletons.retain(|s| s.model != model);
+    singletons.push(Singleton {
+        model,
+        instance,
+        generation,
+        alive: Box::new(move || weak.upgrade().is_some()),
+        value: Box::new(value.clone()),
+    });
+    value
+}
+
+/// `component` 에 바인딩된 모든 모델을 버립니다. 창을 닫은 뒤 모델을 바로 정리할 때 사용합니다.
+pub fn release_models<C: slint::ComponentHandle + 'static>(component: &C) {
+    let instance = instance_of(component);
+    let released = {
+        let mut bindings = bindings();
+        let mut released = prune(&mut bindings);
+        let (kept, removed): (Vec<_>, Vec<_>) = std::mem::take(&mut *bindings).into_iter().partition(|b| b.instance != instance);
+        *bindings = kept;
+        released.extend(removed);
+        released
+    };
+    drop(released);
+}
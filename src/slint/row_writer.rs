@@ -0,0 +1,114 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use slint::Model;
+use crate::slint::{SlintHandle, SlintNotifyModel};
+
+/// 행에 쓸 (필드 번호, 값) 입니다.
+type RowWrite = (usize, Box<dyn Any + Send>);
+type PendingWrites = Arc<Mutex<BTreeMap<usize, Vec<RowWrite>>>>;
+type FlushFn<C> = Box<dyn Fn(&C, BTreeMap<usize, Vec<RowWrite>>) + Send + Sync>;
+
+/// Rust 에서 Slint 데이터 행(`data`)에 쓰는 값을 모았다가, 이벤트 루프에서 한 번에 씁니다.
+///
+/// 여러 필드나 배열 요소에 연달아 값을 보내도 이벤트 루프 호출은 한 번이고, 행은 한 번만 다시 씁니다.
+/// 행은 `SlintNotifyModel::set_row_data_from_rust` 로 쓰므로 `on_change`(`in` 필드의 비교)를 거치지 않습니다.
+///
+/// 값은 필드 번호와 함께 모으고, 데이터 행의 타입은 `set_target` 으로 정합니다.
+/// 그래서 Slint Global 없이 만든 모델(`mocked`)도 같은 쓰기 경로를 사용하며, 컴포넌트에 연결하기 전의 쓰기는 버립니다.
+pub struct SlintRowWriter<C: slint::ComponentHandle> {
+    handle: SlintHandle<C>,
+    target: Arc<OnceLock<FlushFn<C>>>,
+    pending: PendingWrites,
+}
+
+impl<C: slint::ComponentHandle> Clone for SlintRowWriter<C> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+            target: self.target.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+impl<C: slint::ComponentHandle> fmt::Debug for SlintRowWriter<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlintRowWriter").finish_non_exhaustive()
+    }
+}
+
+impl<C: slint::ComponentHandle + 'static> SlintRowWriter<C> {
+    pub fn new(handle: SlintHandle<C>) -> Self {
+        Self {
+            handle,
+            target: Arc::default(),
+            pending: Arc::default(),
+        }
+    }
+
+    /// 쓰기를 반영할 데이터 모델을 정합니다. 처음 호출한 것만 사용합니다.
+    ///
+    /// `rows` 는 컴포넌트에서 데이터 모델(Global 의 `data`)을 가져오는 함수이고,
+    /// `apply` 는 `write` 로 모은 (필드 번호, 값) 을 행에 씁니다. 행을 다시 써야 하면 `true` 를 반환합니다.
+    /// (행 안의 모델만 바꾸었다면 `false` 를 반환합니다.)
+    pub fn set_target<D: Clone + PartialEq + 'static>(
+        &self,
+        rows: fn(&C) -> slint::ModelRc<D>,
+        apply: fn(&mut D, usize, Box<dyn Any + Send>) -> bool,
+    ) {
+        let _ = self.target.set(Box::new(move |component, writes| flush(&rows(component), writes, apply)));
+    }
+
+    /// `row` 행의 `field` 필드에 `value` 쓰기를 예약합니다. 예약된 쓰기는 다음 이벤트 루프 호출에서 순서대로 실행됩니다.
+    pub fn write(&self, row: usize, field: usize, value: impl Any + Send) {
+        let schedule = {
+            let mut pending = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+            let schedule = pending.is_empty();
+            pending.entry(row).or_default().push((field, Box::new(value)));
+            schedule
+        };
+        if !schedule {
+            return;
+        }
+
+        let handle = self.handle.clone();
+        let target = self.target.clone();
+        let pending = self.pending.clone();
+        let scheduled = slint::invoke_from_event_loop(move || {
+            let writes = std::mem::take(&mut *pending.lock().unwrap_or_else(PoisonError::into_inner));
+            // 창이 닫혔으면 값은 Sender 에 남아 있다가 다시 연결할 때 반영됩니다.
+            if let (Some(component), Some(flush)) = (handle.upgrade(), target.get()) {
+                flush(&component, writes);
+            }
+        });
+        // 이벤트 루프가 없으면 예약을 버려, 다음 쓰기가 다시 예약되도록 합니다.
+        if scheduled.is_err() {
+            self.pending.lock().unwrap_or_else(PoisonError::into_inner).clear();
+        }
+    }
+}
+
+fn flush<D: Clone + PartialEq + 'static>(
+    rows: &slint::ModelRc<D>,
+    writes: BTreeMap<usize, Vec<RowWrite>>,
+    apply: fn(&mut D, usize, Box<dyn Any + Send>) -> bool,
+) {
+    let notify_model = rows.as_any().downcast_ref::<SlintNotifyModel<D>>();
+
+    for (row, writes) in writes {
+        let Some(mut data) = rows.row_data(row) else { continue };
+        let mut changed = false;
+        for (field, value) in writes {
+            changed |= apply(&mut data, field, value);
+        }
+        if !changed {
+            continue;
+        }
+        match notify_model {
+            Some(model) => model.set_row_data_from_rust(row, data),
+            None => rows.set_row_data(row, data),
+        }
+    }
+}
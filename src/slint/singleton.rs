@@ -1,18 +1,28 @@
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
 use slint;
 
-static SINGLETON_INSTANCES: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> = OnceLock::new();
+/// `init_singleton` 으로 등록한 인스턴스입니다.
+struct Instance {
+    weak: Box<dyn Any + Send + Sync>,
+    /// 등록할 때마다 새로 받는 번호입니다. 같은 번호면 같은 창입니다.
+    generation: u64,
+}
+
+static SINGLETON_INSTANCES: OnceLock<Mutex<HashMap<TypeId, Instance>>> = OnceLock::new();
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(0);
 
 pub trait SlintSingleton: Sized + slint::ComponentHandle + 'static {
     fn init_singleton(&self) {
         let map = SINGLETON_INSTANCES
             .get_or_init(|| Mutex::new(HashMap::new()));
 
-        let mut map = map.lock().unwrap();
+        let mut map = map.lock().unwrap_or_else(PoisonError::into_inner);
 
-        map.insert(TypeId::of::<Self>(), Box::new(self.as_weak()));
+        let generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+        map.insert(TypeId::of::<Self>(), Instance { weak: Box::new(self.as_weak()), generation });
     }
 
     fn clone_singleton() -> slint::Weak<Self> {
@@ -21,13 +31,21 @@ pub trait SlintSingleton: Sized + slint::ComponentHandle + 'static {
         let map = map.as_ref()
             .expect("Singletons not initialized. Call init_singleton() first.");
 
-        let map = map.lock().unwrap();
+        let map = map.lock().unwrap_or_else(PoisonError::into_inner);
 
-        let weak = map.get(&TypeId::of::<Self>())
+        let instance = map.get(&TypeId::of::<Self>())
             .expect("Singleton not initialized. Call init_singleton() first.");
 
-        weak.downcast_ref::<slint::Weak<Self>>().expect("Type mismatch in singleton store").clone()
+        instance.weak.downcast_ref::<slint::Weak<Self>>().expect("Type mismatch in singleton store").clone()
     }
 }
 
 impl<T: slint::ComponentHandle + 'static> SlintSingleton for T {}
+
+/// 마지막으로 `init_singleton` 한 `C` 인스턴스와 그때 받은 번호입니다. 등록한 적이 없으면 `None` 입니다.
+pub(crate) fn registered_singleton<C: SlintSingleton>() -> Option<(slint::Weak<C>, u64)> {
+    let map = SINGLETON_INSTANCES.get()?.lock().unwrap_or_else(PoisonError::into_inner);
+    let instance = map.get(&TypeId::of::<C>())?;
+    let weak = instance.weak.downcast_ref::<slint::Weak<C>>().expect("Type mismatch in singleton store").clone();
+    Some((weak, instance.generation))
+}
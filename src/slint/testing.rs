@@ -0,0 +1,105 @@
+//! 창 없이 `slint_model!` 을 시험하기 위한 헤드리스 Slint 플랫폼입니다. (`slint-testing` 기능)
+//!
+//! Slint 플랫폼은 프로세스에 하나이고 컴포넌트는 만든 스레드에서만 다룰 수 있으므로,
+//! 모든 테스트는 `run` 으로 하나의 UI 스레드에서 차례로 실행합니다.
+//!
+//! ```ignore
+//! frand_property::slint::testing::run(|| {
+//!     let window = MainWindow::new().unwrap();
+//!     let model = AdderModel::bind_to(&window);
+//!     model.sum.send(3);
+//!     frand_property::slint::testing::process_events();
+//!     assert_eq!(window.get_sum(), 3);
+//! });
+//! ```
+
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::{mpsc, Mutex, OnceLock, PoisonError};
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{EventLoopProxy, Platform, WindowAdapter};
+use slint::{EventLoopError, PlatformError};
+
+type Event = Box<dyn FnOnce() + Send>;
+type Job = Box<dyn FnOnce() + Send>;
+
+/// `slint::invoke_from_event_loop` 로 예약된 함수들입니다. `process_events` 에서 실행합니다.
+static EVENTS: Mutex<VecDeque<Event>> = Mutex::new(VecDeque::new());
+
+static UI_THREAD: OnceLock<Mutex<mpsc::Sender<Job>>> = OnceLock::new();
+
+struct HeadlessPlatform;
+
+impl Platform for HeadlessPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer))
+    }
+
+    fn new_event_loop_proxy(&self) -> Option<Box<dyn EventLoopProxy>> {
+        Some(Box::new(HeadlessProxy))
+    }
+}
+
+struct HeadlessProxy;
+
+impl EventLoopProxy for HeadlessProxy {
+    fn quit_event_loop(&self) -> Result<(), EventLoopError> {
+        Ok(())
+    }
+
+    fn invoke_from_event_loop(&self, event: Event) -> Result<(), EventLoopError> {
+        EVENTS.lock().unwrap_or_else(PoisonError::into_inner).push_back(event);
+        Ok(())
+    }
+}
+
+fn ui_thread() -> mpsc::Sender<Job> {
+    UI_THREAD.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        std::thread::Builder::new()
+            .name("frand-property-slint-testing".into())
+            .spawn(move || {
+                slint::platform::set_platform(Box::new(HeadlessPlatform))
+                    .expect("Slint platform is already initialized");
+                for job in receiver {
+                    job();
+                }
+            })
+            .expect("Failed to spawn the Slint testing thread");
+        Mutex::new(sender)
+    }).lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// 헤드리스 UI 스레드에서 `test` 를 실행하고 결과를 반환합니다.
+/// `test` 가 패닉하면 호출한 스레드에서 같은 패닉을 다시 일으킵니다.
+pub fn run<R: Send + 'static>(test: impl FnOnce() -> R + Send + 'static) -> R {
+    let (sender, receiver) = mpsc::channel();
+    ui_thread()
+        .send(Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(test));
+            // 다음 테스트에 이전 테스트의 예약이 남지 않도록 실행해 둡니다.
+            let events = panic::catch_unwind(process_events);
+            let _ = sender.send(result.and_then(|result| events.map(|_| result)));
+        }))
+        .expect("The Slint testing thread has stopped");
+
+    match receiver.recv().expect("The Slint testing thread has stopped") {
+        Ok(result) => result,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// 이벤트 루프 한 바퀴를 실행합니다. 예약된 함수가 없을 때까지 실행하고 타이머와 바인딩을 갱신합니다.
+///
+/// `run` 안에서 호출해야 합니다. Rust 에서 보낸 `out` 값은 이 함수를 호출한 뒤에 UI 에 반영됩니다.
+pub fn process_events() {
+    loop {
+        let event = EVENTS.lock().unwrap_or_else(PoisonError::into_inner).pop_front();
+        match event {
+            Some(event) => event(),
+            None => break,
+        }
+    }
+    slint::platform::update_timers_and_animations();
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use slint::{Image, Rgba8Pixel, SharedPixelBuffer};
+
+/// Slint `length` (논리 픽셀) 값입니다. Slint 측 값은 `f32` 입니다.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Length(pub f32);
+
+/// Slint `physical-length` (물리 픽셀) 값입니다. Slint 측 값은 `f32` 입니다.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct PhysicalLength(pub f32);
+
+impl From<f32> for Length {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Length> for f32 {
+    fn from(value: Length) -> Self {
+        value.0
+    }
+}
+
+impl From<f32> for PhysicalLength {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PhysicalLength> for f32 {
+    fn from(value: PhysicalLength) -> Self {
+        value.0
+    }
+}
+
+/// Slint `image` 값입니다.
+/// `slint::Image` 는 `Send` 가 아니므로 스레드 사이에서 RGBA 픽셀 버퍼로 전달하고, Slint 에 쓸 때 `Image` 로 변환합니다.
+#[derive(Clone)]
+pub struct PixelImage(pub SharedPixelBuffer<Rgba8Pixel>);
+
+impl PixelImage {
+    pub fn new(buffer: SharedPixelBuffer<Rgba8Pixel>) -> Self {
+        Self(buffer)
+    }
+
+    pub fn buffer(&self) -> &SharedPixelBuffer<Rgba8Pixel> {
+        &self.0
+    }
+
+    pub fn width(&self) -> u32 {
+        self.0.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.0.height()
+    }
+}
+
+impl Default for PixelImage {
+    fn default() -> Self {
+        Self(SharedPixelBuffer::new(0, 0))
+    }
+}
+
+impl PartialEq for PixelImage {
+    fn eq(&self, other: &Self) -> bool {
+        self.width() == other.width()
+            && self.height() == other.height()
+            && self.0.as_bytes() == other.0.as_bytes()
+    }
+}
+
+impl fmt::Debug for PixelImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PixelImage")
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .finish()
+    }
+}
+
+impl From<SharedPixelBuffer<Rgba8Pixel>> for PixelImage {
+    fn from(buffer: SharedPixelBuffer<Rgba8Pixel>) -> Self {
+        Self(buffer)
+    }
+}
+
+impl From<PixelImage> for Image {
+    fn from(value: PixelImage) -> Self {
+        Image::from_rgba8(value.0)
+    }
+}
+
+/// 픽셀 데이터를 읽을 수 없는 이미지(예: GPU 텍스처)는 빈 이미지가 됩니다.
+impl From<Image> for PixelImage {
+    fn from(value: Image) -> Self {
+        value.to_rgba8().map(Self).unwrap_or_default()
+    }
+}
@@ -1,10 +1,25 @@
 use std::borrow::Borrow;
 use std::future::Future;
+use std::pin::Pin;
 use tokio::task::JoinHandle;
 pub use tokio_stream::StreamExt;
-use tokio_stream::Stream;
+use tokio_stream::{Stream, StreamMap};
 use crate::{Receiver, Sender};
 
+/// 모델의 변경 이벤트를 전달하는 스트림입니다.
+pub type ChangeStream<T> = Pin<Box<dyn Stream<Item = T> + Send + 'static>>;
+
+/// 여러 변경 스트림을 하나로 합칩니다. 어느 스트림에서든 이벤트가 발생하면 즉시 전달됩니다.
+pub fn merge_changes<T: 'static>(streams: impl IntoIterator<Item = ChangeStream<T>>) -> ChangeStream<T> {
+    let mut map = StreamMap::new();
+
+    for (index, stream) in streams.into_iter().enumerate() {
+        map.insert(index, stream);
+    }
+
+    Box::pin(map.map(|(_, change)| change))
+}
+
 pub trait PropertyStreamExt: Stream {
     fn drive<F, Fut>(self, mut handler: F) -> impl Future<Output = ()> + Send + 'static
     where
@@ -257,3 +257,358 @@ fn test_lazy_lock_len() {
     assert_eq!(models.len(), *LAZY_LEN);
     assert_eq!(models.len(), 4);
 }
+
+// 10. 모델 Receiver/Sender 그룹 테스트
+model! {
+    GroupSourceModel {
+        pub count: i32,
+        pub scores: i32[3],
+        pub model inner: InnerGroupModel,
+    }
+}
+
+model! {
+    pub InnerGroupModel {
+        pub name: String,
+    }
+}
+
+#[tokio::test]
+async fn test_model_receiver_group() {
+    let model = GroupSourceModel::clone_singleton();
+    let mut receiver = model.clone_receiver();
+
+    let sender = model.clone_sender();
+    tokio::spawn(async move {
+        sleep(Duration::from_millis(50)).await;
+        sender.scores[1].send(7);
+    });
+
+    let values = receiver.notified().await;
+    assert_eq!(values.scores, vec![0, 7, 0]);
+    assert_eq!(receiver.value().scores, values.scores);
+
+    model.clone_sender().send_all(GroupSourceModelValues {
+        count: 3,
+        scores: vec![1, 2, 3],
+        inner: InnerGroupModelValues { name: "Inner".to_string() },
+    });
+    assert_eq!(model.count.receiver().value(), 3);
+    assert_eq!(model.scores[2].receiver().value(), 3);
+    assert_eq!(model.inner.name.receiver().value(), "Inner");
+}
+
+model! {
+    BindAllSourceModel {
+        pub count: i32,
+        pub scores: i32[2],
+    }
+}
+
+model! {
+    BindAllTargetModel {
+        pub count: i32,
+        pub scores: i32[2],
+    }
+}
+
+impl From<BindAllSourceModelValues> for BindAllTargetModelValues {
+    fn from(values: BindAllSourceModelValues) -> Self {
+        Self {
+            count: values.count,
+            scores: values.scores,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_model_spawn_bind() {
+    let source = BindAllSourceModel::clone_singleton();
+    let target = BindAllTargetModel::clone_singleton();
+    let mut target_receiver = target.clone_receiver();
+
+    source.clone_receiver().spawn_bind(target.clone_sender());
+    sleep(Duration::from_millis(10)).await;
+
+    source.count.sender().send(42);
+    source.scores[1].sender().send(5);
+
+    while target_receiver.value().scores[1] != 5 {
+        target_receiver.notified().await;
+    }
+    let values = target_receiver.value();
+    assert_eq!((values.count, values.scores), (42, vec![0, 5]));
+}
+
+#[test]
+#[should_panic(expected = "`scores` 의 길이가 Sender 배열의 길이와 다릅니다")]
+fn test_model_send_all_length_mismatch() {
+    let target = BindAllTargetModel::clone_singleton();
+    target.clone_sender().send_all(BindAllTargetModelValues { count: 1, scores: vec![1, 2, 3] });
+}
+
+model! {
+    BindDirectionSourceModel {
+        pub in request: i32,
+        pub out status: i32,
+        pub level: i32,
+    }
+}
+
+model! {
+    BindDirectionTargetModel {
+        pub in request: i32,
+        pub out status: i32,
+        pub level: i32,
+    }
+}
+
+impl From<BindDirectionSourceModelValues> for BindDirectionTargetModelValues {
+    fn from(values: BindDirectionSourceModelValues) -> Self {
+        Self {
+            status: values.status,
+            level: values.level,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_model_spawn_bind_skips_in_out_fields() {
+    let source = BindDirectionSourceModel::clone_singleton();
+    let target = BindDirectionTargetModel::clone_singleton();
+    let source_owner = source.clone_owner();
+    let target_owner = target.clone_owner();
+    let mut target_receiver = target.clone_receiver();
+
+    source.clone_receiver().spawn_bind(target.clone_sender());
+    sleep(Duration::from_millis(10)).await;
+
+    // in 필드는 Values 에 없고, out 필드는 Sender 에 없으므로 inout 필드만 전달됩니다.
+    source.request.send(1);
+    source_owner.status.send(2);
+    source.level.sender().send(3);
+
+    while target_receiver.value().level != 3 {
+        target_receiver.notified().await;
+    }
+    assert_eq!(target_receiver.value(), BindDirectionTargetModelValues { status: 0, level: 3 });
+    assert_eq!(target_owner.request.value(), 0);
+}
+
+// 11. 변경 이벤트 스트림 테스트
+model! {
+    ChangeModel {
+        pub count: i32,
+        pub scores: i32[2],
+        pub model inner: InnerChangeModel,
+    }
+}
+
+model! {
+    pub InnerChangeModel {
+        pub name: String,
+    }
+}
+
+#[tokio::test]
+async fn test_model_changes() {
+    use frand_property::StreamExt;
+
+    let model = ChangeModel::clone_singleton();
+    let mut changes = model.changes();
+
+    model.count.sender().send(1);
+    assert_eq!(changes.next().await, Some(ChangeModelChange::Count(1)));
+
+    model.scores[1].sender().send(2);
+    assert_eq!(changes.next().await, Some(ChangeModelChange::Scores(1, 2)));
+
+    model.inner.name.sender().send("Inner".to_string());
+    assert_eq!(
+        changes.next().await,
+        Some(ChangeModelChange::Inner(InnerChangeModelChange::Name("Inner".to_string()))),
+    );
+}
+
+// 매크로 없이 직접 구현한 모델은 변경 스트림 없이 `Model` 만 구현할 수 있습니다.
+#[derive(Clone)]
+struct ManualModel {
+    count: Property<i32>,
+}
+
+impl frand_property::Model for ManualModel {
+    type Sender = frand_property::Sender<i32>;
+    type Receiver = frand_property::Receiver<i32>;
+
+    fn clone_sender(&self) -> Self::Sender {
+        self.count.sender().clone()
+    }
+
+    fn clone_receiver(&self) -> Self::Receiver {
+        self.count.receiver().clone()
+    }
+}
+
+#[test]
+fn test_manual_model_without_changes() {
+    use frand_property::{Model, ModelList};
+
+    let models = [ManualModel { count: Property::new((), 0, |_, _| {}) }];
+    models[0].clone_sender().send(3);
+    assert_eq!(models.clone_receivers()[0].value(), 3);
+}
+
+// 12. 초기값 선언 테스트
+model! {
+    InitValueModel {
+        pub count: i32 = 10,
+        pub scores: i32[3] = [1, 2, 3],
+        pub name: String = "Initial",
+        pub label: ArrayString<U20> = "Label",
+    }
+}
+
+#[test]
+fn test_model_init_values() {
+    let model = InitValueModel::clone_singleton();
+
+    assert_eq!(model.count.receiver().value(), 10);
+    assert_eq!(model.scores.iter().map(|p| p.receiver().value()).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(model.name.receiver().value(), "Initial");
+    assert_eq!(model.label.receiver().value().as_str(), "Label");
+
+    // 초기값은 리시버가 생성되기 전에 설정되므로 변경 알림이 없습니다.
+    assert!(!model.count.receiver().has_notified());
+}
+
+// 13. 속성 및 문서 주석 전달 테스트
+model! {
+    /// 속성이 전달되는 모델
+    #[allow(dead_code)]
+    AttrModel {
+        /// 카운트 필드
+        #[allow(dead_code)]
+        pub count: i32 = 1,
+        /// 배열 필드
+        pub values: i32[2],
+    }
+}
+
+#[test]
+fn test_model_attrs_passthrough() {
+    let model = AttrModel::clone_singleton();
+
+    model.clone_sender().send_all(AttrModelValues { count: 2, values: vec![3, 4] });
+
+    assert_eq!(model.count.receiver().value(), 2);
+    assert_eq!(model.values[1].receiver().value(), 4);
+}
+
+// 14. computed 필드 테스트
+model! {
+    ComputedModel {
+        pub price: i32 = 100,
+        pub qty: i32 = 2,
+        pub discounts: i32[2],
+        pub computed subtotal: i32 = |price, qty| price * qty,
+        pub computed total: i32 = |subtotal, discounts: Vec<i32>| subtotal - discounts.iter().sum::<i32>(),
+    }
+}
+
+#[test]
+fn test_model_computed_fields() {
+    let model = ComputedModel::clone_singleton();
+
+    // 초기값은 소스 필드의 초기값으로 계산됩니다.
+    assert_eq!(model.subtotal.value(), 200);
+    assert_eq!(model.total.value(), 200);
+
+    // 런타임 없이 전송 즉시 다시 계산됩니다.
+    model.qty.sender().send(3);
+    assert_eq!(model.subtotal.value(), 300);
+    assert_eq!(model.total.value(), 300);
+
+    let sender = model.clone_sender();
+    sender.discounts[1].send(50);
+    assert_eq!(model.total.value(), 250);
+
+    sender.send_all(ComputedModelValues { price: 10, qty: 1, discounts: vec![1, 2], subtotal: 0, total: 0 });
+    assert_eq!(model.clone_receiver().total.value(), 7);
+}
+
+// 15. 방향 키워드와 Owner 테스트
+mod service {
+    use frand_property::model;
+
+    model! {
+        pub ServiceModel {
+            pub in request: i32,
+            pub out status: i32,
+            pub callback reset: (),
+            pub inout note: i32,
+            pub in inputs: i32[2],
+            pub out outputs: i32[2],
+            pub computed doubled: i32 = |request| request * 2,
+        }
+    }
+
+    /// Owner 는 모델을 선언한 모듈 안에서만 얻을 수 있습니다.
+    pub fn owner() -> ServiceModelOwner {
+        ServiceModel::clone_singleton().clone_owner()
+    }
+}
+
+#[tokio::test]
+async fn test_model_directions() {
+    use frand_property::StreamExt;
+    use service::{ServiceModel, ServiceModelChange, ServiceModelValues};
+
+    let model = ServiceModel::clone_singleton();
+    let owner = service::owner();
+    let mut public_changes = model.changes();
+    let mut owner_changes = owner.changes();
+
+    // 공개 모델은 in 필드에 쓰고, Owner 가 읽습니다.
+    model.request.send(21);
+    assert_eq!(owner.request.value(), 21);
+
+    // computed 필드는 양쪽에서 읽을 수 있습니다. Owner 는 request 와 doubled 변경을 모두 받습니다.
+    assert_eq!(model.doubled.value(), 42);
+    assert_eq!(public_changes.next().await, Some(ServiceModelChange::Doubled(42)));
+    let owner_events = [owner_changes.next().await.unwrap(), owner_changes.next().await.unwrap()];
+    assert!(owner_events.contains(&ServiceModelChange::Request(21)));
+    assert!(owner_events.contains(&ServiceModelChange::Doubled(42)));
+
+    // Owner 가 out 필드에 쓰고, 공개 모델이 읽습니다.
+    owner.status.send(7);
+    owner.outputs[1].send(9);
+    assert_eq!(model.status.value(), 7);
+    assert_eq!(model.outputs[1].value(), 9);
+    let public_events = [public_changes.next().await.unwrap(), public_changes.next().await.unwrap()];
+    assert!(public_events.contains(&ServiceModelChange::Status(7)));
+    assert!(public_events.contains(&ServiceModelChange::Outputs(1, 9)));
+
+    // callback 은 공개 모델에서 호출하고 Owner 가 받습니다.
+    let mut reset = owner.reset.clone();
+    model.reset.notify();
+    assert!(reset.has_notified());
+    reset.mark_unnotified();
+
+    // inout 필드는 양쪽에서 읽고 쓸 수 있습니다.
+    model.note.sender().send(1);
+    assert_eq!(owner.note.receiver().value(), 1);
+
+    // Sender/Receiver 그룹은 각각 쓸 수 있는 필드와 읽을 수 있는 필드만 가집니다.
+    let sender = model.clone_sender();
+    sender.inputs[0].send(3);
+    assert_eq!(owner.inputs[0].value(), 3);
+
+    let receiver = model.clone_receiver();
+    let values = frand_property::ReceiverGroup::value(&receiver);
+    assert_eq!((values.status, values.note, values.outputs, values.doubled), (7, 1, vec![0, 9], 42));
+
+    // send_all 은 읽고 쓸 수 있는 필드에만 씁니다.
+    sender.send_all(ServiceModelValues { status: 100, note: 2, outputs: vec![5, 5], doubled: 0 });
+    assert_eq!(model.note.receiver().value(), 2);
+    assert_eq!(model.status.value(), 7);
+}
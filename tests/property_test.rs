@@ -1,4 +1,7 @@
-use frand_property::{Property, PropertyList};
+#![allow(clippy::useless_vec)]
+
+use std::sync::{Arc, Mutex};
+use frand_property::{Computed, Property, PropertyList};
 
 #[derive(Clone)]
 struct Component {
@@ -31,3 +34,41 @@ fn test_property_list_from_slice() {
     let senders = props.into_senders();
     assert_eq!(senders.len(), 1);
 }
+
+#[test]
+fn test_sender_on_send() {
+    let property = Property::from(0);
+    let sent = Arc::new(Mutex::new(Vec::new()));
+
+    let observed = sent.clone();
+    property.sender().on_send(move |value| observed.lock().unwrap().push(*value));
+
+    // 복제된 Sender 로 보낸 값도 관찰되며, 같은 값은 전송되지 않습니다.
+    let sender = property.sender().clone();
+    sender.send(1);
+    sender.send(1);
+    sender.notify_with(2);
+
+    assert_eq!(*sent.lock().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_computed_recompute_on_send() {
+    let a = Property::from(1);
+    let b = Property::from(2);
+    let sum = Property::from(0);
+
+    let computed = Computed::new(sum.sender().clone(), {
+        let a = a.receiver().clone();
+        let b = b.receiver().clone();
+        move || a.value() + b.value()
+    });
+    computed.watch(a.sender());
+    computed.watch(b.sender());
+    computed.recompute();
+
+    assert_eq!(sum.receiver().value(), 3);
+
+    b.sender().send(10);
+    assert_eq!(sum.receiver().value(), 11);
+}
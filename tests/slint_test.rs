@@ -0,0 +1,204 @@
+#![cfg(feature = "slint")]
+
+use std::sync::{Arc, Mutex};
+use frand_property::slint::{ActionState, SlintAction, SlintCallback};
+
+#[test]
+fn test_slint_callback_handler() {
+    let callback = SlintCallback::<f32, String>::new();
+    assert_eq!(callback.call(1.0), "");
+
+    callback.set_handler(|price| format!("{price:.2}"));
+    assert!(callback.clone().has_handler());
+    assert_eq!(callback.call(1200.0), "1200.00");
+
+    callback.clear_handler();
+    assert_eq!(callback.call(1.0), "");
+}
+
+#[tokio::test]
+async fn test_slint_action_pending_and_result() {
+    let states = Arc::new(Mutex::new(Vec::new()));
+    let states_clone = states.clone();
+    let action = SlintAction::<i32, i32>::new(move |state| states_clone.lock().unwrap().push(state.clone()));
+
+    assert!(action.invoke(1));
+    assert!(action.is_pending());
+    // 처리 중에는 새로운 요청을 거부합니다.
+    assert!(!action.invoke(2));
+
+    let request = action.next().await.unwrap();
+    assert_eq!(request.args, 1);
+    request.complete(Ok::<_, String>(10));
+    assert!(!action.is_pending());
+
+    assert!(action.invoke(3));
+    let request = action.next().await.unwrap();
+    request.complete(Err::<i32, _>("failed"));
+
+    assert!(action.invoke(4));
+    // 완료하지 않고 버린 요청은 실패로 처리됩니다.
+    drop(action.next().await.unwrap());
+    assert!(!action.is_pending());
+
+    let states = states.lock().unwrap();
+    assert_eq!(states[..4], [
+        ActionState::Pending,
+        ActionState::Done(10),
+        ActionState::Pending,
+        ActionState::Failed("failed".to_string()),
+    ]);
+    assert!(matches!(states[5], ActionState::Failed(_)));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_slint_action_invoke_right_after_completion() {
+    const COUNT: i32 = 200;
+
+    let states = Arc::new(Mutex::new(Vec::new()));
+    let states_clone = states.clone();
+    let action = SlintAction::<i32, i32>::new(move |state| states_clone.lock().unwrap().push(state.clone()));
+
+    let handler = action.clone();
+    tokio::spawn(async move {
+        while let Some(request) = handler.next().await {
+            let value = request.args;
+            request.complete(Ok::<_, String>(value));
+        }
+    });
+
+    // UI 처럼 처리 중 상태가 풀리자마자 다음 요청을 보냅니다.
+    let invoker = action.clone();
+    tokio::task::spawn_blocking(move || {
+        for value in 0..COUNT {
+            while !invoker.invoke(value) {
+                std::thread::yield_now();
+            }
+        }
+    }).await.unwrap();
+    while action.is_pending() {
+        tokio::task::yield_now().await;
+    }
+
+    // 이전 요청의 결과는 항상 다음 요청의 `Pending` 보다 먼저 전달됩니다.
+    let states = states.lock().unwrap();
+    let expected: Vec<_> = (0..COUNT).flat_map(|value| [ActionState::Pending, ActionState::Done(value)]).collect();
+    assert_eq!(*states, expected);
+}
+
+#[test]
+fn test_slint_value_types() {
+    use frand_property::slint::{Length, PhysicalLength, PixelImage};
+
+    assert_eq!(f32::from(Length(12.0)), 12.0);
+    assert_eq!(PhysicalLength::from(3.0), PhysicalLength(3.0));
+
+    let mut buffer = slint::SharedPixelBuffer::<slint::Rgba8Pixel>::new(2, 1);
+    buffer.make_mut_slice()[1] = slint::Rgba8Pixel::new(255, 0, 0, 255);
+    let image = PixelImage::new(buffer);
+
+    assert_eq!(PixelImage::default().width(), 0);
+    assert_ne!(image, PixelImage::default());
+    assert_eq!(image.clone(), image);
+}
+
+#[test]
+fn test_slint_int_overflow() {
+    use std::time::Duration;
+    use frand_property::slint::{from_slint_duration, from_slint_int, set_overflow_handler, to_slint_duration, to_slint_int, Overflow, OverflowError};
+
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let errors_clone = errors.clone();
+    set_overflow_handler(move |error: &OverflowError| errors_clone.lock().unwrap().push(error.clone()));
+
+    assert_eq!(to_slint_int(7u64, Overflow::Reject, "count"), Some(7));
+    assert_eq!(to_slint_int(u64::MAX, Overflow::Saturate, "count"), Some(i32::MAX));
+    assert_eq!(to_slint_int(i64::MIN, Overflow::Saturate, "count"), Some(i32::MIN));
+    assert_eq!(to_slint_int(1u64 << 32 | 5, Overflow::Wrap, "count"), Some(5));
+    assert_eq!(from_slint_int::<u8>(-1, Overflow::Saturate, "count"), Some(0));
+    assert_eq!(from_slint_int::<u8>(300, Overflow::Wrap, "count"), Some(44));
+    // `Duration` 은 Slint `duration`(밀리초, `i64`) 범위와 음수를 같은 방식으로 처리합니다.
+    assert_eq!(to_slint_duration(Duration::from_millis(300), Overflow::Reject, "delay"), Some(300));
+    assert_eq!(to_slint_duration(Duration::MAX, Overflow::Saturate, "delay"), Some(i64::MAX));
+    assert_eq!(from_slint_duration(-5, Overflow::Saturate, "delay"), Some(Duration::ZERO));
+    assert_eq!(from_slint_duration(1500, Overflow::Reject, "delay"), Some(Duration::from_millis(1500)));
+    assert!(errors.lock().unwrap().is_empty());
+
+    // 버려진 값은 핸들러에 보고됩니다.
+    assert_eq!(to_slint_int(u64::MAX, Overflow::Reject, "count"), None);
+    assert_eq!(from_slint_int::<usize>(-3, Overflow::Reject, "index"), None);
+    assert_eq!(to_slint_duration(Duration::MAX, Overflow::Reject, "delay"), None);
+    assert_eq!(from_slint_duration(-5, Overflow::Reject, "delay"), None);
+
+    let errors = errors.lock().unwrap();
+    assert_eq!(errors.len(), 4);
+    assert_eq!(errors[2], OverflowError { field: "delay", value: Duration::MAX.as_millis().to_string(), target: "duration" });
+    assert_eq!(errors[3].to_string(), "value -5 of field `delay` is out of range for `core::time::Duration`");
+    assert_eq!(errors[0], OverflowError { field: "count", value: u64::MAX.to_string(), target: "int" });
+    assert_eq!(errors[1].field, "index");
+    assert_eq!(errors[1].to_string(), "value -3 of field `index` is out of range for `usize`");
+}
+
+#[test]
+fn test_slint_notify_model_rows() {
+    use std::rc::Rc;
+    use slint::Model;
+    use frand_property::slint::SlintNotifyModel;
+
+    let changes = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let changes_clone = changes.clone();
+    let model = Rc::new(SlintNotifyModel::from_vec(vec![3, 1], move |row, value| changes_clone.borrow_mut().push((row, value))));
+    // 정렬 뷰는 원래 모델의 알림으로 갱신됩니다.
+    let sorted = model.clone().sorted_by(|a: &i32, b: &i32| a.cmp(b));
+    let even = model.clone().filtered(|value: &i32| value % 2 == 0);
+    assert_eq!(sorted.iter().collect::<Vec<_>>(), [1, 3]);
+
+    model.push(4);
+    model.insert(0, 2);
+    assert_eq!(model.iter().collect::<Vec<_>>(), [2, 3, 1, 4]);
+    assert_eq!(sorted.iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(even.iter().collect::<Vec<_>>(), [2, 4]);
+
+    assert_eq!(model.remove(1), 3);
+    assert_eq!(sorted.iter().collect::<Vec<_>>(), [1, 2, 4]);
+
+    // 뷰에서 바꾼 행은 원래 인덱스로 전달됩니다.
+    sorted.set_row_data(0, 5);
+    even.set_row_data(1, 6);
+    assert_eq!(model.iter().collect::<Vec<_>>(), [2, 5, 6]);
+    assert_eq!(*changes.borrow(), [(1, 5), (2, 6)]);
+
+    let labels = model.clone().mapped(|value| format!("#{value}"), |_, label: String| label[1..].parse().unwrap());
+    assert_eq!(labels.row_data(0).as_deref(), Some("#2"));
+    labels.set_row_data(0, "#7".to_string());
+    assert_eq!(model.row_data(0), Some(7));
+    assert_eq!(changes.borrow().last(), Some(&(0, 7)));
+
+    model.set_vec(vec![9, 8]);
+    assert_eq!(sorted.iter().collect::<Vec<_>>(), [8, 9]);
+    assert_eq!(even.iter().collect::<Vec<_>>(), [8]);
+    model.clear();
+    assert_eq!(sorted.row_count(), 0);
+}
+
+#[test]
+fn test_slint_notify_model_set_row_data_from_rust() {
+    use std::rc::Rc;
+    use slint::Model;
+    use frand_property::slint::SlintNotifyModel;
+
+    let changes = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let changes_clone = changes.clone();
+    let model = Rc::new(SlintNotifyModel::from_vec(vec![1, 2], move |row, value| changes_clone.borrow_mut().push((row, value))));
+    let sorted = model.clone().sorted_by(|a: &i32, b: &i32| b.cmp(a));
+
+    // Rust 에서 쓴 행은 뷰에 알려지지만 on_change 로 돌아오지 않습니다.
+    model.set_row_data_from_rust(0, 5);
+    model.set_row_data_from_rust(5, 9);
+    assert_eq!(model.iter().collect::<Vec<_>>(), [5, 2]);
+    assert_eq!(sorted.iter().collect::<Vec<_>>(), [5, 2]);
+    assert!(changes.borrow().is_empty());
+
+    model.set_row_data(1, 7);
+    assert_eq!(*changes.borrow(), [(1, 7)]);
+}
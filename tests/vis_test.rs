@@ -41,7 +41,6 @@ fn test_public_model_singleton() {
 
 
 // 3. Slint Model Verification
-// Since `slint_model!` requires a valid Slint Component and Global type to compile the generated code,
-// and we don't have a full Slint environment setup in this test file,
-// we rely on the `model!` test (which shares the same visibility logic) and the code review of `codegen_rust.rs`.
-// The parser correctly handles visibility, and the codegen logic is symmetric.
+// `slint_model!` requires a valid Slint Component and Global type to compile the generated code,
+// so it is exercised end to end with the headless Slint platform in `frand-property-slint/tests/headless_test.rs`.
+// The parser handles visibility the same way as `model!`, which is covered above.